@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use near_sdk::{env, AccountId, Promise, require};
+use near_sdk::json_types::U128;
+
+use nep246_core::multi_token::{
+    core::MultiToken,
+    errors::{assert_batch_lengths, MtError},
+    gas,
+    json_types::Amount,
+    token::{Approval, TokenId, MAX_SHARE_BPS},
+    utils::{expect_extension, refund_deposit, Entity, StorageTracker},
+};
+
+use crate::{ApproveMsg, GranteeApproval, MultiTokenApproval, TokenApproval, MAX_REVOKE_PER_CALL};
+
+/// Number of messages in per-token `msg`, `len` if there is a single message or none
+fn per_token_msgs_len(msg: &Option<ApproveMsg>, len: usize) -> usize {
+    match msg {
+        Some(ApproveMsg::PerToken(msgs)) => msgs.len(),
+        _ => len,
+    }
+}
+
+impl MultiTokenApproval for MultiToken {
+    fn mt_approve(
+        &mut self,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        msg: Option<ApproveMsg>,
+    ) -> Option<Promise> {
+        self.assert_storage_call_deposit();
+
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), per_token_msgs_len(&msg, token_ids.len())]);
+
+        let mut used_storage = 0;
+        let approval_ids: Vec<u64> = token_ids
+            .iter()
+            .zip(amounts.iter())
+            .map(|(token_id, amount)| {
+                let (approval, _, storage) = self.internal_approve(&account_id, token_id, amount.0, None);
+                used_storage += storage;
+                approval.approval_id
+            })
+            .collect();
+
+        refund_deposit(used_storage);
+
+        // Check if msg present and then call `mt_on_approve`
+        let amounts = amounts.into_iter().map(U128::from).collect();
+        msg.and_then(|msg| self.internal_notify_approved(account_id, token_ids, amounts, approval_ids, msg))
+    }
+
+    fn mt_approve_share(
+        &mut self,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        shares: Vec<u16>,
+        msg: Option<ApproveMsg>,
+    ) -> Option<Promise> {
+        self.assert_storage_call_deposit();
+
+        assert_batch_lengths(token_ids.len(), &[shares.len(), per_token_msgs_len(&msg, token_ids.len())]);
+        require!(shares.iter().all(|share| *share > 0 && *share <= MAX_SHARE_BPS), "Share must be in 1..=10000");
+
+        let mut used_storage = 0;
+        let (approval_ids, amounts): (Vec<u64>, Vec<U128>) = token_ids
+            .iter()
+            .zip(shares.iter())
+            .map(|(token_id, share)| {
+                let (approval, balance, storage) = self.internal_approve(&account_id, token_id, 0, Some(*share));
+                used_storage += storage;
+                (approval.approval_id, U128(approval.allowance(balance)))
+            })
+            .unzip();
+
+        refund_deposit(used_storage);
+
+        msg.and_then(|msg| self.internal_notify_approved(account_id, token_ids, amounts, approval_ids, msg))
+    }
+
+    fn mt_increase_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount) {
+        self.assert_storage_call_deposit();
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        self.internal_adjust_approval(&token_id, &account_id, |amount| {
+            amount.checked_add(delta.0).unwrap_or_else(|| env::panic_str("Approval amount overflow"))
+        });
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_decrease_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount) {
+        self.assert_call_deposit();
+
+        self.internal_adjust_approval(&token_id, &account_id, |amount| {
+            amount.checked_sub(delta.0).unwrap_or_else(|| env::panic_str("Approval amount can't go below 0"))
+        });
+    }
+
+    fn mt_revoke(&mut self, token_ids: Vec<TokenId>, account_id: AccountId) -> u64 {
+        self.assert_call_deposit();
+
+        let mut processed = 0;
+        for token_id in token_ids.into_iter().take(MAX_REVOKE_PER_CALL) {
+            if env::prepaid_gas() - env::used_gas() < gas::GAS_PER_REVOKE {
+                break;
+            }
+            self.internal_revoke(token_id, &account_id);
+            processed += 1;
+        }
+        require!(processed > 0, "Not enough gas to revoke approval");
+
+        processed
+    }
+
+    fn mt_revoke_all(&mut self, token: Vec<TokenId>) {
+        todo!()
+    }
+
+    fn mt_is_approved(
+        &self,
+        token_ids: Vec<TokenId>,
+        approved_account_id: AccountId,
+        amounts: Vec<Amount>,
+        approval_ids: Option<Vec<u64>>,
+    ) -> bool {
+        let approvals = expect_extension(self.approvals_by_id.as_ref(), "Approval Management", Entity::Contract);
+
+        if token_ids.len() != amounts.len()
+            || approval_ids.as_ref().map_or(false, |ids| ids.len() != token_ids.len())
+        {
+            MtError::LengthMismatch.panic();
+        }
+
+        let amounts_to: Vec<u128> = amounts.iter().map(|a| a.0).collect();
+
+        let results: Vec<bool> = token_ids.into_iter().enumerate().map(|(idx, token_id)| {
+            let by_token = approvals.get(&token_id).unwrap_or_default();
+
+            match by_token.get(&approved_account_id) {
+                Some(_) if self.internal_is_approval_pending(&approved_account_id, &token_id) => false,
+                Some(approve) if self.internal_is_approval_expired(&approved_account_id, &token_id, approve.approval_id) => false,
+                Some(approve) => {
+                    let approval_id = approval_ids.as_ref().and_then(|ids| ids.get(idx));
+                    let allowance = match approve.share {
+                        Some(_) => {
+                            let owner_id = self.owner_by_id.get(&token_id).unwrap();
+                            approve.allowance(self.internal_unwrap_balance_of(&token_id, &owner_id))
+                        }
+                        None => approve.amount.0,
+                    };
+
+                    if allowance.eq(&amounts_to[idx]) {
+                        match approval_id {
+                            Some(approval) => approve.approval_id.eq(approval),
+                            None => true,
+                        }
+                    } else {
+                        false
+                    }
+                }
+                None => false
+            }
+        }).collect();
+
+        !results.contains(&false)
+
+    }
+
+    fn mt_token_approval(&self, token_id: TokenId, account_id: AccountId) -> TokenApproval {
+        let approvals = expect_extension(self.approvals_by_id.as_ref(), "Approval Management", Entity::Contract);
+        let by_token = expect_extension(approvals.get(&token_id), "Approval Management", Entity::Token);
+        let by_account: (AccountId, Approval) = by_token.into_iter().find(|(account, _)| account == &account_id)
+            .expect("This account does not have approvals in this token");
+        let owner = self.owner_by_id.get(&token_id).unwrap();
+
+        TokenApproval::new(owner, HashMap::from([by_account]))
+    }
+
+    fn mt_approvals_for_grantee(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<GranteeApproval> {
+        let by_grantee = expect_extension(self.approvals_by_grantee.as_ref(), "Approval Management", Entity::Contract);
+        let approvals = expect_extension(self.approvals_by_id.as_ref(), "Approval Management", Entity::Contract);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        let entries = match by_grantee.get(&account_id) {
+            Some(entries) => entries,
+            None => return vec![],
+        };
+
+        entries
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit as usize)
+            .filter_map(|((owner_id, token_id), _)| {
+                let approval = approvals.get(&token_id)?.get(&account_id)?.clone();
+                Some(GranteeApproval { owner_id, token_id, approval })
+            })
+            .collect()
+    }
+
+    fn mt_token_approvals(&self, token_id: TokenId, from_index: U128, limit: u64) -> Vec<TokenApproval> {
+        let approvals = expect_extension(self.approvals_by_id.as_ref(), "Approval Management", Entity::Contract);
+        require!(limit != 0, "Limit cannot be 0");
+        let owner = self.owner_by_id.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+
+        // Approvals of token are granted by its owner only, so there's at most one page entry
+        let by_owner: Vec<(AccountId, HashMap<AccountId, Approval>)> = approvals
+            .get(&token_id)
+            .filter(|approved| !approved.is_empty())
+            .map(|approved| vec![(owner, approved)])
+            .unwrap_or_default();
+
+        by_owner.into_iter().skip(from_index.0 as usize).take(limit as usize)
+            .map(|(approval_owner_id, approved_account_ids)| TokenApproval::new(approval_owner_id, approved_account_ids))
+            .collect()
+    }
+}
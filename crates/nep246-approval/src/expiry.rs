@@ -0,0 +1,85 @@
+use near_sdk::{env, require, AccountId, Balance, Promise};
+
+use nep246_core::multi_token::{
+    core::MultiToken,
+    token::{TokenId, MAX_SHARE_BPS},
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+pub trait MultiTokenApprovalExpiry {
+    /// Set or clear expiry of approvals granted to `account_id`. Expired approvals can't be used
+    /// and are removed by anyone with `mt_sweep_expired`. Re-approval is not affected by
+    /// expiry of previous approval
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than token owner
+    /// * Contract MUST panic if `account_id` is not approved for any of tokens
+    ///
+    /// # Arguments:
+    /// * `expires_at` - block timestamp in nanoseconds, `None` to remove expiry
+    fn mt_set_approval_expiry(&mut self, token_ids: Vec<TokenId>, account_id: AccountId, expires_at: Option<u64>);
+
+    /// Remove up to `limit` expired approvals, oldest first. Caller receives a share of
+    /// storage cost freed by removal
+    ///
+    /// # Returns
+    /// Number of removed approvals
+    fn mt_sweep_expired(&mut self, limit: u64) -> u64;
+
+    /// Get expiry of approval granted to `account_id`, in nanoseconds
+    fn mt_approval_expires_at(&self, token_id: TokenId, account_id: AccountId) -> Option<u64>;
+}
+
+impl MultiTokenApprovalExpiry for MultiToken {
+    fn mt_set_approval_expiry(&mut self, token_ids: Vec<TokenId>, account_id: AccountId, expires_at: Option<u64>) {
+        self.assert_storage_call_deposit();
+        expect_extension(self.approval_expiry.as_ref(), "Approval expiry", Entity::Contract);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        for token_id in token_ids {
+            let owner_id = self.owner_by_id.get(&token_id).expect("This token does not exist");
+            unauthorized_assert(&owner_id);
+
+            let approval_id = expect_extension(self.approvals_by_id.as_ref(), "Approval Management", Entity::Contract)
+                .get(&token_id)
+                .and_then(|approvals| approvals.get(&account_id).map(|approval| approval.approval_id))
+                .expect("Account is not approved");
+
+            self.internal_clear_expiry(&token_id, &account_id);
+            if let Some(expires_at) = expires_at {
+                let expiry = self.approval_expiry.as_mut().unwrap();
+                expiry.queue.insert(&(expires_at, token_id.clone(), account_id.clone()), &approval_id);
+                expiry.expiry_by_approval.insert(&(token_id, account_id.clone()), &(approval_id, expires_at));
+            }
+        }
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_sweep_expired(&mut self, limit: u64) -> u64 {
+        require!(limit != 0, "Limit cannot be 0");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let removed = self.internal_sweep_expired_approvals(limit);
+        tracker.stop();
+
+        let keeper_share = self.approval_expiry.as_ref().unwrap().keeper_share_bps;
+        let freed = env::storage_byte_cost() * Balance::from(tracker.bytes_released);
+        let reward = freed / Balance::from(MAX_SHARE_BPS) * Balance::from(keeper_share);
+        if reward > 0 {
+            Promise::new(env::predecessor_account_id()).transfer(reward);
+        }
+
+        removed
+    }
+
+    fn mt_approval_expires_at(&self, token_id: TokenId, account_id: AccountId) -> Option<u64> {
+        let expiry = self.approval_expiry.as_ref()?;
+        let (approval_id, expires_at) = expiry.expiry_by_approval.get(&(token_id.clone(), account_id.clone()))?;
+        let current = self.approvals_by_id.as_ref()?.get(&token_id)?.get(&account_id)?.approval_id;
+        (current == approval_id).then_some(expires_at)
+    }
+}
@@ -0,0 +1,220 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::unnecessary_map_or)]
+#![allow(clippy::manual_div_ceil)]
+#![allow(clippy::manual_is_multiple_of)]
+#![allow(clippy::doc_overindented_list_items)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(unused_variables)]
+
+//! Approval management extension of NEP-246: granting, revoking and checking approvals.
+//!
+//! Approvals are stored and checked on transfer by [MultiToken](nep246_core::multi_token::core::MultiToken)
+//! of `nep246-core`, types are re-exported from there, this crate adds methods of the extension.
+
+mod approval_impl;
+mod expiry;
+mod receiver;
+mod scoped;
+mod series;
+mod strict;
+
+pub use nep246_core::multi_token::approval::*;
+pub use expiry::*;
+pub use receiver::*;
+pub use scoped::*;
+pub use series::*;
+pub use strict::*;
+
+use near_sdk::json_types::U128;
+use near_sdk::{AccountId, Promise};
+use nep246_core::multi_token::{json_types::Amount, token::TokenId};
+
+/// Trait used in approval management
+/// Specs - https://github.com/shipsgold/NEPs/blob/master/specs/Standards/MultiToken/ApprovalManagement.md
+pub trait MultiTokenApproval {
+    /// Add an approved account for a specific set of tokens.
+    ///
+    /// Requirements
+    /// * Caller of the method must attach a deposit of at least 1 yoctoⓃ for
+    ///   security purposes
+    /// * Contract MAY require caller to attach larger deposit, to cover cost of
+    ///   storing approver data
+    /// * Contract MUST panic if called by someone other than token owner
+    /// * Contract MUST panic if `account_id` is the owner or the contract itself
+    /// * Contract MUST panic if addition would cause `mt_revoke_all` to exceed
+    ///   single-block gas limit. See below for more info.
+    /// * Contract MUST increment approval ID even if re-approving an account
+    /// * If successfully approved or if had already been approved, and if `msg` is
+    ///   present, contract MUST call `mt_on_approve` on `account_id`. See
+    ///   `mt_on_approve` description below for details.
+    ///
+    /// # Arguments:
+    /// * `token_ids`: the token ids for which to add an approval
+    /// * `account_id`: the account to add to `approved_account_ids`
+    /// * `amounts`: the number of tokens to approve for transfer, wrapped in quotes and treated
+    ///    like an array of string, although the numbers will be stored as an array of
+    ///    unsigned integer with 128 bits.
+    ///
+    /// * `msg`: optional string to be passed to `mt_on_approve`. May also be an array of
+    ///    optional strings positionally matched to `token_ids`, tokens sharing the same message
+    ///    are passed to `mt_on_approve` in a single call
+    ///
+    /// # Use of approval from `mt_on_approve`
+    /// By default approval is stored before `mt_on_approve` is scheduled, so grantee may use it
+    /// right away, including calling `mt_transfer` from `mt_on_approve` itself. With strict approvals
+    /// (see [StrictApprovals]) notified approvals are pending until `mt_on_approve` finishes and
+    /// `mt_resolve_approve` confirms them: transfers made from `mt_on_approve`, or any time before
+    /// confirmation, fail with "Approval is not confirmed by grantee yet". Approvals without `msg`
+    /// are active immediately in both modes.
+    ///
+    /// # Returns
+    /// void, if no `msg` given. Otherwise, returns promise call to
+    /// `mt_on_approve`, which can resolve with whatever it wants.
+    fn mt_approve(
+        &mut self,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        msg: Option<ApproveMsg>,
+    ) -> Option<Promise>;
+
+    /// Add an approved account for a share of owner's balance in a specific set of tokens.
+    /// Allowance is evaluated at the moment of transfer, so approval stays valid when balance changes.
+    ///
+    /// Requirements are the same as for `mt_approve`, additionally:
+    /// * Contract MUST panic if length of `shares` is not equal to `token_ids`
+    /// * Contract MUST panic if any of shares is 0 or greater than 10000
+    ///
+    /// # Arguments:
+    /// * `account_id`: the account to add to `approved_account_ids`
+    /// * `token_ids`: the token ids for which to add an approval
+    /// * `shares`: share of owner's balance for each token, in basis points (10000 is 100%)
+    /// * `msg`: optional string to be passed to `mt_on_approve`, amounts passed there
+    ///    are allowances at the moment of approval
+    fn mt_approve_share(
+        &mut self,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        shares: Vec<u16>,
+        msg: Option<ApproveMsg>,
+    ) -> Option<Promise>;
+
+    /// Increase amount approved to `account_id` by `delta`. Approval ID is kept, so transfers
+    /// prepared with it stay valid and there's no window where approval doesn't exist
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than token owner
+    /// * Contract MUST panic if `account_id` is not approved or approved for a share of balance
+    /// * Contract MUST panic if new amount is greater than owner's balance
+    fn mt_increase_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount);
+
+    /// Decrease amount approved to `account_id` by `delta`, approval is removed when amount reaches 0.
+    /// Approval ID is kept
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than token owner
+    /// * Contract MUST panic if `account_id` is not approved or approved for a share of balance
+    /// * Contract MUST panic if `delta` is greater than approved amount
+    fn mt_decrease_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount);
+
+    /// Revoke an approved account for a specific token.
+    ///
+    /// Tokens are processed in order, at most [MAX_REVOKE_PER_CALL] of them and only while
+    /// there is enough gas left for the next one, so large sets never fail halfway. Returned
+    /// number is the continuation: call again with `token_ids[processed..]` until all are done.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security
+    ///   purposes
+    /// * If contract requires >1yN deposit on `mt_approve`, contract
+    ///   MUST refund associated storage deposit when owner revokes approval
+    /// * Contract MUST panic if called by someone other than token owner
+    /// * Contract MUST panic if there is not enough gas to revoke even one token
+    ///
+    /// # Arguments:
+    /// * `token_ids`: the token for which to revoke approved_account_ids
+    /// * `account_id`: the account to remove from `approvals`
+    ///
+    /// returns: number of processed tokens from the start of `token_ids`
+    fn mt_revoke(&mut self, token_ids: Vec<TokenId>, account_id: AccountId) -> u64;
+
+    /// Revoke all approved accounts for a specific token.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security
+    ///   purposes
+    /// * If contract requires >1yN deposit on `mt_approve`, contract
+    ///   MUST refund all associated storage deposit when owner revokes approved_account_ids
+    /// * Contract MUST panic if called by someone other than token owner
+    ///
+    /// # Arguments:
+    /// * `token_ids`: the token ids with approved_account_ids to revoke
+    fn mt_revoke_all(&mut self, token_ids: Vec<TokenId>);
+
+    /// Check if tokens are approved for transfer by a given account, optionally
+    /// checking an approval_id
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if `approval_ids` is not null and the length of
+    ///   `approval_ids` is not equal to `token_ids`
+    ///
+    /// # Arguments:
+    /// * `token_ids`: the tokens for which to check an approval
+    /// * `approved_account_id`: the account to check the existence of in `approved_account_ids`
+    /// * `amounts`: specify the positionally corresponding amount for the `token_id`
+    ///    that at least must be approved. The number of tokens to approve for transfer,
+    ///    wrapped in quotes and treated like an array of string, although the numbers will be
+    ///    stored as an array of unsigned integer with 128 bits.
+    /// * `approval_ids`: an optional array of approval IDs to check against
+    ///    current approval IDs for given account and `token_ids`.
+    ///
+    /// # Returns:
+    /// if `approval_ids` is given, `true` if `approved_account_id` is approved with given `approval_id`
+    /// and has at least the amount specified approved  otherwise, `true` if `approved_account_id`
+    /// is in list of approved accounts and has at least the amount specified approved
+    /// finally it returns false for all other states. Result is `true` only if this holds
+    /// for every token, a single unapproved token makes it `false`
+    fn mt_is_approved(
+        &self,
+        token_ids: Vec<TokenId>,
+        approved_account_id: AccountId,
+        amounts: Vec<Amount>,
+        approval_ids: Option<Vec<u64>>,
+    ) -> bool;
+
+    /// Get a the list of approvals for a given token_id and account_id
+    ///
+    /// # Arguments:
+    /// * `token_id`: the token for which to check an approval
+    /// * `account_id`: the account to retrieve approvals for
+    ///
+    /// # Returns:
+    /// A TokenApproval object, as described in Approval Management standard
+    fn mt_token_approval(&self, token_id: TokenId, account_id: AccountId) -> TokenApproval;
+
+    /// Get a list of all approvals granted to account across the contract
+    ///
+    /// # Arguments:
+    /// * `account_id`: the account approvals were granted to
+    /// * `from_index`: index to start from, defaults to 0 if not provided
+    /// * `limit`: the maximum number of approvals to return
+    ///
+    /// # Returns:
+    /// An array of [GranteeApproval] objects ordered by owner and token ID, empty if account has no approvals
+    fn mt_approvals_for_grantee(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<GranteeApproval>;
+
+    /// Get a list of all approvals for a given token_id, grouped by owner
+    ///
+    /// # Arguments:
+    /// * `from_index`: a string representing an unsigned 128-bit integer,
+    ///    representing the starting index of owners to return
+    /// * `limit`: the maximum number of owners to return
+    ///
+    /// # Returns:
+    /// An array of TokenApproval objects, one per owner with all accounts approved by them,
+    /// as described in Approval Management standard, and an empty array if there are no approvals
+    fn mt_token_approvals(&self, token_id: TokenId, from_index: U128, limit: u64) -> Vec<TokenApproval>;
+}
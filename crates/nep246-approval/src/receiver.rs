@@ -1,4 +1,4 @@
-use crate::multi_token::token::TokenId;
+use nep246_core::multi_token::token::TokenId;
 use near_sdk::AccountId;
 use near_sdk::json_types::U128;
 
@@ -0,0 +1,87 @@
+use near_sdk::{env, require, AccountId};
+
+use nep246_core::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use crate::{OperatorScope, MAX_OPERATOR_TOKENS};
+
+pub trait MultiTokenScopedOperator {
+    /// Give `operator_id` blanket rights over tokens owned by caller: it can move any amount of them
+    /// without per-token approvals. Like approvals, rights apply to tokens whose owner (`owner_by_id`,
+    /// i.e. account token was minted for) is caller, not to balances caller merely holds.
+    /// `token_ids` limits rights to listed tokens, `None` covers all tokens owned by caller
+    /// including ones minted for it later. Approving same operator again replaces its scope
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if more than `MAX_OPERATOR_TOKENS` tokens are listed
+    /// * Contract MUST panic if any listed token is not owned by caller
+    ///
+    /// # Arguments:
+    /// * `expires_at` - block timestamp in nanoseconds after which rights are void, `None` for no expiry
+    fn mt_approve_operator(&mut self, operator_id: AccountId, token_ids: Option<Vec<TokenId>>, expires_at: Option<u64>);
+
+    /// Revoke rights of `operator_id` given by caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    fn mt_revoke_operator(&mut self, operator_id: AccountId);
+
+    /// Whether `operator_id` can currently move token of `owner_id`
+    fn mt_is_operator_approved(&self, owner_id: AccountId, operator_id: AccountId, token_id: TokenId) -> bool;
+}
+
+impl MultiTokenScopedOperator for MultiToken {
+    fn mt_approve_operator(&mut self, operator_id: AccountId, token_ids: Option<Vec<TokenId>>, expires_at: Option<u64>) {
+        self.assert_storage_call_deposit();
+        let owner_id = env::predecessor_account_id();
+        if owner_id == operator_id {
+            MtError::ApproveSelf.panic();
+        }
+        if operator_id == env::current_account_id() {
+            MtError::ApproveContract.panic();
+        }
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > env::block_timestamp(), "Expiry must be in the future");
+        }
+
+        let token_ids = token_ids.map(|mut token_ids| {
+            require!(!token_ids.is_empty(), "List at least one token or pass null for all tokens");
+            require!(token_ids.len() <= MAX_OPERATOR_TOKENS, "Too many tokens for operator");
+            token_ids.sort_unstable();
+            token_ids.dedup();
+            for token_id in token_ids.iter() {
+                let token_owner_id = self.owner_by_id.get(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+                require!(token_owner_id == owner_id, format!("Caller doesn't own token {}", token_id));
+            }
+            token_ids
+        });
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        let operators = expect_extension(self.scoped_operators.as_mut(), "Scoped operators", Entity::Contract);
+        operators.scope_by_operator.insert(&(owner_id.clone(), operator_id), &OperatorScope { token_ids, expires_at });
+
+        tracker.stop();
+        tracker.refund(owner_id);
+    }
+
+    fn mt_revoke_operator(&mut self, operator_id: AccountId) {
+        self.assert_call_deposit();
+        let owner_id = env::predecessor_account_id();
+
+        let operators = expect_extension(self.scoped_operators.as_mut(), "Scoped operators", Entity::Contract);
+        require!(operators.scope_by_operator.remove(&(owner_id, operator_id)).is_some(), "Operator is not approved");
+    }
+
+    fn mt_is_operator_approved(&self, owner_id: AccountId, operator_id: AccountId, token_id: TokenId) -> bool {
+        expect_extension(self.scoped_operators.as_ref(), "Scoped operators", Entity::Contract);
+
+        self.internal_is_scoped_operator(&owner_id, &operator_id, &token_id)
+    }
+}
@@ -0,0 +1,74 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId};
+
+use nep246_core::multi_token::{
+    core::MultiToken,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use crate::MAX_SERIES_PER_GRANTEE;
+
+pub trait MultiTokenSeriesApproval {
+    /// Approve `account_id` for every token of caller whose ID starts with `series_prefix`,
+    /// including tokens minted later. Empty prefix approves all tokens of caller.
+    /// `amount_per_token` caps total amount account can move of each token, approving
+    /// same series again replaces the cap but keeps what was already moved
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if caller approved `MAX_SERIES_PER_GRANTEE` series for account already
+    fn mt_approve_series(&mut self, series_prefix: String, account_id: AccountId, amount_per_token: Amount);
+
+    /// Revoke series approval of `account_id` given by caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    fn mt_revoke_series(&mut self, series_prefix: String, account_id: AccountId);
+
+    /// How much of token `account_id` can still move on behalf of `owner_id` through series approvals
+    fn mt_series_allowance(&self, owner_id: AccountId, account_id: AccountId, token_id: TokenId) -> U128;
+}
+
+impl MultiTokenSeriesApproval for MultiToken {
+    fn mt_approve_series(&mut self, series_prefix: String, account_id: AccountId, amount_per_token: Amount) {
+        self.assert_storage_call_deposit();
+        let owner_id = env::predecessor_account_id();
+        require!(owner_id != account_id, "Cannot approve yourself");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        let series = expect_extension(self.series_approvals.as_mut(), "Series approvals", Entity::Contract);
+        let key = (owner_id.clone(), account_id);
+        let mut prefixes = series.by_grantee.get(&key).unwrap_or_default();
+        prefixes.insert(series_prefix, amount_per_token.0);
+        require!(prefixes.len() <= MAX_SERIES_PER_GRANTEE, "Too many series approved for account");
+        series.by_grantee.insert(&key, &prefixes);
+
+        tracker.stop();
+        tracker.refund(owner_id);
+    }
+
+    fn mt_revoke_series(&mut self, series_prefix: String, account_id: AccountId) {
+        self.assert_call_deposit();
+        let owner_id = env::predecessor_account_id();
+
+        let series = expect_extension(self.series_approvals.as_mut(), "Series approvals", Entity::Contract);
+        let key = (owner_id, account_id);
+        let mut prefixes = series.by_grantee.get(&key).unwrap_or_default();
+        require!(prefixes.remove(&series_prefix).is_some(), "Series is not approved");
+        if prefixes.is_empty() {
+            series.by_grantee.remove(&key);
+        } else {
+            series.by_grantee.insert(&key, &prefixes);
+        }
+    }
+
+    fn mt_series_allowance(&self, owner_id: AccountId, account_id: AccountId, token_id: TokenId) -> U128 {
+        expect_extension(self.series_approvals.as_ref(), "Series approvals", Entity::Contract);
+
+        U128(self.internal_series_allowance(&owner_id, &account_id, &token_id))
+    }
+}
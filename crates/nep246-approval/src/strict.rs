@@ -0,0 +1,62 @@
+use near_sdk::{env, require, AccountId, PromiseResult};
+
+use nep246_core::multi_token::{
+    core::{ApprovalId, MultiToken},
+    token::TokenId,
+};
+
+pub trait MultiTokenApprovalResolver {
+    /// Finalize approvals after `mt_on_approve` of grantee is done.
+    /// Approvals are confirmed if grantee succeeded and removed otherwise
+    fn mt_resolve_approve(
+        &mut self,
+        owner_id: AccountId,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        approval_ids: Vec<ApprovalId>,
+    );
+}
+
+impl MultiTokenApprovalResolver for MultiToken {
+    fn mt_resolve_approve(
+        &mut self,
+        owner_id: AccountId,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        approval_ids: Vec<ApprovalId>,
+    ) {
+        // Macro marks this method as `#[private]`, but contracts may call it directly.
+        // Otherwise anyone could confirm pending approvals by chaining the call after own promise
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method mt_resolve_approve is private"
+        );
+        let accepted = matches!(env::promise_result(0), PromiseResult::Successful(_));
+
+        for (token_id, approval_id) in token_ids.iter().zip(approval_ids) {
+            let strict = match self.strict_approvals.as_mut() {
+                Some(strict) => strict,
+                None => return,
+            };
+            let key = (account_id.clone(), token_id.clone());
+
+            // Approval was replaced by a newer one, which has its own callback
+            if strict.pending.get(&key) != Some(approval_id) {
+                continue;
+            }
+            strict.pending.remove(&key);
+
+            // Approval could be dropped meanwhile, e.g. by transfer
+            let still_granted = self
+                .approvals_by_id
+                .as_ref()
+                .and_then(|by_id| by_id.get(token_id))
+                .and_then(|approvals| approvals.get(&account_id).map(|approval| approval.approval_id))
+                == Some(approval_id);
+
+            if !accepted && still_granted {
+                self.internal_remove_approval(token_id, &owner_id, &account_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Events of the standard and sinks they are delivered to.
+//!
+//! Every event is serialized to NEP-297 JSON once and passed to each registered [EventSink].
+//! By default the only sink is [LogSink], which writes `EVENT_JSON:` logs indexers expect.
+//! Contracts can add secondary sinks, e.g. to collect a digest of events or forward them to a
+//! registry contract, without changing functions that emit events. Sinks live for a single
+//! call, so they must be registered at the start of every method that needs them.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use near_sdk::env;
+use serde::Serialize;
+
+/// Receiver of events emitted by the library
+pub trait EventSink {
+    /// Called for every event. `event` is NEP-297 JSON without `EVENT_JSON:` prefix.
+    /// Must not emit events itself
+    fn on_event(&mut self, event: &str);
+}
+
+/// Default sink: logs event in NEP-297 format
+pub struct LogSink;
+
+impl EventSink for LogSink {
+    fn on_event(&mut self, event: &str) {
+        env::log_str(&format!("EVENT_JSON:{}", event));
+    }
+}
+
+/// Shared sink, so contract can keep a handle and read what was collected after events are emitted
+impl<T: EventSink> EventSink for Rc<RefCell<T>> {
+    fn on_event(&mut self, event: &str) {
+        self.borrow_mut().on_event(event);
+    }
+}
+
+thread_local! {
+    static EVENT_SINKS: RefCell<Vec<Box<dyn EventSink>>> = RefCell::new(vec![Box::new(LogSink)]);
+}
+
+/// Deliver events of the rest of current call to `sink` as well
+pub fn add_event_sink(sink: Box<dyn EventSink>) {
+    EVENT_SINKS.with(|sinks| sinks.borrow_mut().push(sink));
+}
+
+/// Replace all sinks, including default [LogSink]. Without [LogSink] events are not visible to indexers
+pub fn replace_event_sinks(new_sinks: Vec<Box<dyn EventSink>>) {
+    EVENT_SINKS.with(|sinks| *sinks.borrow_mut() = new_sinks);
+}
+
+fn dispatch_event(event: &str) {
+    EVENT_SINKS.with(|sinks| sinks.borrow_mut().iter_mut().for_each(|sink| sink.on_event(event)));
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "standard")]
+#[must_use = "don't forget to `.emit()` this event"]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum NearEvent<'a> {
+    Nep246(crate::multi_token::events::Nep246Event<'a>),
+}
+
+impl<'a> NearEvent<'a> {
+    fn to_json_string(&self) -> String {
+        // Events cannot fail to serialize so fine to panic on error
+        #[allow(clippy::redundant_closure)]
+        serde_json::to_string(self).ok().unwrap_or_else(|| env::abort())
+    }
+
+    /// Passes the event to registered sinks, by default logs it to the host. This is required
+    /// to ensure that the event is triggered and to consume the event.
+    pub(crate) fn emit(self) {
+        dispatch_event(&self.to_json_string());
+    }
+}
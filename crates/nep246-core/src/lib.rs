@@ -0,0 +1,21 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::unnecessary_map_or)]
+#![allow(clippy::manual_div_ceil)]
+#![allow(clippy::manual_is_multiple_of)]
+#![allow(clippy::doc_overindented_list_items)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(unused_variables)]
+
+//! Core of NEP-246 Implementation: state of multi token, transfers and extensions built into it.
+//! Approval management, enumeration and metadata views are in their own crates, `nep-246`
+//! re-exports all of them.
+//!
+//! NOTES:
+//! * All amounts, balances and allowance are limited by U128 (max value 2**128 - 1).
+//! * Token standard uses JSON for serialization of arguments and results.
+//! * Amounts in arguments and results are serialized as Base-10 strings, e.g. "100". This is done to avoid JSON limitation of max integer value of 2**53.
+//! * The contract must track the change in storage when adding to and removing from collections. This is not included in this core multi token standard but instead in the Storage Standard.
+//! * To prevent the deployed contract from being modified or deleted, it should not have any access keys on its account.
+pub mod multi_token;
+pub mod event;
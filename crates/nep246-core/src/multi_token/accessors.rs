@@ -0,0 +1,124 @@
+//! Read-only access to state of [MultiToken] for custom views of contracts.
+//!
+//! Fields of [MultiToken] follow the storage layout and may change between versions,
+//! accessors keep the same interface, so views built on them don't need to follow those changes.
+
+use std::collections::HashMap;
+
+use near_sdk::{AccountId, Balance};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::{Approval, TokenId},
+};
+
+/// Balances of holders, see [MultiToken::balances]
+pub struct Balances<'a> {
+    token: &'a MultiToken,
+}
+
+impl Balances<'_> {
+    /// Balance of account in token, `None` if token doesn't exist or account is not registered in it
+    pub fn get(&self, token_id: &TokenId, account_id: &AccountId) -> Option<Balance> {
+        self.token.balances_per_token.get(token_id).and_then(|balances| balances.get(account_id))
+    }
+
+    /// Total supply of token
+    pub fn supply(&self, token_id: &TokenId) -> Option<Balance> {
+        self.token.total_supply.get(token_id)
+    }
+
+    /// Accounts registered in token, at most `limit` starting from `from_index`
+    pub fn holders(&self, token_id: &TokenId, from_index: u64, limit: u64) -> Vec<AccountId> {
+        self.token
+            .holders_per_token
+            .get(token_id)
+            .map(|holders| holders.iter().skip(from_index as usize).take(limit as usize).collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of accounts registered in token
+    pub fn holders_count(&self, token_id: &TokenId) -> u64 {
+        self.token.holders_per_token.get(token_id).map_or(0, |holders| holders.len())
+    }
+}
+
+/// Approvals given by token owners, see [MultiToken::approvals]
+pub struct Approvals<'a> {
+    token: &'a MultiToken,
+}
+
+impl Approvals<'_> {
+    /// Whether contract uses approval management
+    pub fn is_enabled(&self) -> bool {
+        self.token.approvals_by_id.is_some()
+    }
+
+    /// All approvals of token by grantee
+    pub fn of_token(&self, token_id: &TokenId) -> HashMap<AccountId, Approval> {
+        self.token.approvals_by_id.as_ref().and_then(|by_id| by_id.get(token_id)).unwrap_or_default()
+    }
+
+    /// Approval of `grantee_id` in token
+    pub fn get(&self, token_id: &TokenId, grantee_id: &AccountId) -> Option<Approval> {
+        self.of_token(token_id).remove(grantee_id)
+    }
+
+    /// ID the next approval of token will get
+    pub fn next_approval_id(&self, token_id: &TokenId) -> Option<u64> {
+        self.token.next_approval_id_by_id.as_ref().and_then(|by_id| by_id.get(token_id))
+    }
+}
+
+/// Owners (creators) of tokens, see [MultiToken::owners]
+pub struct Owners<'a> {
+    token: &'a MultiToken,
+}
+
+impl Owners<'_> {
+    /// Owner of token, `None` if token doesn't exist
+    pub fn get(&self, token_id: &TokenId) -> Option<AccountId> {
+        self.token.owner_by_id.get(token_id)
+    }
+
+    /// Number of tokens
+    pub fn len(&self) -> u64 {
+        self.token.owner_by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.token.owner_by_id.is_empty()
+    }
+
+    /// Tokens with owners ordered by token ID, at most `limit` starting from `from_index`
+    pub fn iter(&self, from_index: u64, limit: u64) -> Vec<(TokenId, AccountId)> {
+        self.token.owner_by_id.iter().skip(from_index as usize).take(limit as usize).collect()
+    }
+
+    /// Tokens created for account, empty if enumeration is not used
+    pub fn tokens_of(&self, account_id: &AccountId) -> Vec<TokenId> {
+        self.token
+            .tokens_per_owner
+            .as_ref()
+            .and_then(|per_owner| per_owner.get(account_id))
+            .map(|tokens| tokens.iter().map(|(token_id, _)| token_id).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl MultiToken {
+    /// Read-only access to balances and holders
+    pub fn balances(&self) -> Balances<'_> {
+        Balances { token: self }
+    }
+
+    /// Read-only access to approvals
+    pub fn approvals(&self) -> Approvals<'_> {
+        Approvals { token: self }
+    }
+
+    /// Read-only access to owners of tokens
+    pub fn owners(&self) -> Owners<'_> {
+        Owners { token: self }
+    }
+}
@@ -0,0 +1,88 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U64;
+use near_sdk::{env, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::TokenId,
+    utils::{expect_extension, Entity},
+};
+
+use super::{MultiTokenActivity, TokenActivityView};
+
+#[derive(Debug, Clone, Copy, BorshDeserialize, BorshSerialize)]
+pub struct TokenActivity {
+    pub issued_at: u64,
+    pub last_transfer_at: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Activity {
+    pub activity_by_id: LookupMap<TokenId, TokenActivity>,
+    pub acquired_at: LookupMap<(AccountId, TokenId), u64>,
+}
+
+impl MultiToken {
+    /// Start recording activity of tokens minted from now on
+    pub fn enable_activity<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix = prefix.into_storage_key();
+        self.activity = Some(Activity {
+            activity_by_id: LookupMap::new([prefix.clone(), "t".into()].concat()),
+            acquired_at: LookupMap::new([prefix, "a".into()].concat()),
+        });
+    }
+
+    /// Record issue of token and first holding of its minter
+    pub(crate) fn internal_activity_minted(&mut self, token_id: &TokenId, owner_id: &AccountId, amount: u128) {
+        if let Some(activity) = self.activity.as_mut() {
+            let now = env::block_timestamp();
+            activity.activity_by_id.insert(token_id, &TokenActivity { issued_at: now, last_transfer_at: None });
+            if amount > 0 {
+                activity.acquired_at.insert(&(owner_id.clone(), token_id.clone()), &now);
+            }
+        }
+    }
+
+    pub(crate) fn internal_activity_transferred(&mut self, token_id: &TokenId) {
+        if let Some(activity) = self.activity.as_mut() {
+            // Tokens minted before activity was enabled have no record to update
+            if let Some(mut record) = activity.activity_by_id.get(token_id) {
+                record.last_transfer_at = Some(env::block_timestamp());
+                activity.activity_by_id.insert(token_id, &record);
+            }
+        }
+    }
+
+    /// Track holding of account after its balance changed from `old` to `new`
+    pub(crate) fn internal_activity_balance_changed(
+        &mut self,
+        token_id: &TokenId,
+        account_id: &AccountId,
+        old: u128,
+        new: u128,
+    ) {
+        if let Some(activity) = self.activity.as_mut() {
+            if old == 0 && new > 0 {
+                activity.acquired_at.insert(&(account_id.clone(), token_id.clone()), &env::block_timestamp());
+            } else if old > 0 && new == 0 {
+                activity.acquired_at.remove(&(account_id.clone(), token_id.clone()));
+            }
+        }
+    }
+}
+
+impl MultiTokenActivity for MultiToken {
+    fn mt_token_activity(&self, token_id: TokenId) -> Option<TokenActivityView> {
+        let activity = expect_extension(self.activity.as_ref(), "Activity", Entity::Contract);
+        activity.activity_by_id.get(&token_id).map(|record| TokenActivityView {
+            issued_at: U64(record.issued_at),
+            last_transfer_at: record.last_transfer_at.map(U64),
+        })
+    }
+
+    fn mt_first_acquired_at(&self, account_id: AccountId, token_id: TokenId) -> Option<U64> {
+        let activity = expect_extension(self.activity.as_ref(), "Activity", Entity::Contract);
+        activity.acquired_at.get(&(account_id, token_id)).map(U64)
+    }
+}
@@ -0,0 +1,33 @@
+/*! Activity timestamps of tokens for provenance displays
+
+Contract records when every token was issued and last transferred, and since when each account
+holds it, so wallets can show "held since 2022" without an indexer. Timestamps are written in the
+same mint, transfer and balance paths that already touch the token. Holding starts when balance
+of account becomes positive and is forgotten once it drops to 0.
+ */
+
+mod activity_impl;
+
+pub use activity_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U64;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenActivityView {
+    /// Block timestamp of mint in nanoseconds
+    pub issued_at: U64,
+    /// Block timestamp of last transfer in nanoseconds, `None` if token never moved
+    pub last_transfer_at: Option<U64>,
+}
+
+pub trait MultiTokenActivity {
+    /// Get activity of token, `None` if token was minted before activity was tracked
+    fn mt_token_activity(&self, token_id: TokenId) -> Option<TokenActivityView>;
+
+    /// Get block timestamp in nanoseconds since which account holds positive balance of token
+    fn mt_first_acquired_at(&self, account_id: AccountId, token_id: TokenId) -> Option<U64>;
+}
@@ -0,0 +1,284 @@
+use near_sdk::{env, ext_contract, AccountId, Balance, Promise, require};
+use near_sdk::json_types::U128;
+use near_sdk::collections::TreeMap;
+
+use crate::multi_token::{
+    core::{MultiToken, StorageKey},
+    errors::MtError,
+    gas,
+    token::{Approval, TokenId},
+    utils::{expect_extension, Entity, StorageTracker, unauthorized_assert},
+};
+use crate::multi_token::approval::{
+    ext_approve_resolver, ApproveMsg, GAS_FOR_RESOLVE_APPROVE, MAX_APPROVALS_PER_TOKEN,
+};
+
+const NO_DEPOSIT: Balance = 0;
+
+#[ext_contract(ext_approval_receiver)]
+pub trait MultiTokenReceiver {
+    fn mt_on_approve(&mut self,
+                     token_ids: Vec<TokenId>,
+                     amounts: Vec<U128>,
+                     owner_id: AccountId,
+                     approval_ids: Vec<u64>,
+                     msg: String);
+}
+
+/// `mt_on_approve` call to grantee: msg, tokens, amounts and approval IDs
+type ApproveCall = (String, Vec<TokenId>, Vec<U128>, Vec<u64>);
+
+impl MultiToken {
+    /// Store approval without charging for storage, so batch can be charged once
+    ///
+    /// returns: new approval, owner's balance and storage used by approval and its grantee index in bytes
+    pub fn internal_approve(
+        &mut self,
+        account_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+        share: Option<u16>,
+    ) -> (Approval, Balance, u64) {
+        // Get owner & check if caller is authorized
+        let owner_id = self.owner_by_id.get(token_id).expect("This token does not exist");
+        unauthorized_assert(&owner_id);
+
+        // Such approvals only waste storage
+        if account_id == &owner_id {
+            MtError::ApproveSelf.panic();
+        }
+        if account_id == &env::current_account_id() {
+            MtError::ApproveContract.panic();
+        }
+
+        // Get the balance to check if user have enough tokens
+        let balance = self.internal_unwrap_balance_of(token_id, &owner_id);
+        require!(balance >= amount, "Not enough balance to approve");
+
+        // Unwrap to check if approval supported
+        let approvals_by_id = expect_extension(self.approvals_by_id.as_mut(), "Approval Management", Entity::Contract);
+        let next_id = expect_extension(self.next_approval_id_by_id.as_mut(), "Approval Management", Entity::Contract);
+
+        let mut approvals = approvals_by_id.get(token_id).unwrap_or_default();
+
+        // Check for approvals limit. Re-approving existing grantee doesn't take another slot
+        require!(
+            approvals.contains_key(account_id) || approvals.len() < MAX_APPROVALS_PER_TOKEN,
+            "Token reached approvals limit"
+        );
+
+        let approval_id = expect_extension(next_id.get(token_id), "Approval Management", Entity::Token);
+
+        // Measured rather than computed, grantee index may or may not get a new entry
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        next_id.insert(token_id, &(approval_id + 1));
+
+        let new_approval = Approval { amount: U128(amount), approval_id, share };
+
+        let old_approval = approvals.insert(account_id.clone(), new_approval.clone());
+        approvals_by_id.insert(token_id, &approvals);
+
+        self.internal_index_grantee(account_id, &owner_id, token_id);
+        tracker.stop();
+
+        if old_approval.is_none() {
+            self.internal_count_approval(&owner_id);
+        }
+
+        (new_approval, balance, tracker.bytes_added)
+    }
+
+    pub fn internal_revoke(&mut self, token_id: TokenId, account_id: &AccountId) {
+        // It's impossible that token does not have owner, so i'll just unwrap the value
+        let owner = self.owner_by_id.get(&token_id).unwrap();
+
+        unauthorized_assert(&owner);
+
+        self.internal_remove_approval(&token_id, &owner, account_id);
+    }
+
+    /// Change amount of existing approval in place, removing it if amount becomes 0
+    pub fn internal_adjust_approval(&mut self, token_id: &TokenId, account_id: &AccountId, adjust: impl FnOnce(Balance) -> Balance) {
+        let owner_id = self.owner_by_id.get(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        unauthorized_assert(&owner_id);
+
+        let approvals_by_id = expect_extension(self.approvals_by_id.as_mut(), "Approval Management", Entity::Contract);
+        let mut approvals = approvals_by_id.get(token_id).unwrap_or_default();
+        let approval = approvals.get_mut(account_id).expect("Account is not approved");
+        require!(approval.share.is_none(), "Approval for a share of balance can't be adjusted");
+
+        let amount = adjust(approval.amount.0);
+        if amount == 0 {
+            self.internal_remove_approval(token_id, &owner_id, account_id);
+            return;
+        }
+        approval.amount = U128(amount);
+        approvals_by_id.insert(token_id, &approvals);
+
+        let balance = self.internal_unwrap_balance_of(token_id, &owner_id);
+        require!(balance >= amount, "Not enough balance to approve");
+    }
+
+    /// Remove approval of `account_id` without checking the caller
+    pub fn internal_remove_approval(&mut self, token_id: &TokenId, owner_id: &AccountId, account_id: &AccountId) {
+        // Get all approvals for token, will panic if approval extension is not used for contract or token
+        let approvals = expect_extension(self.approvals_by_id.as_mut(), "Approval Management", Entity::Contract);
+        let mut approvals_by_token = expect_extension(approvals.get(token_id), "Approval Management", Entity::Token);
+
+        // Remove approval for user & also clean map to save space it it's empty
+        approvals_by_token.remove(account_id);
+
+        if approvals_by_token.is_empty() {
+            approvals.remove(token_id);
+        } else {
+            approvals.insert(token_id, &approvals_by_token);
+        }
+
+        self.internal_unindex_grantee(account_id, owner_id, token_id);
+    }
+
+    /// Take `amount` moved by grantee out of its approval. Approval for a share follows
+    /// owner's balance and stays as is. Used up fixed approval is kept with zero amount,
+    /// so refund can be given back to it, owner frees its storage with revoke
+    pub fn internal_spend_approval(&mut self, token_id: &TokenId, account_id: &AccountId, amount: Balance) {
+        self.internal_update_fixed_approval(token_id, account_id, None, |allowance| allowance.saturating_sub(amount));
+    }
+
+    /// Give `amount` refunded by receiver back to approval `approval_id` it was spent from.
+    /// Approval replaced or revoked in the meantime is left alone
+    pub fn internal_restore_approval(&mut self, token_id: &TokenId, account_id: &AccountId, approval_id: u64, amount: Balance) {
+        self.internal_update_fixed_approval(token_id, account_id, Some(approval_id), |allowance| {
+            allowance.saturating_add(amount)
+        });
+    }
+
+    fn internal_update_fixed_approval(
+        &mut self,
+        token_id: &TokenId,
+        account_id: &AccountId,
+        approval_id: Option<u64>,
+        update: impl FnOnce(Balance) -> Balance,
+    ) {
+        let approvals_by_id = match self.approvals_by_id.as_mut() {
+            Some(approvals_by_id) => approvals_by_id,
+            None => return,
+        };
+        let mut approvals = approvals_by_id.get(token_id).unwrap_or_default();
+        match approvals.get_mut(account_id) {
+            Some(approval) if approval.share.is_none() && approval_id.map_or(true, |id| id == approval.approval_id) => {
+                approval.amount = U128(update(approval.amount.0));
+                approvals_by_id.insert(token_id, &approvals);
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `mt_on_approve` on grantee. With per-token messages, tokens sharing the same message
+    /// are notified in one call, tokens without message are skipped.
+    /// In strict mode notified approvals stay pending until `mt_resolve_approve`
+    pub fn internal_notify_approved(
+        &mut self,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approval_ids: Vec<u64>,
+        msg: ApproveMsg,
+    ) -> Option<Promise> {
+        let owner_id = env::predecessor_account_id();
+
+        let calls: Vec<ApproveCall> = match msg {
+            ApproveMsg::Single(msg) => vec![(msg, token_ids, amounts, approval_ids)],
+            ApproveMsg::PerToken(msgs) => {
+                require!(msgs.len() == token_ids.len(), "Length of token_ids and msg must be equal");
+
+                let mut calls: Vec<ApproveCall> = vec![];
+                for (idx, msg) in msgs.into_iter().enumerate() {
+                    let msg = match msg {
+                        Some(msg) => msg,
+                        None => continue,
+                    };
+                    match calls.iter_mut().find(|(existing, ..)| existing == &msg) {
+                        Some((_, ids, amts, approvals)) => {
+                            ids.push(token_ids[idx].clone());
+                            amts.push(amounts[idx]);
+                            approvals.push(approval_ids[idx]);
+                        }
+                        None => calls.push((msg, vec![token_ids[idx].clone()], vec![amounts[idx]], vec![approval_ids[idx]])),
+                    }
+                }
+                calls
+            }
+        };
+
+        if calls.is_empty() {
+            return None;
+        }
+
+        let strict = self.strict_approvals.is_some();
+        let calls_len = calls.len() as u64;
+        let reserved = gas::gas_reserved_for_approve(calls_len, strict);
+        let gas_per_call = gas::gas_per_batch_element(env::prepaid_gas(), reserved, calls_len);
+
+        calls
+            .into_iter()
+            .map(|(msg, token_ids, amounts, approval_ids)| {
+                let on_approve = ext_approval_receiver::mt_on_approve(
+                    token_ids.clone(),
+                    amounts,
+                    owner_id.clone(),
+                    approval_ids.clone(),
+                    msg,
+                    account_id.clone(),
+                    NO_DEPOSIT,
+                    gas_per_call,
+                );
+                if !strict {
+                    return on_approve;
+                }
+
+                token_ids
+                    .iter()
+                    .zip(approval_ids.iter())
+                    .for_each(|(token_id, approval_id)| self.internal_mark_pending(&account_id, token_id, *approval_id));
+
+                on_approve.then(ext_approve_resolver::mt_resolve_approve(
+                    owner_id.clone(),
+                    account_id.clone(),
+                    token_ids,
+                    approval_ids,
+                    env::current_account_id(),
+                    NO_DEPOSIT,
+                    GAS_FOR_RESOLVE_APPROVE,
+                ))
+            })
+            .reduce(|all, promise| all.and(promise))
+    }
+
+    /// Remember that `grantee_id` is approved by `owner_id` in token
+    pub fn internal_index_grantee(&mut self, grantee_id: &AccountId, owner_id: &AccountId, token_id: &TokenId) {
+        if let Some(by_grantee) = self.approvals_by_grantee.as_mut() {
+            let mut entries = by_grantee.get(grantee_id).unwrap_or_else(|| {
+                TreeMap::new(StorageKey::ApprovalsByGranteeInner {
+                    account_hash: env::sha256(grantee_id.as_bytes()),
+                })
+            });
+            entries.insert(&(owner_id.clone(), token_id.clone()), &());
+            by_grantee.insert(grantee_id, &entries);
+        }
+    }
+
+    /// Forget that `grantee_id` is approved by `owner_id` in token
+    pub fn internal_unindex_grantee(&mut self, grantee_id: &AccountId, owner_id: &AccountId, token_id: &TokenId) {
+        if let Some(by_grantee) = self.approvals_by_grantee.as_mut() {
+            if let Some(mut entries) = by_grantee.get(grantee_id) {
+                entries.remove(&(owner_id.clone(), token_id.clone()));
+                if entries.is_empty() {
+                    by_grantee.remove(grantee_id);
+                } else {
+                    by_grantee.insert(grantee_id, &entries);
+                }
+            }
+        }
+    }
+}
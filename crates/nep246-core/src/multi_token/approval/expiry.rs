@@ -0,0 +1,82 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap};
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::{ApprovalId, MultiToken},
+    token::{TokenId, MAX_SHARE_BPS},
+    utils::{expect_extension, Entity},
+};
+
+/// Approvals that stop working after given time
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ApprovalExpiry {
+    /// Share of freed storage cost paid to caller of `mt_sweep_expired`, in basis points
+    pub keeper_share_bps: u16,
+    /// Approval expiring at given time, ordered by expiry
+    pub queue: TreeMap<(u64, TokenId, AccountId), ApprovalId>,
+    /// Approval ID and its expiry by (token, grantee)
+    pub expiry_by_approval: LookupMap<(TokenId, AccountId), (ApprovalId, u64)>,
+}
+
+impl MultiToken {
+    /// Allow token owners to limit lifetime of approvals
+    pub fn enable_approval_expiry<P: IntoStorageKey>(&mut self, prefix: P, keeper_share_bps: u16) {
+        require!(keeper_share_bps <= MAX_SHARE_BPS, "Keeper share cannot exceed 10000");
+
+        let prefix = prefix.into_storage_key();
+        self.approval_expiry = Some(ApprovalExpiry {
+            keeper_share_bps,
+            queue: TreeMap::new([prefix.clone(), "q".into()].concat()),
+            expiry_by_approval: LookupMap::new([prefix, "e".into()].concat()),
+        });
+    }
+
+    /// Whether approval of `account_id` in token has expired
+    pub fn internal_is_approval_expired(&self, account_id: &AccountId, token_id: &TokenId, approval_id: ApprovalId) -> bool {
+        self.approval_expiry.as_ref().map_or(false, |expiry| {
+            match expiry.expiry_by_approval.get(&(token_id.clone(), account_id.clone())) {
+                Some((expiring_id, expires_at)) => expiring_id == approval_id && env::block_timestamp() >= expires_at,
+                None => false,
+            }
+        })
+    }
+
+    pub fn internal_clear_expiry(&mut self, token_id: &TokenId, account_id: &AccountId) {
+        let expiry = self.approval_expiry.as_mut().unwrap();
+        if let Some((_, expires_at)) = expiry.expiry_by_approval.remove(&(token_id.clone(), account_id.clone())) {
+            expiry.queue.remove(&(expires_at, token_id.clone(), account_id.clone()));
+        }
+    }
+
+    /// Remove up to `limit` expired approvals, oldest first. Returns number of removed approvals
+    pub fn internal_sweep_expired_approvals(&mut self, limit: u64) -> u64 {
+        let now = env::block_timestamp();
+
+        let mut removed = 0;
+        for _ in 0..limit {
+            let expiry = expect_extension(self.approval_expiry.as_mut(), "Approval expiry", Entity::Contract);
+            let key = match expiry.queue.min() {
+                Some(key) if key.0 <= now => key,
+                _ => break,
+            };
+            let (_, token_id, account_id) = key.clone();
+            let approval_id = expiry.queue.remove(&key).unwrap();
+            expiry.expiry_by_approval.remove(&(token_id.clone(), account_id.clone()));
+
+            // Approval could be revoked or replaced by re-approval since expiry was set
+            let current = self
+                .approvals_by_id
+                .as_ref()
+                .and_then(|by_id| by_id.get(&token_id))
+                .and_then(|approvals| approvals.get(&account_id).map(|approval| approval.approval_id));
+            if current == Some(approval_id) {
+                let owner_id = self.owner_by_id.get(&token_id).unwrap();
+                self.internal_remove_approval(&token_id, &owner_id, &account_id);
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+}
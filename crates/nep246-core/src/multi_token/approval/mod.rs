@@ -0,0 +1,58 @@
+mod approval_impl;
+mod expiry;
+mod rate_limit;
+mod scoped;
+mod series;
+mod strict;
+
+use std::collections::HashMap;
+pub use approval_impl::*;
+pub use expiry::*;
+pub use rate_limit::*;
+pub use scoped::*;
+pub use series::*;
+pub use strict::*;
+
+use crate::multi_token::token::{Approval, TokenId};
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Limit for amount of approvals
+/// See - https://github.com/shipsgold/NEPs/blob/master/specs/Standards/MultiToken/ApprovalManagement.md#why-must-mt_approve-panic-if-mt_revoke_all-would-fail-later
+pub const MAX_APPROVALS_PER_TOKEN: usize = 99;
+
+/// Most tokens `mt_revoke` processes in one call
+pub const MAX_REVOKE_PER_CALL: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenApproval {
+    approval_owner_id: AccountId,
+    approved_account_ids: HashMap<AccountId, Approval>,
+}
+
+impl TokenApproval {
+    pub fn new(approval_owner_id: AccountId, approved_account_ids: HashMap<AccountId, Approval>) -> Self {
+        Self { approval_owner_id, approved_account_ids }
+    }
+}
+
+/// Message passed to `mt_on_approve`. Either a single string for all tokens or
+/// an array positionally matched to `token_ids`, where `null` means no call for that token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(untagged)]
+pub enum ApproveMsg {
+    Single(String),
+    PerToken(Vec<Option<String>>),
+}
+
+/// Approval granted to account, as seen from grantee's side
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GranteeApproval {
+    pub owner_id: AccountId,
+    pub token_id: TokenId,
+    pub approval: Approval,
+}
@@ -0,0 +1,43 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, AccountId, EpochHeight, IntoStorageKey};
+
+use crate::multi_token::{core::MultiToken, utils::unauthorized_assert};
+
+/// Limit of approvals that single owner can grant per epoch
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ApprovalRateLimit {
+    pub max_per_epoch: u64,
+    /// Epoch of last approval and number of approvals granted in it
+    pub usage: LookupMap<AccountId, (EpochHeight, u64)>,
+}
+
+impl MultiToken {
+    /// Limit number of new approvals each owner can grant per epoch
+    pub fn enable_approval_rate_limit<P: IntoStorageKey>(&mut self, prefix: P, max_per_epoch: u64) {
+        self.approval_rate_limit = Some(ApprovalRateLimit { max_per_epoch, usage: LookupMap::new(prefix) });
+    }
+
+    /// Change limit of approvals per epoch. Can only be called by contract owner
+    pub fn set_approval_rate_limit(&mut self, max_per_epoch: u64) {
+        unauthorized_assert(&self.owner_id);
+
+        self.approval_rate_limit.as_mut().expect("Approval rate limit is not enabled").max_per_epoch =
+            max_per_epoch;
+    }
+
+    /// Count new approval of `owner_id`, panics if limit is reached
+    pub fn internal_count_approval(&mut self, owner_id: &AccountId) {
+        if let Some(limit) = self.approval_rate_limit.as_mut() {
+            let epoch = env::epoch_height();
+            let count = match limit.usage.get(owner_id) {
+                Some((last_epoch, count)) if last_epoch == epoch => count,
+                _ => 0,
+            };
+
+            require!(count < limit.max_per_epoch, "Approvals limit per epoch reached");
+
+            limit.usage.insert(owner_id, &(epoch, count + 1));
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, AccountId, IntoStorageKey};
+
+use crate::multi_token::{core::MultiToken, token::TokenId};
+
+/// Most tokens one operator approval can list
+pub const MAX_OPERATOR_TOKENS: usize = 64;
+
+/// Scope of operator rights. Listed tokens are kept sorted, so lookup is a binary search
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OperatorScope {
+    pub token_ids: Option<Vec<TokenId>>,
+    pub expires_at: Option<u64>,
+}
+
+/// Operators with blanket rights over tokens of owners
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ScopedOperators {
+    /// Scope by (owner, operator)
+    pub scope_by_operator: LookupMap<(AccountId, AccountId), OperatorScope>,
+}
+
+impl MultiToken {
+    /// Allow owners to approve operators for all or listed tokens
+    pub fn enable_scoped_operators<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.scoped_operators = Some(ScopedOperators { scope_by_operator: LookupMap::new(prefix) });
+    }
+
+    /// Whether `operator_id` has unexpired rights over token of `owner_id`
+    pub fn internal_is_scoped_operator(&self, owner_id: &AccountId, operator_id: &AccountId, token_id: &TokenId) -> bool {
+        let operators = match self.scoped_operators.as_ref() {
+            Some(operators) => operators,
+            None => return false,
+        };
+        let scope = match operators.scope_by_operator.get(&(owner_id.clone(), operator_id.clone())) {
+            Some(scope) => scope,
+            None => return false,
+        };
+        if scope.expires_at.map_or(false, |expires_at| env::block_timestamp() >= expires_at) {
+            return false;
+        }
+        scope.token_ids.map_or(true, |token_ids| token_ids.binary_search(token_id).is_ok())
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{core::MultiToken, token::TokenId};
+
+/// Limit of series one owner can approve for a single account
+pub const MAX_SERIES_PER_GRANTEE: usize = 16;
+
+/// Approvals covering all tokens with ID prefix
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SeriesApprovals {
+    /// Amount per token by series prefix, for (owner, grantee)
+    pub by_grantee: LookupMap<(AccountId, AccountId), HashMap<String, Balance>>,
+    /// Amount already moved through series approvals, by (owner, grantee, token)
+    pub spent: LookupMap<(AccountId, AccountId, TokenId), Balance>,
+}
+
+impl MultiToken {
+    /// Allow owners to approve accounts for whole series of tokens
+    pub fn enable_series_approvals<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix = prefix.into_storage_key();
+        self.series_approvals = Some(SeriesApprovals {
+            by_grantee: LookupMap::new([prefix.clone(), "g".into()].concat()),
+            spent: LookupMap::new([prefix, "s".into()].concat()),
+        });
+    }
+
+    /// Remaining allowance of `grantee_id` in token of `owner_id`. Longest matching prefix wins
+    pub fn internal_series_allowance(&self, owner_id: &AccountId, grantee_id: &AccountId, token_id: &TokenId) -> Balance {
+        let series = match self.series_approvals.as_ref() {
+            Some(series) => series,
+            None => return 0,
+        };
+        let key = (owner_id.clone(), grantee_id.clone());
+        let per_token = series
+            .by_grantee
+            .get(&key)
+            .and_then(|prefixes| {
+                prefixes
+                    .into_iter()
+                    .filter(|(prefix, _)| token_id.starts_with(prefix.as_str()))
+                    .max_by_key(|(prefix, _)| prefix.len())
+                    .map(|(_, amount)| amount)
+            })
+            .unwrap_or(0);
+        let spent = series.spent.get(&(key.0, key.1, token_id.clone())).unwrap_or(0);
+
+        per_token.saturating_sub(spent)
+    }
+
+    /// Consume `amount` of series allowance. Returns false if allowance is not enough and nothing is consumed
+    pub(crate) fn internal_use_series_approval(
+        &mut self,
+        owner_id: &AccountId,
+        grantee_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+    ) -> bool {
+        if self.internal_series_allowance(owner_id, grantee_id, token_id) < amount {
+            return false;
+        }
+
+        let series = self.series_approvals.as_mut().unwrap();
+        let key = (owner_id.clone(), grantee_id.clone(), token_id.clone());
+        let spent = series.spent.get(&key).unwrap_or(0);
+        series.spent.insert(&key, &(spent + amount));
+        true
+    }
+}
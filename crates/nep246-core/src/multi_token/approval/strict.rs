@@ -0,0 +1,49 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{ext_contract, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::{ApprovalId, MultiToken},
+    token::TokenId,
+};
+
+pub use crate::multi_token::gas::GAS_FOR_RESOLVE_APPROVE;
+
+#[ext_contract(ext_approve_resolver)]
+trait ApproveResolver {
+    fn mt_resolve_approve(
+        &mut self,
+        owner_id: AccountId,
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+        approval_ids: Vec<ApprovalId>,
+    );
+}
+
+/// Approvals that are only usable after grantee accepted them in `mt_on_approve`
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct StrictApprovals {
+    /// Approval IDs waiting for confirmation by (grantee, token)
+    pub pending: LookupMap<(AccountId, TokenId), ApprovalId>,
+}
+
+impl MultiToken {
+    /// Require grantees to successfully process `mt_on_approve` before approval can be used.
+    /// Approvals granted without `msg` are not affected
+    pub fn enable_strict_approvals<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.strict_approvals = Some(StrictApprovals { pending: LookupMap::new(prefix) });
+    }
+
+    /// Whether approval of `account_id` in token still waits for confirmation
+    pub fn internal_is_approval_pending(&self, account_id: &AccountId, token_id: &TokenId) -> bool {
+        self.strict_approvals
+            .as_ref()
+            .map_or(false, |strict| strict.pending.contains_key(&(account_id.clone(), token_id.clone())))
+    }
+
+    pub fn internal_mark_pending(&mut self, account_id: &AccountId, token_id: &TokenId, approval_id: ApprovalId) {
+        if let Some(strict) = self.strict_approvals.as_mut() {
+            strict.pending.insert(&(account_id.clone(), token_id.clone()), &approval_id);
+        }
+    }
+}
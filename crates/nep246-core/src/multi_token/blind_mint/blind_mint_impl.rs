@@ -0,0 +1,130 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, Vector};
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey, Promise};
+
+use crate::multi_token::{
+    core::MultiToken,
+    metadata::TokenMetadata,
+    token::Token,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{commitment_of, BlindCommitView, MultiTokenBlindMint};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct BlindCommit {
+    pub commitment: Vec<u8>,
+    pub committed_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct BlindMint {
+    pub price: Balance,
+    /// Blocks between commitment and its reveal
+    pub reveal_delay: u64,
+    pub pool: Vector<TokenMetadata>,
+    pub commits: LookupMap<AccountId, BlindCommit>,
+    /// Commitments not revealed yet, each reserves one item of the pool
+    pub pending: u64,
+}
+
+/// Index of pool item drawn for `commitment`, seeded by random seed of current block and `secret`
+fn draw_index(commitment: &[u8], secret: &[u8], len: u64) -> u64 {
+    let entropy = env::sha256(&[env::random_seed().as_slice(), commitment, secret].concat());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&entropy[..8]);
+    u64::from_le_bytes(bytes) % len
+}
+
+impl MultiToken {
+    /// Run commit-reveal drop selling tokens for `price`, revealable `reveal_delay` blocks after purchase
+    pub fn enable_blind_mint<P: IntoStorageKey>(&mut self, prefix: P, price: Balance, reveal_delay: u64) {
+        require!(reveal_delay > 0, "Reveal delay cannot be 0");
+        let prefix = prefix.into_storage_key();
+        self.blind_mint = Some(BlindMint {
+            price,
+            reveal_delay,
+            pool: Vector::new([prefix.clone(), "p".into()].concat()),
+            commits: LookupMap::new([prefix, "c".into()].concat()),
+            pending: 0,
+        });
+    }
+
+}
+
+impl MultiTokenBlindMint for MultiToken {
+    fn mt_blind_add_metadata(&mut self, metadata: Vec<TokenMetadata>) {
+        unauthorized_assert(&self.owner_id);
+        require!(!metadata.is_empty(), "Nothing to add");
+        let drop = expect_extension(self.blind_mint.as_mut(), "Blind mint", Entity::Contract);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        drop.pool.extend(metadata);
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_blind_commit(&mut self, commitment: Base64VecU8) {
+        require!(commitment.0.len() == 32, "Commitment must be sha256 hash");
+        let account_id = env::predecessor_account_id();
+        let drop = expect_extension(self.blind_mint.as_mut(), "Blind mint", Entity::Contract);
+        require!(drop.pool.len() > drop.pending, "Drop is sold out");
+        require!(!drop.commits.contains_key(&account_id), "Reveal previous commitment first");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        drop.commits.insert(&account_id, &BlindCommit { commitment: commitment.0, committed_at: env::block_height() });
+        tracker.stop();
+        drop.pending += 1;
+
+        let required = drop.price + env::storage_byte_cost() * Balance::from(tracker.bytes_added);
+        let attached = env::attached_deposit();
+        require!(required <= attached, format!("Must attach {} yoctoNEAR to cover price and storage", required));
+        if attached - required > 1 {
+            Promise::new(account_id).transfer(attached - required);
+        }
+    }
+
+    fn mt_blind_reveal(&mut self, secret: Base64VecU8) -> Token {
+        self.assert_storage_call_deposit();
+        let account_id = env::predecessor_account_id();
+        let drop = expect_extension(self.blind_mint.as_mut(), "Blind mint", Entity::Contract);
+        let commit = drop.commits.get(&account_id).unwrap_or_else(|| env::panic_str("Nothing to reveal"));
+        require!(
+            env::block_height() >= commit.committed_at + drop.reveal_delay,
+            "Commitment can't be revealed yet"
+        );
+        require!(commitment_of(&account_id, &secret.0) == commit.commitment, "Secret doesn't match commitment");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        drop.commits.remove(&account_id);
+        drop.pending -= 1;
+        let index = draw_index(&commit.commitment, &secret.0, drop.pool.len());
+        let metadata = drop.pool.swap_remove(index);
+        let token = self.internal_mint(account_id.clone(), Some(1), Some(metadata), None);
+        tracker.stop();
+        tracker.refund(account_id);
+
+        token
+    }
+
+    fn mt_blind_commitment(&self, account_id: AccountId) -> Option<BlindCommitView> {
+        let drop = expect_extension(self.blind_mint.as_ref(), "Blind mint", Entity::Contract);
+        drop.commits.get(&account_id).map(|commit| BlindCommitView {
+            commitment: commit.commitment.into(),
+            reveal_from: U64(commit.committed_at + drop.reveal_delay),
+        })
+    }
+
+    fn mt_blind_price(&self) -> U128 {
+        U128(expect_extension(self.blind_mint.as_ref(), "Blind mint", Entity::Contract).price)
+    }
+
+    fn mt_blind_available(&self) -> u64 {
+        let drop = expect_extension(self.blind_mint.as_ref(), "Blind mint", Entity::Contract);
+        drop.pool.len() - drop.pending
+    }
+}
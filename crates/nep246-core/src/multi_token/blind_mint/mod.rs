@@ -0,0 +1,68 @@
+/*! Commit-reveal minting for fair randomized drops
+
+Owner fills a pool with metadata of tokens to drop. Buyer picks a random secret and pays the
+price with `mt_blind_commit(commitment)`, where commitment is [commitment_of] of its account and
+the secret. At least `reveal_delay` blocks later buyer reveals the secret with `mt_blind_reveal`,
+and gets one unit of a new token with metadata drawn from the pool.
+
+Draw is seeded by random seed of the reveal block mixed with the secret. Seed is not known
+when buying and secret is not known to anyone else, so neither the buyer nor snipers
+watching commitments can simulate which metadata a commitment gets.
+
+Every commitment reserves one item of the pool, so buyers never pay for a sold out drop.
+Proceeds stay on the contract account.
+ */
+
+mod blind_mint_impl;
+
+pub use blind_mint_impl::*;
+
+use crate::multi_token::metadata::TokenMetadata;
+use crate::multi_token::token::Token;
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId};
+
+/// Commitment to `secret` of `account_id`: sha256 of account ID bytes followed by the secret
+pub fn commitment_of(account_id: &AccountId, secret: &[u8]) -> Vec<u8> {
+    env::sha256(&[account_id.as_bytes(), secret].concat())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlindCommitView {
+    pub commitment: Base64VecU8,
+    /// First block height at which commitment can be revealed
+    pub reveal_from: U64,
+}
+
+pub trait MultiTokenBlindMint {
+    /// Add metadata of tokens to the pool of the drop
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Caller must attach enough deposit to cover storage, the rest is refunded
+    fn mt_blind_add_metadata(&mut self, metadata: Vec<TokenMetadata>);
+
+    /// Buy one token of the drop, committing to a secret revealed later
+    ///
+    /// # Requirements:
+    /// * Caller must attach the price and storage of the commitment, the rest is refunded
+    /// * Contract MUST panic if caller has unrevealed commitment or pool is sold out
+    fn mt_blind_commit(&mut self, commitment: Base64VecU8);
+
+    /// Reveal secret of caller's commitment and mint token with metadata drawn from the pool
+    ///
+    /// # Requirements:
+    /// * Caller must attach enough deposit to cover storage of the token, the rest is refunded
+    /// * Contract MUST panic if secret doesn't match the commitment or reveal delay is not over
+    fn mt_blind_reveal(&mut self, secret: Base64VecU8) -> Token;
+
+    fn mt_blind_commitment(&self, account_id: AccountId) -> Option<BlindCommitView>;
+
+    /// Price of one token of the drop
+    fn mt_blind_price(&self) -> U128;
+
+    /// Number of items in the pool not reserved by commitments
+    fn mt_blind_available(&self) -> u64;
+}
@@ -0,0 +1,191 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey, Promise};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::{assert_batch_lengths, MtError},
+    metadata::TokenMetadata,
+    json_types::Amount,
+    token::{Token, TokenId},
+    utils::StorageTracker,
+};
+
+use super::{DepositRefundPolicy, MultiTokenBurn};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MintDeposits {
+    pub policy: DepositRefundPolicy,
+    /// Payer and amount of storage deposit for each token
+    pub deposit_by_id: LookupMap<TokenId, (AccountId, Balance)>,
+}
+
+impl MultiToken {
+    /// Start recording storage deposits of mints made with `internal_mint_refundable`
+    pub fn enable_mint_deposits<P: IntoStorageKey>(&mut self, prefix: P, policy: DepositRefundPolicy) {
+        self.mint_deposits = Some(MintDeposits { policy, deposit_by_id: LookupMap::new(prefix) });
+    }
+
+    /// Mint token and remember storage deposit paid by `payer_id`, so it can be refunded
+    /// when the last unit is burned. Unused attached deposit is refunded to `payer_id` right away.
+    pub fn internal_mint_refundable(
+        &mut self,
+        owner_id: AccountId,
+        owner_amount: Option<Balance>,
+        metadata: Option<TokenMetadata>,
+        payer_id: AccountId,
+    ) -> Token {
+        require!(self.mint_deposits.is_some(), "Refundable mint is not supported by contract");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        let token = self.internal_mint_with_refund(owner_id.clone(), owner_amount, metadata, None);
+        let deposits = self.mint_deposits.as_mut().unwrap();
+        // Placeholder to account storage of the record itself
+        deposits.deposit_by_id.insert(&token.token_id, &(payer_id.clone(), 0));
+
+        tracker.stop();
+
+        let deposit = env::storage_byte_cost() * Balance::from(tracker.bytes_added);
+        deposits.deposit_by_id.insert(&token.token_id, &(payer_id.clone(), deposit));
+        tracker.refund(payer_id);
+
+        self.emit_mint(&owner_id, &token.token_id, &token.supply.0, None);
+
+        token
+    }
+
+    /// Burn tokens of `owner_id`. Token minted with refundable deposit is deleted when its supply
+    /// reaches 0, and storage it released is refunded, up to the recorded deposit
+    pub fn internal_burn(
+        &mut self,
+        owner_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+        memo: Option<String>,
+    ) {
+        require!(amount > 0, "Amount cannot be 0");
+
+        self.internal_withdraw(token_id, owner_id, amount);
+        self.emit_burn(owner_id, token_id, &amount, memo);
+
+        if self.total_supply.get(token_id).unwrap_or(0) > 0 {
+            return;
+        }
+
+        let (payer_id, deposit) = match self.mint_deposits.as_ref().and_then(|deposits| deposits.deposit_by_id.get(token_id)) {
+            Some(record) => record,
+            None => return,
+        };
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        self.mint_deposits.as_mut().unwrap().deposit_by_id.remove(token_id);
+        self.internal_delete_token(token_id);
+        tracker.stop();
+
+        let refund = deposit.min(env::storage_byte_cost() * Balance::from(tracker.bytes_released));
+        let receiver_id = match self.mint_deposits.as_ref().unwrap().policy {
+            DepositRefundPolicy::Burner => owner_id.clone(),
+            DepositRefundPolicy::Payer => payer_id,
+        };
+        if refund > 0 {
+            Promise::new(receiver_id).transfer(refund);
+        }
+    }
+
+    /// Remove all records of token with no supply left
+    fn internal_delete_token(&mut self, token_id: &TokenId) {
+        let owner_id = self.owner_by_id.remove(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+
+        if let Some(mut balances) = self.balances_per_token.remove(token_id) {
+            if let Some(mut holders) = self.holders_per_token.remove(token_id) {
+                for account_id in holders.to_vec() {
+                    balances.remove(&account_id);
+                    self.internal_drop_from_portfolio(&account_id, token_id);
+                }
+                holders.clear();
+            }
+            balances.remove(&owner_id);
+        }
+        self.total_supply.remove(token_id);
+
+        let metadata = self.token_metadata_by_id.as_mut().and_then(|by_id| by_id.remove(token_id));
+        let expires_at = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.expires_at.as_ref())
+            .and_then(|at| at.parse::<u64>().ok());
+        if let (Some(expiring), Some(expires_at)) = (self.expiring_tokens.as_mut(), expires_at) {
+            expiring.queue.remove(&(expires_at, token_id.clone()));
+        }
+        if let Some(order) = self.token_order.as_mut() {
+            if let Some(key) = order.key_by_id.remove(token_id) {
+                order.index.remove(&(key, token_id.clone()));
+            }
+        }
+        if let Some(kinds) = self.token_kinds.as_mut() {
+            kinds.kind_by_id.remove(token_id);
+        }
+        if let Some(royalties) = self.royalties.as_mut() {
+            royalties.royalty_by_id.remove(token_id);
+            royalties.secondary_sold.remove(token_id);
+        }
+        if let Some(locks) = self.transfer_locks.as_mut() {
+            locks.lock_until_by_id.remove(token_id);
+        }
+        if let Some(clawback) = self.clawback.as_mut() {
+            clawback.enabled_tokens.remove(token_id);
+        }
+        if let Some(min_amounts) = self.min_transfer_amounts.as_mut() {
+            min_amounts.by_id.remove(token_id);
+        }
+        if let Some(activity) = self.activity.as_mut() {
+            activity.activity_by_id.remove(token_id);
+        }
+        if let Some(next_approval_id) = self.next_approval_id_by_id.as_mut() {
+            next_approval_id.remove(token_id);
+        }
+        if let Some(approvals) = self.approvals_by_id.as_mut().and_then(|by_id| by_id.remove(token_id)) {
+            approvals
+                .keys()
+                .for_each(|grantee_id| self.internal_unindex_grantee(grantee_id, &owner_id, token_id));
+        }
+        if let Some(per_owner) = self.tokens_per_owner.as_mut() {
+            if let Some(mut token_ids) = per_owner.get(&owner_id) {
+                token_ids.remove(token_id);
+                if token_ids.is_empty() {
+                    per_owner.remove(&owner_id);
+                } else {
+                    per_owner.insert(&owner_id, &token_ids);
+                }
+            }
+        }
+    }
+}
+
+impl MultiTokenBurn for MultiToken {
+    fn mt_burn(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>, memo: Option<String>, batch_id: Option<String>) {
+        self.assert_call_deposit();
+        self.set_batch_id(batch_id);
+
+        assert_batch_lengths(token_ids.len(), &[amounts.len()]);
+
+        let owner_id = env::predecessor_account_id();
+        token_ids
+            .iter()
+            .zip(amounts.iter())
+            .for_each(|(token_id, amount)| self.internal_burn(&owner_id, token_id, amount.0, memo.clone()));
+    }
+
+    fn mt_burn_from_self(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>) {
+        self.assert_call_deposit();
+        assert_batch_lengths(token_ids.len(), &[amounts.len()]);
+
+        let owner_id = env::predecessor_account_id();
+        token_ids
+            .iter()
+            .zip(amounts.iter())
+            .for_each(|(token_id, amount)| self.internal_burn(&owner_id, token_id, amount.0, Some("consumed".to_string())));
+    }
+}
@@ -0,0 +1,45 @@
+mod burn_impl;
+
+pub use burn_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Who gets storage deposit back when the last unit of token is burned
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DepositRefundPolicy {
+    /// Account that burned the last unit
+    Burner,
+    /// Account that paid for mint
+    Payer,
+}
+
+/// Burning of tokens by their holders
+pub trait MultiTokenBurn {
+    /// Burn tokens of caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller does not have enough balance
+    /// * If token was minted with refundable deposit and this burn brings supply to 0,
+    ///   contract MUST delete the token and refund storage it released, up to the deposit,
+    ///   according to [DepositRefundPolicy]
+    ///
+    /// # Arguments:
+    /// * `token_ids` - tokens to burn
+    /// * `amounts` - how much to burn of each token
+    /// * `memo` - included in emitted event
+    /// * `batch_id` - optional ID echoed into every emitted event
+    fn mt_burn(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>, memo: Option<String>, batch_id: Option<String>);
+
+    /// Burn tokens of caller that it consumed, called by receivers from `mt_on_transfer`,
+    /// see [crate::multi_token::consumer]
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller does not have enough balance
+    fn mt_burn_from_self(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>);
+}
@@ -0,0 +1,150 @@
+use ed25519_dalek::Signature;
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::{env, require, AccountId, Balance, CurveType, PublicKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{Channel, ChannelId, ChannelView, ChannelVoucher, MultiTokenPaymentChannel};
+
+/// Whether `signature` of `message` was made with ed25519 `public_key`
+fn verify_ed25519(public_key: &PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    // First byte of the key is its curve type
+    let key = match ed25519_dalek::PublicKey::from_bytes(&public_key.as_bytes()[1..]) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+    match Signature::try_from(signature) {
+        Ok(signature) => key.verify_strict(message, &signature).is_ok(),
+        Err(_) => false,
+    }
+}
+
+impl MultiToken {
+    fn internal_expect_channel(&self, channel_id: ChannelId) -> Channel {
+        let channels = expect_extension(self.payment_channels.as_ref(), "Payment channels", Entity::Contract);
+        channels.channels_by_id.get(&channel_id).unwrap_or_else(|| env::panic_str("Channel not found"))
+    }
+
+    fn internal_save_channel(&mut self, channel_id: ChannelId, channel: &Channel) {
+        self.payment_channels.as_mut().unwrap().channels_by_id.insert(&channel_id, channel);
+    }
+
+    /// Return unclaimed part of deposit to sender and remove channel
+    fn internal_finish_channel(&mut self, channel_id: ChannelId, channel: Channel) -> Balance {
+        self.payment_channels.as_mut().unwrap().channels_by_id.remove(&channel_id);
+        let rest = channel.deposit - channel.claimed;
+        if rest > 0 {
+            self.internal_move(&channel.token_id, &env::current_account_id(), &channel.sender_id, rest, "channel_refund");
+        }
+        rest
+    }
+}
+
+impl MultiTokenPaymentChannel for MultiToken {
+    fn mt_channel_open(&mut self, receiver_id: AccountId, token_id: TokenId, amount: Amount, public_key: PublicKey) -> ChannelId {
+        self.assert_storage_call_deposit();
+        expect_extension(self.payment_channels.as_ref(), "Payment channels", Entity::Contract);
+        self.assert_no_compliance();
+
+        let sender_id = env::predecessor_account_id();
+        if sender_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        if amount.0 == 0 {
+            MtError::ZeroAmount.panic();
+        }
+        require!(public_key.curve_type() == CurveType::ED25519, "Only ed25519 keys can sign vouchers");
+        self.assert_can_move(&token_id, &sender_id, &receiver_id, amount.0);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        self.internal_move(&token_id, &sender_id, &env::current_account_id(), amount.0, "channel_open");
+
+        let channels = self.payment_channels.as_mut().unwrap();
+        let channel_id = channels.next_channel_id;
+        channels.next_channel_id += 1;
+        let channel = Channel {
+            sender_id: sender_id.clone(),
+            receiver_id,
+            token_id,
+            public_key,
+            deposit: amount.0,
+            claimed: 0,
+            closing_at: None,
+        };
+        channels.channels_by_id.insert(&channel_id, &channel);
+        tracker.stop();
+        tracker.refund(sender_id);
+
+        channel_id
+    }
+
+    fn mt_channel_claim(&mut self, channel_id: ChannelId, amount: Amount, signature: Base64VecU8) -> U128 {
+        self.assert_call_deposit();
+        let mut channel = self.internal_expect_channel(channel_id);
+        require!(env::predecessor_account_id() == channel.receiver_id, "Only receiver can claim");
+        require!(amount.0 > channel.claimed, "Voucher is not above claimed amount");
+        require!(amount.0 <= channel.deposit, "Voucher exceeds deposit of channel");
+
+        let voucher = ChannelVoucher { contract_id: env::current_account_id(), channel_id, amount: amount.0 };
+        require!(verify_ed25519(&channel.public_key, &voucher.message(), &signature.0), "Invalid voucher signature");
+
+        let paid = amount.0 - channel.claimed;
+        channel.claimed = amount.0;
+        self.internal_move(&channel.token_id, &env::current_account_id(), &channel.receiver_id, paid, "channel_claim");
+
+        if channel.claimed == channel.deposit {
+            self.internal_finish_channel(channel_id, channel);
+        } else {
+            self.internal_save_channel(channel_id, &channel);
+        }
+
+        U128(paid)
+    }
+
+    fn mt_channel_close(&mut self, channel_id: ChannelId) {
+        self.assert_call_deposit();
+        let mut channel = self.internal_expect_channel(channel_id);
+        let caller_id = env::predecessor_account_id();
+
+        if caller_id == channel.receiver_id {
+            self.internal_finish_channel(channel_id, channel);
+            return;
+        }
+
+        require!(caller_id == channel.sender_id, "Only sender or receiver can close channel");
+        require!(channel.closing_at.is_none(), "Channel is already closing");
+        let challenge_period = self.payment_channels.as_ref().unwrap().challenge_period;
+        channel.closing_at = Some(env::block_timestamp().saturating_add(challenge_period));
+        self.internal_save_channel(channel_id, &channel);
+    }
+
+    fn mt_channel_withdraw(&mut self, channel_id: ChannelId) -> U128 {
+        self.assert_call_deposit();
+        let channel = self.internal_expect_channel(channel_id);
+        require!(env::predecessor_account_id() == channel.sender_id, "Only sender can withdraw");
+        let closing_at = channel.closing_at.unwrap_or_else(|| env::panic_str("Channel is not closing"));
+        require!(env::block_timestamp() >= closing_at, "Challenge period is not over");
+
+        U128(self.internal_finish_channel(channel_id, channel))
+    }
+
+    fn mt_channel(&self, channel_id: ChannelId) -> Option<ChannelView> {
+        let channels = expect_extension(self.payment_channels.as_ref(), "Payment channels", Entity::Contract);
+        channels.channels_by_id.get(&channel_id).map(|channel| ChannelView {
+            sender_id: channel.sender_id,
+            receiver_id: channel.receiver_id,
+            token_id: channel.token_id,
+            public_key: channel.public_key,
+            deposit: U128(channel.deposit),
+            claimed: U128(channel.claimed),
+            closing_at: channel.closing_at.map(U64),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{AccountId, Balance, IntoStorageKey, PublicKey};
+
+use crate::multi_token::{core::MultiToken, token::TokenId};
+
+use super::ChannelId;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Channel {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub token_id: TokenId,
+    pub public_key: PublicKey,
+    pub deposit: Balance,
+    pub claimed: Balance,
+    pub closing_at: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PaymentChannels {
+    /// Time receiver has to claim after sender closed channel, in nanoseconds
+    pub challenge_period: u64,
+    pub channels_by_id: LookupMap<ChannelId, Channel>,
+    pub next_channel_id: ChannelId,
+}
+
+impl MultiToken {
+    /// Allow payment channels, sender closing a channel gives receiver `challenge_period`
+    /// nanoseconds to claim
+    pub fn enable_payment_channels<P: IntoStorageKey>(&mut self, prefix: P, challenge_period: u64) {
+        self.payment_channels =
+            Some(PaymentChannels { challenge_period, channels_by_id: LookupMap::new(prefix), next_channel_id: 0 });
+    }
+}
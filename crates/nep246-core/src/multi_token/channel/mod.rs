@@ -0,0 +1,94 @@
+/*! Unidirectional payment channels in a single token
+
+Sender locks tokens for a receiver with `mt_channel_open` and then pays off-chain by signing
+vouchers for the cumulative amount owed, e.g. for every move in a game. Receiver settles the
+highest voucher with `mt_channel_claim` whenever it wants, each claim pays only the difference
+to what was claimed before.
+
+Receiver closes channel with `mt_channel_close` right away, returning the rest to sender. Sender
+can't know whether receiver holds unclaimed vouchers, so its `mt_channel_close` starts challenge
+period during which receiver can still claim. Once it's over sender takes the rest back with
+`mt_channel_withdraw`.
+
+Vouchers are ed25519 signatures over [ChannelVoucher::message] with the key given at opening.
+Verifying them needs `ed25519-dalek`, so methods are available with `payment-channels` feature.
+State of channels is part of multi token regardless of the feature, so enabling it keeps stored state readable.
+ */
+
+#[cfg(feature = "payment-channels")]
+mod channel_impl;
+mod channel_state;
+
+pub use channel_state::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, PublicKey};
+
+pub type ChannelId = u64;
+
+/// Promise of sender to pay `amount` in total through channel
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct ChannelVoucher {
+    pub contract_id: AccountId,
+    pub channel_id: ChannelId,
+    pub amount: u128,
+}
+
+impl ChannelVoucher {
+    /// Bytes sender signs: Borsh-serialized voucher
+    pub fn message(&self) -> Vec<u8> {
+        self.try_to_vec().unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ChannelView {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub token_id: TokenId,
+    pub public_key: PublicKey,
+    /// Total amount locked in channel
+    pub deposit: U128,
+    /// Amount already paid to receiver
+    pub claimed: U128,
+    /// Block timestamp in nanoseconds when challenge period ends, if sender closed channel
+    pub closing_at: Option<U64>,
+}
+
+pub trait MultiTokenPaymentChannel {
+    /// Lock `amount` of token of caller for `receiver_id`. Vouchers must be signed with `public_key`
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage of the channel, unused part is refunded
+    /// * Key must be ed25519
+    fn mt_channel_open(&mut self, receiver_id: AccountId, token_id: TokenId, amount: Amount, public_key: PublicKey) -> ChannelId;
+
+    /// Settle voucher for cumulative `amount`, paying receiver the difference to claimed amount
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not receiver of the channel
+    /// * Contract MUST panic if signature is invalid or amount is not above claimed one or exceeds deposit
+    fn mt_channel_claim(&mut self, channel_id: ChannelId, amount: Amount, signature: Base64VecU8) -> U128;
+
+    /// Close channel. Receiver closes it right away, sender starts challenge period
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is neither sender nor receiver of the channel
+    fn mt_channel_close(&mut self, channel_id: ChannelId);
+
+    /// Return the rest of deposit to sender after challenge period is over
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not sender or challenge period is not over
+    fn mt_channel_withdraw(&mut self, channel_id: ChannelId) -> U128;
+
+    fn mt_channel(&self, channel_id: ChannelId) -> Option<ChannelView>;
+}
@@ -0,0 +1,102 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupSet;
+use near_sdk::{require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    events::MtBurn,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity},
+};
+
+use super::MultiTokenClawback;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Clawback {
+    /// Account allowed to take tokens back
+    pub compliance_id: AccountId,
+    /// Tokens where owner opted in for clawback
+    pub enabled_tokens: LookupSet<TokenId>,
+}
+
+impl MultiToken {
+    /// Allow `compliance_id` to take back tokens with enabled clawback
+    pub fn enable_clawback<P: IntoStorageKey>(&mut self, prefix: P, compliance_id: AccountId) {
+        self.clawback = Some(Clawback { compliance_id, enabled_tokens: LookupSet::new(prefix) });
+    }
+}
+
+impl MultiTokenClawback for MultiToken {
+    fn mt_set_clawback_enabled(&mut self, token_id: TokenId, enabled: bool) {
+        self.assert_call_deposit();
+
+        let owner_id = self.owner_by_id.get(&token_id).expect("This token does not exist");
+        unauthorized_assert(&owner_id);
+
+        let clawback = expect_extension(self.clawback.as_mut(), "Clawback", Entity::Contract);
+        if enabled {
+            clawback.enabled_tokens.insert(&token_id);
+        } else {
+            clawback.enabled_tokens.remove(&token_id);
+        }
+    }
+
+    fn mt_clawback(
+        &mut self,
+        token_id: TokenId,
+        owner_id: AccountId,
+        receiver_id: Option<AccountId>,
+        amount: Amount,
+        memo: String,
+    ) {
+        self.assert_call_deposit();
+
+        let clawback = expect_extension(self.clawback.as_ref(), "Clawback", Entity::Contract);
+        unauthorized_assert(&clawback.compliance_id);
+        require!(clawback.enabled_tokens.contains(&token_id), "Clawback is not enabled for this token");
+        require!(!memo.is_empty(), "Reason of clawback must be provided");
+        require!(amount.0 > 0, "Amount cannot be 0");
+
+        let compliance_id = clawback.compliance_id.clone();
+
+        self.internal_withdraw(&token_id, &owner_id, amount.0);
+
+        match receiver_id {
+            Some(receiver_id) => {
+                if owner_id == receiver_id {
+                    MtError::SenderIsReceiver.panic();
+                }
+
+                self.internal_deposit(&token_id, &receiver_id, amount.0);
+                self.emit_transfer(
+                    &owner_id,
+                    &receiver_id,
+                    &token_id,
+                    amount.0,
+                    Some(&compliance_id),
+                    Some(memo),
+                );
+                self.internal_record_transfer(&owner_id, &receiver_id, &token_id, amount.0);
+            }
+            None => MtBurn {
+                owner_id: &owner_id,
+                authorized_id: Some(&compliance_id),
+                token_ids: &[token_id.as_str()],
+                amounts: &[&amount.0.to_string()],
+                memo: Some(&memo),
+                batch_id: self.batch_id.as_deref(),
+            }
+            .emit(),
+        }
+    }
+
+    fn mt_is_clawback_enabled(&self, token_id: TokenId) -> bool {
+        self.clawback.as_ref().map(|clawback| clawback.enabled_tokens.contains(&token_id)).unwrap_or(false)
+    }
+
+    fn mt_compliance_id(&self) -> AccountId {
+        expect_extension(self.clawback.as_ref(), "Clawback", Entity::Contract).compliance_id.clone()
+    }
+}
@@ -0,0 +1,47 @@
+mod clawback_impl;
+
+pub use clawback_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::AccountId;
+
+/// Clawback extension for regulated assets. Compliance account may take tokens back
+/// from any holder of tokens where owner opted in for clawback.
+pub trait MultiTokenClawback {
+    /// Allow or forbid clawback for token
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than token owner
+    fn mt_set_clawback_enabled(&mut self, token_id: TokenId, enabled: bool);
+
+    /// Take tokens from holder. Tokens are transferred to `receiver_id` if it's given, otherwise burned.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than compliance account
+    /// * Contract MUST panic if clawback is not enabled for token
+    /// * Contract MUST panic if `memo` is empty
+    ///
+    /// # Arguments:
+    /// * `token_id` - ID of token
+    /// * `owner_id` - holder to take tokens from
+    /// * `receiver_id` - account to transfer tokens to, `None` to burn them
+    /// * `amount` - how much to take
+    /// * `memo` - reason of clawback, included in emitted event
+    fn mt_clawback(
+        &mut self,
+        token_id: TokenId,
+        owner_id: AccountId,
+        receiver_id: Option<AccountId>,
+        amount: Amount,
+        memo: String,
+    );
+
+    /// Check if clawback is enabled for token
+    fn mt_is_clawback_enabled(&self, token_id: TokenId) -> bool;
+
+    /// Get account that is allowed to perform clawbacks
+    fn mt_compliance_id(&self) -> AccountId;
+}
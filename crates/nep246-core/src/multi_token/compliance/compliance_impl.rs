@@ -0,0 +1,183 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{
+    env, ext_contract, require, AccountId, Balance, IntoStorageKey, Promise, PromiseResult,
+};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{MultiTokenCompliance, PendingTransfer, TimeoutPolicy};
+
+pub use crate::multi_token::gas::{GAS_FOR_COMPLIANCE_CHECK, GAS_FOR_COMPLIANCE_RESOLVE};
+
+const NO_DEPOSIT: Balance = 0;
+
+#[ext_contract(ext_oracle)]
+pub trait ComplianceOracle {
+    fn check_transfer(&self, sender_id: AccountId, receiver_id: AccountId, token_id: TokenId, amount: U128) -> bool;
+}
+
+#[ext_contract(ext_self)]
+trait MtComplianceResolver {
+    fn mt_on_compliance_check(&mut self, pending_id: u64) -> bool;
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Compliance {
+    pub oracle_id: AccountId,
+    /// Nanoseconds after which pending transfer can be settled by policy
+    pub timeout: u64,
+    pub timeout_policy: TimeoutPolicy,
+    pub pending: LookupMap<u64, PendingTransfer>,
+    pub next_pending_id: u64,
+}
+
+impl MultiToken {
+    /// Route all transfers through compliance oracle
+    pub fn enable_compliance<P: IntoStorageKey>(
+        &mut self,
+        prefix: P,
+        oracle_id: AccountId,
+        timeout: u64,
+        timeout_policy: TimeoutPolicy,
+    ) {
+        self.compliance =
+            Some(Compliance { oracle_id, timeout, timeout_policy, pending: LookupMap::new(prefix), next_pending_id: 0 });
+    }
+
+    /// Change oracle and policy. Can only be called by contract owner
+    pub fn set_compliance_oracle(&mut self, oracle_id: AccountId, timeout: u64, timeout_policy: TimeoutPolicy) {
+        unauthorized_assert(&self.owner_id);
+
+        let compliance = expect_extension(self.compliance.as_mut(), "Compliance", Entity::Contract);
+        compliance.oracle_id = oracle_id;
+        compliance.timeout = timeout;
+        compliance.timeout_policy = timeout_policy;
+    }
+
+    /// Panics if transfers must go through compliance oracle
+    pub fn assert_no_compliance(&self) {
+        require!(self.compliance.is_none(), "Transfers require compliance check, use mt_transfer_compliant");
+    }
+
+    /// Deliver escrowed tokens to receiver or return them to sender
+    fn internal_settle_compliance(&mut self, pending_id: u64, approved: bool) -> bool {
+        let compliance = expect_extension(self.compliance.as_mut(), "Compliance", Entity::Contract);
+        let pending = compliance.pending.remove(&pending_id).expect("Pending transfer not found");
+
+        let escrow_id = env::current_account_id();
+        let to_id = if approved { &pending.receiver_id } else { &pending.sender_id };
+        let memo = if approved { "compliance_approved" } else { "compliance_rejected" };
+        self.internal_move(&pending.token_id, &escrow_id, to_id, pending.amount.0, memo);
+
+        approved
+    }
+}
+
+impl MultiTokenCompliance for MultiToken {
+    fn mt_transfer_compliant(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        memo: Option<String>,
+    ) -> Promise {
+        self.assert_storage_call_deposit();
+        let amount = U128::from(amount);
+        require!(
+            env::prepaid_gas() > GAS_FOR_COMPLIANCE_CHECK + GAS_FOR_COMPLIANCE_RESOLVE,
+            "Not enough prepaid gas"
+        );
+
+        let sender_id = env::predecessor_account_id();
+        if sender_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        require!(amount.0 > 0, "Amount must be positive");
+        self.assert_not_transfer_locked(&token_id);
+        self.assert_not_cooling_down(&token_id, &sender_id);
+        self.assert_transferable(&sender_id, &receiver_id);
+        // Receiver must be able to hold token before anything is escrowed
+        self.internal_unwrap_balance_of(&token_id, &receiver_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let escrow_id = env::current_account_id();
+        self.internal_move(&token_id, &sender_id, &escrow_id, amount.0, memo.as_deref().unwrap_or("compliance_escrow"));
+
+        let compliance = expect_extension(self.compliance.as_mut(), "Compliance", Entity::Contract);
+        let pending_id = compliance.next_pending_id;
+        compliance.next_pending_id += 1;
+        compliance.pending.insert(
+            &pending_id,
+            &PendingTransfer {
+                sender_id: sender_id.clone(),
+                receiver_id: receiver_id.clone(),
+                token_id: token_id.clone(),
+                amount,
+                expires_at: env::block_timestamp() + compliance.timeout,
+            },
+        );
+        let oracle_id = compliance.oracle_id.clone();
+        tracker.stop();
+        tracker.refund(sender_id.clone());
+
+        ext_oracle::check_transfer(
+            sender_id,
+            receiver_id,
+            token_id,
+            amount,
+            oracle_id,
+            NO_DEPOSIT,
+            GAS_FOR_COMPLIANCE_CHECK,
+        )
+        .then(ext_self::mt_on_compliance_check(
+            pending_id,
+            env::current_account_id(),
+            NO_DEPOSIT,
+            GAS_FOR_COMPLIANCE_RESOLVE,
+        ))
+    }
+
+    fn mt_on_compliance_check(&mut self, pending_id: u64) -> bool {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method mt_on_compliance_check is private"
+        );
+
+        let compliance = expect_extension(self.compliance.as_ref(), "Compliance", Entity::Contract);
+        // Already settled by timeout
+        if compliance.pending.get(&pending_id).is_none() {
+            return false;
+        }
+
+        let approved = match env::promise_result(0) {
+            PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<bool>(&value).ok(),
+            _ => None,
+        };
+        let approved = approved.unwrap_or(compliance.timeout_policy == TimeoutPolicy::Approve);
+
+        self.internal_settle_compliance(pending_id, approved)
+    }
+
+    fn mt_settle_compliance(&mut self, pending_id: u64) -> bool {
+        self.assert_call_deposit();
+        let compliance = expect_extension(self.compliance.as_ref(), "Compliance", Entity::Contract);
+        let pending = compliance.pending.get(&pending_id).expect("Pending transfer not found");
+        require!(env::block_timestamp() >= pending.expires_at, "Pending transfer has not timed out yet");
+
+        let approved = compliance.timeout_policy == TimeoutPolicy::Approve;
+        self.internal_settle_compliance(pending_id, approved)
+    }
+
+    fn mt_pending_compliance(&self, pending_id: u64) -> Option<PendingTransfer> {
+        expect_extension(self.compliance.as_ref(), "Compliance", Entity::Contract).pending.get(&pending_id)
+    }
+}
@@ -0,0 +1,66 @@
+/*! Transfers confirmed by external compliance oracle (e.g. KYC checks)
+
+When enabled, direct transfers are disabled. `mt_transfer_compliant` escrows tokens of sender
+and asks oracle with `check_transfer`. If oracle answers `true` tokens go to receiver,
+if `false` they're returned to sender. If oracle fails or transfer isn't resolved before
+timeout, [TimeoutPolicy] decides.
+ */
+
+mod compliance_impl;
+
+pub use compliance_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, Promise};
+
+/// What happens with transfer when oracle didn't give an answer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TimeoutPolicy {
+    Approve,
+    Reject,
+}
+
+/// Transfer waiting for oracle decision, tokens are held by contract
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PendingTransfer {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub token_id: TokenId,
+    pub amount: U128,
+    /// Block timestamp in nanoseconds after which [TimeoutPolicy] can be applied
+    pub expires_at: u64,
+}
+
+pub trait MultiTokenCompliance {
+    /// Transfer tokens once compliance oracle confirms it
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage of the pending transfer,
+    ///   unused part is refunded
+    ///
+    /// returns: Promise resolving to `true` if tokens were delivered
+    fn mt_transfer_compliant(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        memo: Option<String>,
+    ) -> Promise;
+
+    /// Callback of oracle check. Can only be called by the contract itself
+    fn mt_on_compliance_check(&mut self, pending_id: u64) -> bool;
+
+    /// Apply timeout policy to transfer that is pending longer than timeout. Can be called by anyone
+    ///
+    /// returns: `true` if tokens were delivered
+    fn mt_settle_compliance(&mut self, pending_id: u64) -> bool;
+
+    /// Get pending transfer by ID
+    fn mt_pending_compliance(&self, pending_id: u64) -> Option<PendingTransfer>;
+}
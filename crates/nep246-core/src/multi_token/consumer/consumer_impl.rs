@@ -0,0 +1,90 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, require, AccountId, Balance, PromiseOrValue, PromiseResult};
+
+use crate::multi_token::{errors::assert_batch_lengths, token::TokenId};
+
+use super::MultiTokenConsumer;
+
+pub use crate::multi_token::gas::{GAS_FOR_BURN_FROM_SELF, GAS_FOR_RESOLVE_CONSUME};
+
+/// Deposit required by `mt_burn_from_self`
+const ONE_YOCTO: Balance = 1;
+
+#[ext_contract(ext_mt_burn)]
+pub trait MultiTokenBurnFromSelf {
+    fn mt_burn_from_self(&mut self, token_ids: Vec<TokenId>, amounts: Vec<U128>);
+}
+
+#[ext_contract(ext_consumer)]
+pub trait MultiTokenConsumerResolver {
+    fn mt_resolve_consume(
+        &mut self,
+        sender_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        used: Vec<U128>,
+    ) -> Vec<U128>;
+}
+
+/// Body of `mt_on_transfer` of consumer: consume received tokens and burn what was used.
+/// Resolves to amounts unused by consumer
+pub fn consume_on_transfer<C: MultiTokenConsumer>(
+    consumer: &mut C,
+    sender_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<U128>,
+    msg: String,
+) -> PromiseOrValue<Vec<U128>> {
+    assert_batch_lengths(token_ids.len(), &[amounts.len()]);
+
+    let used = consumer.mt_consume(&sender_id, &token_ids, &amounts, &msg);
+    assert_batch_lengths(token_ids.len(), &[used.len()]);
+    amounts
+        .iter()
+        .zip(used.iter())
+        .for_each(|(amount, used)| require!(used.0 <= amount.0, "Consumer used more than it received"));
+
+    let (burn_ids, burn_amounts): (Vec<TokenId>, Vec<U128>) = token_ids
+        .iter()
+        .cloned()
+        .zip(used.iter().cloned())
+        .filter(|(_, used)| used.0 > 0)
+        .unzip();
+    if burn_ids.is_empty() {
+        return PromiseOrValue::Value(amounts);
+    }
+
+    ext_mt_burn::mt_burn_from_self(burn_ids, burn_amounts, env::predecessor_account_id(), ONE_YOCTO, GAS_FOR_BURN_FROM_SELF)
+        .then(ext_consumer::mt_resolve_consume(
+            sender_id,
+            token_ids,
+            amounts,
+            used,
+            env::current_account_id(),
+            0,
+            GAS_FOR_RESOLVE_CONSUME,
+        ))
+        .into()
+}
+
+/// Body of `mt_resolve_consume`: keep used tokens if they were burned, otherwise
+/// revert consumption and return everything
+pub fn resolve_consume<C: MultiTokenConsumer>(
+    consumer: &mut C,
+    sender_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<U128>,
+    used: Vec<U128>,
+) -> Vec<U128> {
+    require!(env::predecessor_account_id() == env::current_account_id(), "Method mt_resolve_consume is private");
+
+    match env::promise_result(0) {
+        PromiseResult::Successful(_) => {
+            amounts.iter().zip(used.iter()).map(|(amount, used)| U128(amount.0 - used.0)).collect()
+        }
+        _ => {
+            consumer.mt_revert_consume(&sender_id, &token_ids, &used);
+            amounts
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/*! Receivers that consume tokens sent to them, e.g. a game using a potion or redeeming a ticket
+
+Receiver implements [MultiTokenConsumer] and wires it with `impl_multi_token_consumer`, which
+implements `mt_on_transfer`. Received tokens are passed to `mt_consume`, used amounts are then
+burned with `mt_burn_from_self` of the MT contract and the rest is returned to sender as unused.
+
+The flow is atomic: if `mt_consume` panics, `mt_on_transfer` fails and MT contract refunds
+everything. If burning fails, `mt_revert_consume` undoes consumption and all tokens go back.
+ */
+
+mod consumer_impl;
+
+pub use consumer_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+/// Implemented by receiving contract that burns tokens it uses
+pub trait MultiTokenConsumer {
+    /// Apply effects of received tokens. Predecessor is the MT contract, which should be checked
+    /// against a whitelist. Panic to reject the transfer, nothing is burned then
+    ///
+    /// Returns how much of each token was used, at most the received amount
+    fn mt_consume(&mut self, sender_id: &AccountId, token_ids: &[TokenId], amounts: &[U128], msg: &str) -> Vec<U128>;
+
+    /// Undo effects of `mt_consume` after used tokens could not be burned.
+    /// All received tokens are returned to sender
+    fn mt_revert_consume(&mut self, sender_id: &AccountId, token_ids: &[TokenId], used: &[U128]);
+}
@@ -0,0 +1,92 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{ConversionRate, MultiTokenConversion};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Conversions {
+    pub rates: LookupMap<(TokenId, TokenId), ConversionRate>,
+}
+
+impl MultiToken {
+    /// Allow holders to convert tokens by rates set by owner
+    pub fn enable_conversions<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.conversions = Some(Conversions { rates: LookupMap::new(prefix) });
+    }
+}
+
+impl MultiTokenConversion for MultiToken {
+    fn mt_set_conversion_rate(&mut self, from_id: TokenId, to_id: TokenId, rate: Option<ConversionRate>) {
+        self.assert_storage_call_deposit();
+        unauthorized_assert(&self.owner_id);
+        require!(from_id != to_id, "Token cannot be converted into itself");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let conversions = expect_extension(self.conversions.as_mut(), "Conversion", Entity::Contract);
+        match rate {
+            Some(rate) => {
+                require!(rate.from_amount.0 > 0 && rate.to_amount.0 > 0, "Rate amounts cannot be 0");
+                conversions.rates.insert(&(from_id, to_id), &rate);
+            }
+            None => {
+                conversions.rates.remove(&(from_id, to_id));
+            }
+        }
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_convert(&mut self, from_id: TokenId, to_id: TokenId, amount: Amount) -> U128 {
+        self.assert_storage_call_deposit();
+        if amount.0 == 0 {
+            MtError::ZeroAmount.panic();
+        }
+
+        let rate = expect_extension(self.conversions.as_ref(), "Conversion", Entity::Contract)
+            .rates
+            .get(&(from_id.clone(), to_id.clone()))
+            .unwrap_or_else(|| env::panic_str("No conversion rate between tokens"));
+        require!(amount.0 % rate.from_amount.0 == 0, "Amount must be a multiple of rate's from_amount");
+        let converted = (amount.0 / rate.from_amount.0)
+            .checked_mul(rate.to_amount.0)
+            .unwrap_or_else(|| env::panic_str("Converted amount overflow"));
+
+        let account_id = env::predecessor_account_id();
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        self.internal_withdraw(&from_id, &account_id, amount.0);
+        self.emit_burn(&account_id, &from_id, &amount.0, Some("convert".to_string()));
+
+        let registered = self
+            .balances_per_token
+            .get(&to_id)
+            .unwrap_or_else(|| MtError::TokenNotFound.panic())
+            .contains_key(&account_id);
+        if !registered {
+            self.internal_register_account(&to_id, &account_id);
+        }
+        self.internal_deposit(&to_id, &account_id, converted);
+        self.emit_mint(&account_id, &to_id, &converted, Some("convert".to_string()));
+
+        tracker.stop();
+        tracker.refund(account_id);
+
+        U128(converted)
+    }
+
+    fn mt_conversion_rate(&self, from_id: TokenId, to_id: TokenId) -> Option<ConversionRate> {
+        self.conversions.as_ref().and_then(|conversions| conversions.rates.get(&(from_id, to_id)))
+    }
+}
@@ -0,0 +1,48 @@
+/*! Conversion between tiers of in-game currency
+
+Contract owner sets rates between pairs of tokens (e.g. 100 bronze = 1 silver), and holders
+convert by burning tokens of one id and minting tokens of another in the same call.
+ */
+
+mod conversion_impl;
+
+pub use conversion_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// `from_amount` of source token is converted into `to_amount` of target token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConversionRate {
+    pub from_amount: U128,
+    pub to_amount: U128,
+}
+
+pub trait MultiTokenConversion {
+    /// Set or remove rate of conversion from `from_id` to `to_id`. Rate is one-way,
+    /// reverse conversion needs its own rate
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if any amount of rate is 0
+    fn mt_set_conversion_rate(&mut self, from_id: TokenId, to_id: TokenId, rate: Option<ConversionRate>);
+
+    /// Burn `amount` of `from_id` owned by caller and mint corresponding amount of `to_id` to them
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if there is no rate between tokens
+    /// * Contract MUST panic if `amount` is not a multiple of rate's `from_amount`
+    ///
+    /// # Returns
+    /// Amount of `to_id` minted
+    fn mt_convert(&mut self, from_id: TokenId, to_id: TokenId, amount: Amount) -> U128;
+
+    /// Get rate of conversion from `from_id` to `to_id`
+    fn mt_conversion_rate(&self, from_id: TokenId, to_id: TokenId) -> Option<ConversionRate>;
+}
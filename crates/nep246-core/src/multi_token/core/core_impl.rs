@@ -0,0 +1,1342 @@
+use crate::multi_token::core::{ApprovalId, GranteeEntry, MultiTokenCore, MultiTokenResolver, TransferResult};
+use crate::multi_token::events::{
+    MsgRejectReason, MtBurn, MtMint, MtTransfer, MtTransferCallRejected, MtTransferCallResult, MtTransferRevert,
+    RevertReason,
+};
+use crate::multi_token::errors::{assert_batch_lengths, MtError};
+use crate::multi_token::approval::{ApprovalExpiry, ApprovalRateLimit, SeriesApprovals, StrictApprovals};
+use crate::multi_token::burn::MintDeposits;
+use crate::multi_token::clawback::Clawback;
+use crate::multi_token::dao::DaoMint;
+use crate::multi_token::erc1155_compat::Erc1155Compat;
+use crate::multi_token::history::TransferHistory;
+use crate::multi_token::multisig::Council;
+use crate::multi_token::swap::Swaps;
+use crate::multi_token::payout::Royalties;
+use crate::multi_token::portfolio::Portfolios;
+use crate::multi_token::compliance::Compliance;
+use crate::multi_token::operator::SystemOperators;
+use crate::multi_token::staking::Staking;
+use crate::multi_token::conversion::Conversions;
+use crate::multi_token::dust::MinTransferAmounts;
+use crate::multi_token::holder_cap::HolderCaps;
+use crate::multi_token::enumeration::TokenOrder;
+use crate::multi_token::dispute::Disputes;
+use crate::multi_token::gas;
+use crate::multi_token::expiring::ExpiringTokens;
+use crate::multi_token::memo_index::MemoIndex;
+use crate::multi_token::ft_mint::FtSales;
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::migration::Rekeys;
+use crate::multi_token::keeper::Keeper;
+use crate::multi_token::croncat::Croncat;
+use crate::multi_token::transfer_lock::TransferLocks;
+use crate::multi_token::token_kind::TokenKinds;
+use crate::multi_token::transfer_cooldown::TransferCooldowns;
+use crate::multi_token::storage_management::StorageAccounts;
+use crate::multi_token::activity::Activity;
+use crate::multi_token::channel::PaymentChannels;
+use crate::multi_token::blind_mint::BlindMint;
+use crate::multi_token::approval::ScopedOperators;
+use crate::multi_token::security::DEFAULT_MIN_CALL_DEPOSIT;
+use crate::multi_token::metadata::{token_uri, TokenMetadata};
+use crate::multi_token::token::{Approval, Token, TokenId};
+use crate::multi_token::utils::{refund_deposit_to_account, StorageTracker};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap, UnorderedMap, UnorderedSet};
+use near_sdk::json_types::U128;
+use near_sdk::{
+    env, ext_contract, log, require, AccountId, Balance, BorshStorageKey,
+    CryptoHash, IntoStorageKey, Promise, PromiseOrValue, PromiseResult, StorageUsage,
+};
+use std::collections::HashMap;
+
+pub use crate::multi_token::gas::{GAS_FOR_MT_TRANSFER_CALL, GAS_FOR_RESOLVE_TRANSFER};
+
+const NO_DEPOSIT: Balance = 0;
+
+/// Maximum length of `batch_id` passed to batch methods
+pub const MAX_BATCH_ID_LEN: usize = 64;
+
+#[ext_contract(ext_self)]
+trait MtResolver {
+    fn mt_resolve_transfer(
+        &mut self,
+        sender_id: AccountId,
+        receiver: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
+    ) -> Vector<Balance>;
+}
+
+#[ext_contract(ext_receiver)]
+pub trait MultiTokenReceiver {
+    fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_id: Vec<AccountId>,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>>;
+}
+
+/// Implementation of the multi-token standard
+/// Allows to include NEP-246 compatible tokens to any contract.
+/// There are next traits that any contract may implement:
+///     - MultiTokenCore -- interface with transfer methods. MultiToken provides methods for it.
+///     - MultiTokenApproval -- interface with approve methods. MultiToken provides methods for it.
+///     - MultiTokenEnumeration -- interface for getting lists of tokens. MultiToken provides methods for it.
+///     - MultiTokenMetadata -- return metadata for the token in NEP-246, up to contract to implement.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MultiToken {
+    /// Owner of contract
+    pub owner_id: AccountId,
+
+    /// How much storage takes every token
+    pub extra_storage_in_bytes_per_emission: StorageUsage,
+
+    /// Owner of each token
+    pub owner_by_id: TreeMap<TokenId, AccountId>,
+
+    /// Total supply for each token
+    pub total_supply: LookupMap<TokenId, Balance>,
+
+    /// Metadata for each token
+    pub token_metadata_by_id: Option<LookupMap<TokenId, TokenMetadata>>,
+
+    /// All tokens owned by user, ordered by id
+    pub tokens_per_owner: Option<LookupMap<AccountId, TreeMap<TokenId, ()>>>,
+
+    /// Balance of user for given token
+    pub balances_per_token: UnorderedMap<TokenId, LookupMap<AccountId, u128>>,
+
+    /// All accounts registered in given token
+    pub holders_per_token: LookupMap<TokenId, UnorderedSet<AccountId>>,
+
+    /// All approvals of user
+    pub approvals_by_id: Option<LookupMap<TokenId, HashMap<AccountId, Approval>>>,
+
+    /// Next id of approval
+    pub next_approval_id_by_id: Option<LookupMap<TokenId, u64>>,
+
+    /// Owners & tokens where account is approved, ordered by owner and token. Reverse index of `approvals_by_id`
+    pub approvals_by_grantee: Option<LookupMap<AccountId, TreeMap<GranteeEntry, ()>>>,
+
+    /// Limit of new approvals per epoch for each owner
+    pub approval_rate_limit: Option<ApprovalRateLimit>,
+
+    /// Next id for token
+    pub next_token_id: u64,
+
+    /// Recent transfers of each token, if history extension is used
+    pub transfer_history: Option<TransferHistory>,
+
+    /// Council confirming admin actions, if multisig extension is used
+    pub council: Option<Council>,
+
+    /// Compliance account & tokens it can take back, if clawback extension is used
+    pub clawback: Option<Clawback>,
+
+    /// Mapping of tokens to ERC-1155 tokens on Aurora, if compatibility extension is used
+    pub erc1155_compat: Option<Erc1155Compat>,
+
+    /// DAO authorizing mints, if DAO mint extension is used
+    pub dao_mint: Option<DaoMint>,
+
+    /// Storage deposits paid at mint, refunded when the last unit is burned
+    pub mint_deposits: Option<MintDeposits>,
+
+    /// Pending swaps between accounts, if swap extension is used
+    pub swaps: Option<Swaps>,
+
+    /// Royalties set by creators, if royalty extension is used
+    pub royalties: Option<Royalties>,
+
+    /// Approvals waiting for `mt_on_approve` to succeed, if strict approval mode is used
+    pub strict_approvals: Option<StrictApprovals>,
+
+    /// Tokens of every account, if portfolio extension is used
+    pub portfolios: Option<Portfolios>,
+
+    /// Oracle confirming every transfer, if compliance extension is used
+    pub compliance: Option<Compliance>,
+
+    /// Deposit required by state-changing methods, see [crate::multi_token::security]
+    pub min_call_deposit: Balance,
+
+    /// If set, `msg` of `*_transfer_call` must be valid JSON not longer than this
+    pub max_msg_len: Option<u32>,
+
+    /// Operators allowed to move tokens of users that opted in, if operator extension is used
+    pub system_operators: Option<SystemOperators>,
+
+    /// Stakes of holders, if staking extension is used
+    pub staking: Option<Staking>,
+
+    /// Rates between tokens, if conversion extension is used
+    pub conversions: Option<Conversions>,
+
+    /// Expiry of approvals, if approval expiry extension is used
+    pub approval_expiry: Option<ApprovalExpiry>,
+
+    /// Minimum transfer amount of tokens, if dust protection extension is used
+    pub min_transfer_amounts: Option<MinTransferAmounts>,
+
+    /// Limits of holders per token, if holder cap extension is used
+    pub holder_caps: Option<HolderCaps>,
+
+    /// Custom order of tokens in enumeration, if contract enabled it
+    pub token_order: Option<TokenOrder>,
+
+    /// Arbiter and locked balances, if dispute extension is used
+    pub disputes: Option<Disputes>,
+
+    /// Expiry index of tokens, if expiring tokens extension is used
+    pub expiring_tokens: Option<ExpiringTokens>,
+
+    /// Recent transfers by memo tag, if memo index extension is used
+    pub memo_index: Option<MemoIndex>,
+
+    /// Approvals covering series of tokens, if series approval extension is used
+    pub series_approvals: Option<SeriesApprovals>,
+
+    /// Token prices in payment FT, if FT mint extension is used
+    pub ft_sales: Option<FtSales>,
+
+    /// Progress of re-keying accounts, if rekey extension is used
+    pub rekeys: Option<Rekeys>,
+
+    /// Maintenance tasks run by keepers
+    pub keeper: Option<Keeper>,
+
+    /// Croncat manager scheduled calls are accepted from
+    pub croncat: Option<Croncat>,
+
+    /// Lock of transfers after mint
+    pub transfer_locks: Option<TransferLocks>,
+
+    /// Kind of every minted token, if contract distinguishes them
+    pub token_kinds: Option<TokenKinds>,
+
+    /// Minimal time between transfers of tokens by the same account
+    pub transfer_cooldowns: Option<TransferCooldowns>,
+
+    /// Storage deposits of accounts and their registrations in tokens
+    pub storage_accounts: Option<StorageAccounts>,
+
+    /// First and last activity of tokens and since when accounts hold them
+    pub activity: Option<Activity>,
+
+    /// Open payment channels, settled with signed vouchers
+    pub payment_channels: Option<PaymentChannels>,
+
+    /// Commitments and metadata pool of commit-reveal drop, if contract runs one
+    pub blind_mint: Option<BlindMint>,
+
+    /// Operators with blanket rights over all or listed tokens of owners, if scoped operator extension is used
+    pub scoped_operators: Option<ScopedOperators>,
+
+    /// Batch ID given by caller of current batch method, echoed into every emitted event. Not stored
+    #[borsh_skip]
+    pub batch_id: Option<String>,
+}
+
+#[derive(BorshStorageKey, BorshSerialize)]
+pub enum StorageKey {
+    PerOwner,
+    TokensPerOwner { account_hash: Vec<u8> },
+    TokenPerOwnerInner { account_id_hash: CryptoHash },
+    OwnerById,
+    OwnerByIdInner { account_id_hash: CryptoHash },
+    TokenMetadata,
+    Approvals,
+    ApprovalById,
+    ApprovalsInner { account_id_hash: CryptoHash },
+    TotalSupply { supply: u128 },
+    Balances,
+    BalancesInner { token_id: Vec<u8> },
+    HoldersPerToken,
+    HoldersPerTokenInner { token_id: Vec<u8> },
+    ApprovalsByGranteeInner { account_hash: Vec<u8> },
+    HolderCandidates,
+    HolderCandidatesInner,
+}
+
+impl MultiToken {
+    pub fn new<Q, R, S, T>(
+        owner_by_id_prefix: Q,
+        owner_id: AccountId,
+        token_metadata_prefix: Option<R>,
+        enumeration_prefix: Option<S>,
+        approval_prefix: Option<T>,
+    ) -> Self
+        where
+            Q: IntoStorageKey,
+            R: IntoStorageKey,
+            S: IntoStorageKey,
+            T: IntoStorageKey,
+    {
+        let (approvals_by_id, next_approval_id_by_id, approvals_by_grantee) = if let Some(prefix) = approval_prefix {
+            let prefix: Vec<u8> = prefix.into_storage_key();
+            (
+                Some(LookupMap::new(prefix.clone())),
+                Some(LookupMap::new([prefix.clone(), "n".into()].concat())),
+                Some(LookupMap::new([prefix, "g".into()].concat())),
+            )
+        } else {
+            (None, None, None)
+        };
+
+        Self {
+            owner_id,
+            extra_storage_in_bytes_per_emission: 0,
+            owner_by_id: TreeMap::new(StorageKey::OwnerById),
+            total_supply: LookupMap::new(StorageKey::TotalSupply { supply: u128::MAX }),
+            token_metadata_by_id: token_metadata_prefix.map(LookupMap::new),
+            tokens_per_owner: enumeration_prefix.map(LookupMap::new),
+            balances_per_token: UnorderedMap::new(StorageKey::Balances),
+            holders_per_token: LookupMap::new(StorageKey::HoldersPerToken),
+            approvals_by_id,
+            next_approval_id_by_id,
+            approvals_by_grantee,
+            approval_rate_limit: None,
+            next_token_id: 0,
+            transfer_history: None,
+            council: None,
+            clawback: None,
+            erc1155_compat: None,
+            dao_mint: None,
+            mint_deposits: None,
+            swaps: None,
+            royalties: None,
+            strict_approvals: None,
+            portfolios: None,
+            compliance: None,
+            min_call_deposit: DEFAULT_MIN_CALL_DEPOSIT,
+            max_msg_len: None,
+            system_operators: None,
+            staking: None,
+            conversions: None,
+            approval_expiry: None,
+            min_transfer_amounts: None,
+            holder_caps: None,
+            token_order: None,
+            disputes: None,
+            expiring_tokens: None,
+            memo_index: None,
+            series_approvals: None,
+            ft_sales: None,
+            rekeys: None,
+            keeper: None,
+            croncat: None,
+            transfer_locks: None,
+            token_kinds: None,
+            transfer_cooldowns: None,
+            storage_accounts: None,
+            activity: None,
+            payment_channels: None,
+            blind_mint: None,
+            scoped_operators: None,
+            batch_id: None,
+        }
+    }
+
+    /// Used to get balance of specified account in specified token
+    pub fn internal_unwrap_balance_of(
+        &self,
+        token_id: &TokenId,
+        account_id: &AccountId,
+    ) -> Balance {
+        match self
+            .balances_per_token
+            .get(token_id)
+            .unwrap_or_else(|| MtError::TokenNotFound.panic())
+            .get(account_id)
+        {
+            Some(balance) => balance,
+            None => MtError::NotRegistered(account_id.clone()).panic(),
+        }
+    }
+
+    /// Add to balance of user specified amount
+    pub fn internal_deposit(
+        &mut self,
+        token_id: &TokenId,
+        account_id: &AccountId,
+        amount: Balance,
+    ) {
+        let balance = self.internal_unwrap_balance_of(token_id, account_id);
+        if let Some(new) = balance.checked_add(amount) {
+            self.assert_wallet_cap(token_id, account_id, new);
+            // Account which ran out of token takes a holder slot again
+            if balance == 0 && !self.internal_is_holder(token_id, account_id) {
+                self.assert_holders_cap(token_id, account_id);
+                self.internal_add_holder(token_id, account_id);
+            }
+            let supply = self
+                .total_supply
+                .get(token_id)
+                .unwrap()
+                .checked_add(amount)
+                .unwrap_or_else(|| env::panic_str("Total supply overflow"));
+            self.assert_kind_supply(token_id, supply);
+            let mut balances = self.balances_per_token.get(token_id).unwrap();
+            balances.insert(account_id, &new);
+            self.total_supply.insert(token_id, &supply);
+            self.internal_activity_balance_changed(token_id, account_id, balance, new);
+        } else {
+            env::panic_str("Balance overflow");
+        }
+    }
+
+    /// Subtract specified amount from user account in given token
+    pub fn internal_withdraw(
+        &mut self,
+        token_id: &TokenId,
+        account_id: &AccountId,
+        amount: Balance,
+    ) {
+        self.assert_not_dispute_locked(account_id, token_id);
+        let balance = self.internal_unwrap_balance_of(token_id, account_id);
+        if let Some(new) = balance.checked_sub(amount) {
+            let mut balances = self.balances_per_token.get(token_id).unwrap();
+            balances.insert(account_id, &new);
+            self.internal_activity_balance_changed(token_id, account_id, balance, new);
+            self.total_supply.insert(
+                token_id,
+                &self
+                    .total_supply
+                    .get(token_id)
+                    .unwrap()
+                    .checked_sub(amount)
+                    .unwrap_or_else(|| env::panic_str("Total supply overflow")),
+            );
+        } else {
+            MtError::InsufficientBalance { balance, amount }.panic();
+        }
+    }
+
+    pub fn internal_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        approval_id: Option<u64>,
+        amount: Balance,
+    ) -> (AccountId, Option<HashMap<AccountId, Approval>>) {
+        self.internal_transfer_with_memo(sender_id, receiver_id, token_id, approval_id, amount, None)
+    }
+
+    /// Same as `internal_transfer`, `memo` is included in event and indexed if it's tagged
+    pub fn internal_transfer_with_memo(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        approval_id: Option<u64>,
+        amount: Balance,
+        memo: Option<String>,
+    ) -> (AccountId, Option<HashMap<AccountId, Approval>>) {
+        // Safety checks
+        if sender_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        if amount == 0 {
+            MtError::ZeroAmount.panic();
+        }
+        self.assert_transferable(sender_id, receiver_id);
+        self.assert_no_compliance();
+
+        let owner_of_token = self.owner_by_id.get(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        // Fail before touching any state if receiver can't hold the token
+        self.internal_unwrap_balance_of(token_id, receiver_id);
+
+        // Approvals stay for the rest of owner's balance, fixed one used here is spent below
+        let approvals = self.approvals_by_id.as_ref().and_then(|by_id| by_id.get(token_id));
+
+        let has_token_approval = approvals.as_ref().map_or(false, |approvals| approvals.contains_key(sender_id));
+
+        // Approved account moves tokens of the owner and is reported as authorizer.
+        // Operator rights are unlimited, so series allowance is consumed only without them
+        let authorized_id = if sender_id != &owner_of_token
+            && !has_token_approval
+            && (self.internal_is_scoped_operator(&owner_of_token, sender_id, token_id)
+                || self.internal_use_series_approval(&owner_of_token, sender_id, token_id, amount))
+        {
+            Some(sender_id)
+        } else if sender_id != &owner_of_token {
+            let approved_accounts = approvals.as_ref().expect("Unauthorized");
+
+            let approval = approved_accounts.get(sender_id);
+
+            if approval.is_none() {
+                env::panic_str("Sender not approved");
+            }
+            require!(
+                !self.internal_is_approval_pending(sender_id, token_id),
+                "Approval is not confirmed by grantee yet"
+            );
+            require!(
+                !self.internal_is_approval_expired(sender_id, token_id, approval.unwrap().approval_id),
+                "Approval has expired"
+            );
+
+            if let Some(expected) = approval_id {
+                let actual = approval.unwrap().approval_id;
+                if actual != expected {
+                    MtError::ApprovalIdMismatch { expected, actual }.panic();
+                }
+            }
+
+            let owner_balance = self.internal_unwrap_balance_of(token_id, &owner_of_token);
+            require!(approval.unwrap().allowance(owner_balance) >= amount, "Not enough allowance");
+            Some(sender_id)
+        } else {
+            None
+        };
+
+        let owner_id = &owner_of_token;
+        if owner_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        self.assert_can_move(token_id, owner_id, receiver_id, amount);
+
+        self.internal_withdraw(token_id, owner_id, amount);
+        // Owner keeps its balance record, it's removed together with the token, but frees its holder slot
+        if self.internal_unwrap_balance_of(token_id, owner_id) == 0 {
+            self.internal_remove_holder(token_id, owner_id);
+        }
+        self.internal_deposit(token_id, receiver_id, amount);
+        self.internal_stamp_transfer(token_id, owner_id);
+        if sender_id != owner_id && has_token_approval {
+            self.internal_spend_approval(token_id, sender_id, amount);
+        }
+
+        if let Some(memo) = &memo {
+            self.internal_index_memo(owner_id, receiver_id, token_id, amount, memo);
+        }
+        self.emit_transfer(
+            owner_id,
+            receiver_id,
+            token_id,
+            amount,
+            authorized_id,
+            memo,
+        );
+        self.internal_record_transfer(owner_id, receiver_id, token_id, amount);
+        self.internal_mark_secondary_sale(token_id, owner_id);
+
+        (owner_id.to_owned(), approvals)
+    }
+
+    /// Checks every movement of `amount` of token out of `from_id` balance must pass,
+    /// whether it's a transfer or a move into escrow of an extension
+    pub fn assert_can_move(&self, token_id: &TokenId, from_id: &AccountId, to_id: &AccountId, amount: Balance) {
+        self.assert_transferable(from_id, to_id);
+        self.assert_not_transfer_locked(token_id);
+        self.assert_not_cooling_down(token_id, from_id);
+        self.assert_min_transfer_amount(token_id, amount);
+        self.assert_kind_amount(token_id, amount);
+    }
+
+    /// Move balance without approval checks, registering receiver in token if needed.
+    /// Used by extensions that hold tokens on behalf of users
+    pub(crate) fn internal_move(
+        &mut self,
+        token_id: &TokenId,
+        from_id: &AccountId,
+        to_id: &AccountId,
+        amount: Balance,
+        memo: &str,
+    ) {
+        let registered = self
+            .balances_per_token
+            .get(token_id)
+            .unwrap_or_else(|| MtError::TokenNotFound.panic())
+            .contains_key(to_id);
+        if !registered {
+            self.internal_register_account(token_id, to_id);
+        }
+
+        self.internal_withdraw(token_id, from_id, amount);
+        self.internal_deposit(token_id, to_id, amount);
+        self.internal_stamp_transfer(token_id, from_id);
+
+        self.emit_transfer(from_id, to_id, token_id, amount, None, Some(memo.to_string()));
+        self.internal_record_transfer(from_id, to_id, token_id, amount);
+    }
+
+    pub fn internal_batch_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_ids: &Vec<TokenId>,
+        approval_ids: Vec<Option<u64>>,
+        amounts: Vec<Balance>,
+    ) -> Vec<(AccountId, Option<HashMap<AccountId, Approval>>)> {
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), approval_ids.len()]);
+        // Checked for the whole batch before any balance is touched
+        if sender_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        token_ids.iter().enumerate().map(|(idx, token)| {
+            let amount: Balance = amounts[idx];
+            let approval = approval_ids[idx];
+            self.internal_transfer(sender_id, receiver_id, token, approval, amount)
+        }).collect()
+    }
+
+    /// Check everything `internal_transfer` checks for a single token, without panicking
+    pub fn internal_check_transfer(
+        &self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        approval_id: Option<u64>,
+        amount: Balance,
+    ) -> Result<(), String> {
+        if sender_id == receiver_id {
+            return Err(MtError::SenderIsReceiver.to_string());
+        }
+        if amount == 0 {
+            return Err(MtError::ZeroAmount.to_string());
+        }
+        let min = self.internal_min_transfer_amount(token_id);
+        if amount < min {
+            return Err(MtError::BelowMinAmount { min, amount }.to_string());
+        }
+        if let Some(until) = self.internal_active_transfer_lock(token_id) {
+            return Err(MtError::TransferLocked { until }.to_string());
+        }
+        self.internal_check_kind_amount(token_id, amount).map_err(|err| err.to_string())?;
+        let owner_id = self.owner_by_id.get(token_id).ok_or_else(|| MtError::TokenNotFound.to_string())?;
+        if &owner_id == receiver_id {
+            return Err(MtError::SenderIsReceiver.to_string());
+        }
+        if let Some(until) = self.internal_active_cooldown(token_id, &owner_id) {
+            return Err(MtError::TransferCooldown { until }.to_string());
+        }
+        let balances = self.balances_per_token.get(token_id).ok_or_else(|| MtError::TokenNotFound.to_string())?;
+        if !balances.contains_key(receiver_id) {
+            return Err(MtError::NotRegistered(receiver_id.clone()).to_string());
+        }
+        let balance = balances.get(&owner_id).ok_or_else(|| MtError::NotRegistered(owner_id.clone()).to_string())?;
+
+        let token_approval = self
+            .approvals_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(token_id))
+            .and_then(|approvals| approvals.get(sender_id).cloned());
+
+        let blanket_approved = token_approval.is_none()
+            && (self.internal_is_scoped_operator(&owner_id, sender_id, token_id)
+                || self.internal_series_allowance(&owner_id, sender_id, token_id) >= amount);
+
+        if sender_id != &owner_id && !blanket_approved {
+            let approval = token_approval.ok_or_else(|| "Sender not approved".to_string())?;
+            if self.internal_is_approval_pending(sender_id, token_id) {
+                return Err("Approval is not confirmed by grantee yet".to_string());
+            }
+            if self.internal_is_approval_expired(sender_id, token_id, approval.approval_id) {
+                return Err("Approval has expired".to_string());
+            }
+            if let Some(expected) = approval_id {
+                if approval.approval_id != expected {
+                    return Err(MtError::ApprovalIdMismatch { expected, actual: approval.approval_id }.to_string());
+                }
+            }
+            if approval.allowance(balance) < amount {
+                return Err("Not enough allowance".to_string());
+            }
+        }
+
+        if balance < amount {
+            return Err(MtError::InsufficientBalance { balance, amount }.to_string());
+        }
+        if self.internal_is_dispute_locked(&owner_id, token_id) {
+            return Err("Balance is locked by dispute".to_string());
+        }
+        let receiver_balance = balances.get(receiver_id).unwrap_or(0);
+        if !self.internal_fits_wallet_cap(token_id, receiver_id, receiver_balance.saturating_add(amount)) {
+            return Err("Receiver can't hold more of token".to_string());
+        }
+        if !self.internal_is_holder(token_id, receiver_id) && !self.internal_fits_holders_cap(token_id, receiver_id) {
+            return Err("Token reached maximum number of holders".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn internal_register_account(&mut self, token_id: &TokenId, account_id: &AccountId) {
+        self.assert_holders_cap(token_id, account_id);
+        if self
+            .balances_per_token
+            .get(token_id)
+            .unwrap()
+            .insert(account_id, &0)
+            .is_some()
+        {
+            env::panic_str("The account is already registered");
+        }
+        self.internal_add_holder(token_id, account_id);
+    }
+
+    /// Remember that `account_id` holds a balance entry in `token_id`
+    pub fn internal_add_holder(&mut self, token_id: &TokenId, account_id: &AccountId) {
+        let mut holders = self.holders_per_token.get(token_id).unwrap_or_else(|| {
+            UnorderedSet::new(StorageKey::HoldersPerTokenInner {
+                token_id: env::sha256(token_id.as_bytes()),
+            })
+        });
+        holders.insert(account_id);
+        self.holders_per_token.insert(token_id, &holders);
+        self.internal_touch_portfolio(account_id, token_id);
+    }
+
+    /// Whether `account_id` is counted as holder of `token_id`
+    pub fn internal_is_holder(&self, token_id: &TokenId, account_id: &AccountId) -> bool {
+        self.holders_per_token.get(token_id).map_or(false, |holders| holders.contains(account_id))
+    }
+
+    /// Forget that `account_id` holds a balance entry in `token_id`
+    pub fn internal_remove_holder(&mut self, token_id: &TokenId, account_id: &AccountId) {
+        if let Some(mut holders) = self.holders_per_token.get(token_id) {
+            holders.remove(account_id);
+            self.holders_per_token.insert(token_id, &holders);
+        }
+        self.internal_drop_from_portfolio(account_id, token_id);
+    }
+
+    pub fn internal_mint(
+        &mut self,
+        owner_id: AccountId,
+        owner_amount: Option<Balance>,
+        metadata: Option<TokenMetadata>,
+        refund_id: Option<AccountId>,
+    ) -> Token {
+        let token =
+            self.internal_mint_with_refund(owner_id.clone(), owner_amount, metadata, refund_id);
+        self.emit_mint(&owner_id, &token.token_id, &token.supply.0, None);
+
+        token
+    }
+
+    /// Mint token to `receiver_id` on behalf of `minter_id`, e.g. airdrop at mint. Minter pays
+    /// from attached deposit for storage of the token and, if contract manages storage and
+    /// receiver is not registered, for its registration. The rest is refunded to minter
+    pub fn internal_mint_to(
+        &mut self,
+        minter_id: AccountId,
+        receiver_id: AccountId,
+        owner_amount: Option<Balance>,
+        metadata: Option<TokenMetadata>,
+    ) -> Token {
+        let registration = self.internal_storage_register_paid(&receiver_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let token = self.internal_mint_with_refund(receiver_id.clone(), owner_amount, metadata, None);
+        tracker.stop();
+
+        let required = registration + env::storage_byte_cost() * Balance::from(tracker.bytes_added);
+        let attached = env::attached_deposit();
+        require!(required <= attached, format!("Must attach {} yoctoNEAR to cover storage", required));
+        if attached - required > 1 {
+            Promise::new(minter_id).transfer(attached - required);
+        }
+
+        self.emit_mint(&receiver_id, &token.token_id, &token.supply.0, None);
+
+        token
+    }
+
+    /// Mint several tokens to `owner_id` and emit single mint event for all of them.
+    /// Storage is measured over the whole batch, unused attached deposit is refunded to `refund_id`
+    /// if it's given.
+    pub fn internal_batch_mint(
+        &mut self,
+        owner_id: AccountId,
+        tokens: Vec<(Option<Balance>, Option<TokenMetadata>)>,
+        refund_id: Option<AccountId>,
+    ) -> Vec<Token> {
+        require!(!tokens.is_empty(), "Nothing to mint");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        let (minted, amounts): (Vec<Token>, Vec<String>) = tokens
+            .into_iter()
+            .map(|(owner_amount, metadata)| {
+                let token = self.internal_mint_with_refund(owner_id.clone(), owner_amount, metadata, None);
+                (token, owner_amount.unwrap_or(0).to_string())
+            })
+            .unzip();
+
+        tracker.stop();
+        if let Some(refund_id) = refund_id {
+            tracker.refund(refund_id);
+        }
+
+        let token_ids: Vec<&str> = minted.iter().map(|token| token.token_id.as_str()).collect();
+        let amounts: Vec<&str> = amounts.iter().map(|amount| amount.as_str()).collect();
+        MtMint { owner_id: &owner_id, token_ids: &token_ids, amounts: &amounts, memo: None, batch_id: self.batch_id.as_deref() }.emit();
+
+        minted
+    }
+
+    /// Mint a new token without checking:
+    /// * Whether the caller id is equal to the `owner_id`
+    /// * `refund_id` will transfer the leftover balance after storage costs are calculated to the provided account.
+    ///   Typically, the account will be the owner. If `None`, will not refund. This is useful for delaying refunding
+    ///   until multiple tokens have been minted.
+    ///
+    /// Tokens get sequential ids from `next_token_id` ("0", "1", ...), each mint advances it.
+    ///
+    /// Returns the newly minted token and does not emit the mint event. This allows minting multiple before emitting.
+    pub fn internal_mint_with_refund(
+        &mut self,
+        token_owner_id: AccountId,
+        owner_amount: Option<Balance>,
+        mut token_metadata: Option<TokenMetadata>,
+        refund_id: Option<AccountId>,
+    ) -> Token {
+        // Remember current storage usage if refund_id is Some
+        let initial_storage_usage = refund_id.map(|account_id| (account_id, env::storage_usage()));
+
+        // Panic if contract is using metadata extension and caller must provide it
+        if self.token_metadata_by_id.is_some() && token_metadata.is_none() {
+            env::panic_str("MUST provide metadata");
+        }
+        if let Some(metadata) = &token_metadata {
+            metadata.assert_valid_attributes();
+        }
+
+        let token_id = TokenId::from(self.next_token_id.to_string());
+
+        // Increment next id of the token. Panic if it's overflowing u64::MAX
+        self.next_token_id = self
+            .next_token_id
+            .checked_add(1)
+            .expect("u64 overflow, cannot mint any more tokens");
+        self.internal_set_minted_kind(&token_id, token_metadata.as_mut(), owner_amount);
+
+        // If contract uses approval management create new LookupMap for approvals
+        self.next_approval_id_by_id
+            .as_mut()
+            .and_then(|internal| internal.insert(&token_id, &0));
+
+        // Alias
+        let owner_id: AccountId = token_owner_id;
+
+        // Insert new owner
+        self.owner_by_id.insert(&token_id, &owner_id);
+
+        // Insert new metadata
+        self.token_metadata_by_id
+            .as_mut()
+            .and_then(|by_id| by_id.insert(&token_id, &token_metadata.clone().unwrap()));
+        self.internal_index_expiry(&token_id, token_metadata.as_ref());
+        self.internal_lock_minted(&token_id);
+
+        // Insert new supply
+        let supply = owner_amount.unwrap_or(0);
+        self.total_supply.insert(&token_id, &supply);
+
+        // Insert new balance
+        let mut new_set: LookupMap<AccountId, u128> = LookupMap::new(StorageKey::BalancesInner {
+            token_id: env::sha256(token_id.as_bytes()),
+        });
+        new_set.insert(&owner_id, &owner_amount.unwrap_or(0));
+        self.balances_per_token.insert(&token_id, &new_set);
+        self.internal_add_holder(&token_id, &owner_id);
+        self.internal_activity_minted(&token_id, &owner_id, supply);
+
+        // Updates enumeration if extension is used
+        if let Some(per_owner) = &mut self.tokens_per_owner {
+            let mut token_ids = per_owner.get(&owner_id).unwrap_or_else(|| {
+                TreeMap::new(StorageKey::TokensPerOwner {
+                    account_hash: env::sha256(owner_id.as_bytes()),
+                })
+            });
+            token_ids.insert(&token_id, &());
+            per_owner.insert(&owner_id, &token_ids);
+        }
+
+        // Stuff for Approval Management extension, also check for presence of it first
+        let approved_account_ids = if self.approvals_by_id.is_some() {
+            Some(HashMap::new())
+        } else {
+            None
+        };
+
+        let kind = self.internal_token_kind(&token_id);
+
+        let token = Token {
+            token_id,
+            owner_id,
+            supply: U128(supply),
+            kind,
+            balances: HashMap::new(),
+            metadata: token_metadata,
+            approvals: approved_account_ids,
+            next_approval_id: Some(0),
+        };
+        self.internal_order_minted(&token);
+
+        if let Some((id, usage)) = initial_storage_usage {
+            refund_deposit_to_account(env::storage_usage() - usage, id);
+        }
+
+        token
+    }
+
+    /// Echo `batch_id` into events emitted during the rest of current call
+    pub fn set_batch_id(&mut self, batch_id: Option<String>) {
+        if let Some(batch_id) = &batch_id {
+            require!(batch_id.len() <= MAX_BATCH_ID_LEN, "Batch ID is too long");
+        }
+        self.batch_id = batch_id;
+    }
+
+    pub(crate) fn emit_transfer(
+        &self,
+        owner_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+        authorized_id: Option<&AccountId>,
+        memo: Option<String>,
+    ) {
+        MtTransfer {
+            old_owner_id: owner_id,
+            new_owner_id: receiver_id,
+            token_ids: &[token_id.as_str()],
+            amounts: &[&amount.to_string()],
+            authorized_id: authorized_id.filter(|authorized_id| *authorized_id != owner_id),
+            memo: memo.as_deref(),
+            batch_id: self.batch_id.as_deref(),
+        }
+            .emit();
+    }
+
+    pub(crate) fn emit_mint(&self, owner_id: &AccountId, token_id: &TokenId, amount: &Balance, memo: Option<String>) {
+        MtMint {
+            owner_id,
+            token_ids: &[token_id.as_str()],
+            amounts: &[&amount.to_string()],
+            memo: memo.as_deref(),
+            batch_id: self.batch_id.as_deref(),
+        }
+            .emit()
+    }
+
+    pub(crate) fn emit_burn(&self, owner_id: &AccountId, token_id: &TokenId, amount: &Balance, memo: Option<String>) {
+        MtBurn {
+            owner_id,
+            authorized_id: Some(owner_id),
+            token_ids: &[token_id.as_str()],
+            amounts: &[&amount.to_string()],
+            memo: memo.as_deref(),
+            batch_id: self.batch_id.as_deref(),
+        }
+            .emit()
+    }
+}
+
+impl MultiToken {
+    /// Compose URI of token using `base_uri` from contract metadata, see [token_uri]
+    /// Reject garbage `msg` of `*_transfer_call` before spending gas on receiver call
+    pub fn enable_msg_validation(&mut self, max_msg_len: u32) {
+        self.max_msg_len = Some(max_msg_len);
+    }
+
+    /// Check `msg` if validation is enabled. Emits rejection event and returns `false` if it's invalid
+    fn internal_validate_msg(
+        &self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_ids: &[TokenId],
+        msg: &str,
+    ) -> bool {
+        let max_msg_len = match self.max_msg_len {
+            Some(max_msg_len) => max_msg_len,
+            None => return true,
+        };
+
+        let reason = if msg.len() > max_msg_len as usize {
+            MsgRejectReason::TooLong
+        } else if near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(msg).is_err() {
+            MsgRejectReason::InvalidJson
+        } else {
+            return true;
+        };
+
+        let token_ids: Vec<&str> = token_ids.iter().map(|token_id| token_id.as_str()).collect();
+        MtTransferCallRejected { sender_id, receiver_id, token_ids: &token_ids, reason }.emit();
+
+        false
+    }
+
+    /// Replace metadata of existing token, e.g. from an owner-gated method of contract.
+    /// Panics if metadata of token was frozen by council. Storage is not charged
+    pub fn internal_update_token_metadata(&mut self, token_id: &TokenId, metadata: TokenMetadata) {
+        self.owner_by_id.get(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        self.assert_metadata_not_frozen(token_id);
+        metadata.assert_valid_attributes();
+
+        self.token_metadata_by_id
+            .as_mut()
+            .unwrap_or_else(|| env::panic_str("Metadata is not supported by contract"))
+            .insert(token_id, &metadata);
+    }
+
+    pub fn internal_token_uri(&self, base_uri: Option<&str>, token_id: &TokenId) -> Option<String> {
+        self.owner_by_id.get(token_id)?;
+        let metadata = self.token_metadata_by_id.as_ref().and_then(|by_id| by_id.get(token_id));
+
+        token_uri(base_uri, token_id, metadata.as_ref())
+    }
+}
+
+impl MultiTokenCore for MultiToken {
+    fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        approval: Option<u64>,
+    ) {
+        self.assert_call_deposit();
+        let sender_id = env::predecessor_account_id();
+        env::log_str(format!("Predecessor {}", sender_id).as_str());
+        self.internal_transfer(&sender_id, &receiver_id, &token_id, approval, amount.0);
+    }
+
+    fn mt_batch_transfer(&mut self, receiver_id: AccountId, token_ids: Vec<TokenId>, amounts: Vec<Amount>, approvals: Vec<Option<u64>>, batch_id: Option<String>) {
+        self.assert_call_deposit();
+        self.set_batch_id(batch_id);
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), approvals.len()]);
+        let sender = env::predecessor_account_id();
+        env::log_str(format!("Predecessor {}", sender).as_str());
+
+        let amounts = amounts.iter().map(|a| a.0).collect();
+        self.internal_batch_transfer(&sender, &receiver_id, &token_ids, approvals, amounts);
+    }
+
+    fn mt_batch_transfer_best_effort(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        approvals: Vec<Option<u64>>,
+        batch_id: Option<String>,
+    ) -> Vec<TransferResult> {
+        self.assert_call_deposit();
+        self.set_batch_id(batch_id);
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), approvals.len()]);
+        let sender = env::predecessor_account_id();
+        if sender == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        self.assert_transferable(&sender, &receiver_id);
+        self.assert_no_compliance();
+
+        token_ids
+            .iter()
+            .zip(amounts.iter())
+            .zip(approvals)
+            .map(|((token_id, amount), approval)| {
+                match self.internal_check_transfer(&sender, &receiver_id, token_id, approval, amount.0) {
+                    Ok(()) => {
+                        self.internal_transfer(&sender, &receiver_id, token_id, approval, amount.0);
+                        TransferResult::Ok
+                    }
+                    Err(reason) => TransferResult::Err(reason),
+                }
+            })
+            .collect()
+    }
+
+    fn mt_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        approval_id: Option<u64>,
+        msg: String,
+    ) -> PromiseOrValue<bool> {
+        self.assert_call_deposit();
+        let amount = U128::from(amount);
+
+        require!(
+            env::prepaid_gas() > gas::min_gas_for_transfer_call(),
+            "Not enough prepaid gas"
+        );
+        let sender_id = env::predecessor_account_id();
+
+        if !self.internal_validate_msg(&sender_id, &receiver_id, std::slice::from_ref(&token_id), &msg) {
+            return PromiseOrValue::Value(false);
+        }
+
+        let (old_owner, old_approvals) =
+            self.internal_transfer(&sender_id, &receiver_id, &token_id, approval_id, amount.0);
+
+        // Refund goes back to the approval sender spent, as in batch call
+        let approval_id = old_approvals
+            .as_ref()
+            .filter(|_| old_owner != sender_id)
+            .and_then(|approvals| approvals.get(&sender_id))
+            .map_or(0, |approval| approval.approval_id);
+
+        ext_receiver::mt_on_transfer(
+            sender_id.clone(),
+            vec![old_owner.clone()],
+            vec![token_id.clone()],
+            vec![amount],
+            msg,
+            receiver_id.clone(),
+            NO_DEPOSIT,
+            gas::gas_for_receiver(env::prepaid_gas()),
+        )
+            .then(ext_self::mt_resolve_transfer(
+                sender_id,
+                receiver_id,
+                vec![token_id],
+                vec![amount],
+                Some(vec![(old_owner, approval_id, amount)]),
+                env::current_account_id(),
+                NO_DEPOSIT,
+                GAS_FOR_RESOLVE_TRANSFER,
+            ))
+            .into()
+    }
+
+    fn mt_batch_transfer_call(&mut self, receiver_id: AccountId, token_ids: Vec<TokenId>, amounts: Vec<Amount>, approval_ids: Vec<Option<u64>>, msg: String, batch_id: Option<String>) -> PromiseOrValue<bool> {
+        self.assert_call_deposit();
+        let amounts: Vec<U128> = amounts.into_iter().map(U128::from).collect();
+        self.set_batch_id(batch_id);
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), approval_ids.len()]);
+
+        let count = token_ids.len() as u64;
+        require!(
+            count <= gas::MAX_BATCH_TRANSFER_CALL_LEN,
+            format!("Batch transfer call is limited to {} tokens", gas::MAX_BATCH_TRANSFER_CALL_LEN)
+        );
+        require!(
+            env::prepaid_gas() >= gas::min_gas_for_batch_transfer_call(count),
+            "Not enough prepaid gas"
+        );
+        let sender_id = env::predecessor_account_id();
+
+        if !self.internal_validate_msg(&sender_id, &receiver_id, &token_ids, &msg) {
+            return PromiseOrValue::Value(false);
+        }
+
+        let amounts_to = amounts.iter().map(|a| a.0).collect();
+
+        let tuples =
+            self.internal_batch_transfer(&sender_id, &receiver_id, &token_ids, approval_ids, amounts_to);
+
+        let mut owners = vec![];
+
+        // Positional per token: owner whose balance was moved, approval of sender used for it
+        // (0 if sender moved own tokens) and transferred amount
+        let approvals: Vec<(AccountId, ApprovalId, U128)> = tuples.into_iter()
+            .zip(amounts.iter())
+            .map(|((owner, approvals), amount)| {
+                owners.push(owner.clone());
+                let approval_id = approvals
+                    .as_ref()
+                    .filter(|_| owner != sender_id)
+                    .and_then(|approvals| approvals.get(&sender_id))
+                    .map_or(0, |approval| approval.approval_id);
+                (owner, approval_id, *amount)
+            }).collect();
+
+        ext_receiver::mt_on_transfer(
+            sender_id.clone(),
+            owners,
+            token_ids.clone(),
+            amounts.clone(),
+            msg,
+            receiver_id.clone(),
+            NO_DEPOSIT,
+            gas::gas_for_batch_receiver(env::prepaid_gas(), count),
+        )
+            .then(ext_self::mt_resolve_transfer(
+                sender_id,
+                receiver_id,
+                token_ids,
+                amounts,
+                Some(approvals),
+                env::current_account_id(),
+                NO_DEPOSIT,
+                gas::gas_for_resolve_batch(count),
+            ))
+            .into()
+    }
+
+    fn mt_approval_for_all(&mut self, owner: AccountId, approved: bool) {
+        todo!()
+    }
+
+    fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<U128> {
+        id.iter()
+            .map(|token_id| {
+                self.balances_per_token
+                    .get(token_id)
+                    .and_then(|balances| balances.get(&owner))
+                    .map(U128)
+                    .expect("User does not have account in of the tokens")
+            })
+            .collect()
+    }
+
+    fn mt_token(&self, token_id: TokenId) -> Option<Token> {
+        let metadata = if let Some(metadata_by_id) = &self.token_metadata_by_id {
+            metadata_by_id.get(&token_id)
+        } else {
+            None
+        };
+        let next_approval_id = self.next_approval_id_by_id.as_ref().and_then(|by_id| by_id.get(&token_id));
+        let supply = self.total_supply.get(&token_id)?;
+        let owner_id = self.owner_by_id.get(&token_id)?;
+        let approved_accounts = self
+            .approvals_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(&token_id).or_else(|| Some(HashMap::new())));
+        let balances = self.balances_per_token.get(&token_id)?;
+        let kind = self.internal_token_kind(&token_id);
+
+        Some(Token {
+            token_id,
+            owner_id,
+            supply: U128(supply),
+            kind,
+            balances: HashMap::new(),
+            metadata,
+            approvals: approved_accounts,
+            next_approval_id,
+        })
+    }
+}
+
+impl MultiToken {
+    /// Return unused part of `idx`-th token of `mt_on_transfer` to `sender_id`.
+    /// Receiver returns unused amounts positionally matched to `token_ids`, as strings or numbers.
+    /// If `mt_on_transfer` failed, the whole amount is unused and returned
+    ///
+    /// returns: used amount and amount burned because sender's account is gone, as in FT standard
+    pub fn internal_resolve_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver: AccountId,
+        token_id: TokenId,
+        idx: usize,
+        amount: U128,
+    ) -> (Balance, Balance) {
+        let amount: Balance = amount.into();
+
+        let (unused, reason) = match env::promise_result(0) {
+            PromiseResult::NotReady => env::abort(),
+            PromiseResult::Successful(value) => {
+                match near_sdk::serde_json::from_slice::<Vec<Amount>>(&value).ok().and_then(|unused| unused.get(idx).copied()) {
+                    Some(unused) if unused.0 > amount => (amount, RevertReason::ClampedAmount),
+                    Some(unused) => (unused.0, RevertReason::RefundRequested),
+                    None => (amount, RevertReason::InvalidResponse),
+                }
+            }
+            PromiseResult::Failed => (amount, RevertReason::ReceiverPanicked),
+        };
+
+        // All this `.get()` will not fail since it would fail before it gets to this call
+        if unused > 0 {
+            let mut balances = self.balances_per_token.get(&token_id).unwrap();
+            let receiver_balance = balances.get(&receiver).unwrap_or(0);
+
+            if receiver_balance > 0 {
+                let refund = std::cmp::min(receiver_balance, unused);
+                balances.insert(&receiver, &(receiver_balance - refund));
+
+                return if let Some(sender_balance) = balances.get(sender_id) {
+                    balances.insert(sender_id, &(sender_balance + refund));
+                    log!("Refund {} from {} to {}", refund, receiver, sender_id);
+                    MtTransferRevert {
+                        old_owner_id: &receiver,
+                        new_owner_id: sender_id,
+                        token_ids: &[token_id.as_str()],
+                        amounts: &[&refund.to_string()],
+                        reason,
+                        memo: None,
+                    }
+                    .emit();
+                    (amount - refund, 0)
+                } else {
+                    let supply = self.total_supply.get(&token_id).unwrap();
+                    self.total_supply.insert(&token_id, &(supply - refund));
+                    log!("The account of the sender was deleted");
+                    (amount, refund)
+                };
+            }
+        }
+        (amount, 0)
+    }
+}
+
+impl MultiTokenResolver for MultiToken {
+    fn mt_resolve_transfer(
+        &mut self,
+        sender_id: AccountId,
+        receiver: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
+    ) -> Vec<U128> {
+        // Macro marks this method as `#[private]`, but contracts may call it directly
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method mt_resolve_transfer is private"
+        );
+        let approvals_len = approvals.as_ref().map_or(token_ids.len(), |approvals| approvals.len());
+        assert_batch_lengths(token_ids.len(), &[amounts.len(), approvals_len]);
+
+        // In batch calls tokens may belong to different owners, they're given in `approvals`
+        let resolved: Vec<(Balance, Balance)> = token_ids.iter()
+            .enumerate()
+            .map(|(idx, token_id)| {
+                let owner_id = approvals
+                    .as_ref()
+                    .and_then(|approvals| approvals.get(idx))
+                    .map_or(&sender_id, |(owner_id, _, _)| owner_id);
+                let (used, burned) =
+                    self.internal_resolve_transfer(owner_id, receiver.clone(), token_id.clone(), idx, amounts[idx]);
+
+                // Approval the grantee spent gets the refunded part back
+                let approval_id = approvals.as_ref().and_then(|approvals| approvals.get(idx)).map_or(0, |(_, id, _)| *id);
+                let refund = amounts[idx].0 - used;
+                if refund > 0 && owner_id != &sender_id {
+                    self.internal_restore_approval(token_id, &sender_id, approval_id, refund);
+                }
+                (used, burned)
+            })
+            .collect();
+
+        let ids: Vec<&str> = token_ids.iter().map(|token_id| token_id.as_str()).collect();
+        let requested: Vec<String> = amounts.iter().map(|amount| amount.0.to_string()).collect();
+        let refunded: Vec<String> =
+            amounts.iter().zip(resolved.iter()).map(|(amount, (used, _))| (amount.0 - used).to_string()).collect();
+        let kept: Vec<String> = resolved.iter().map(|(used, burned)| (used - burned).to_string()).collect();
+        MtTransferCallResult {
+            sender_id: &sender_id,
+            receiver_id: &receiver,
+            token_ids: &ids,
+            requested: &requested.iter().map(|amount| amount.as_str()).collect::<Vec<&str>>(),
+            refunded: &refunded.iter().map(|amount| amount.as_str()).collect::<Vec<&str>>(),
+            kept: &kept.iter().map(|amount| amount.as_str()).collect::<Vec<&str>>(),
+        }
+        .emit();
+
+        resolved.into_iter().map(|(used, _)| used.into()).collect()
+    }
+}
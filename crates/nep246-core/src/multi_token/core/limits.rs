@@ -0,0 +1,104 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::token::TokenId;
+
+/// Deposits of single sender in single token during current window
+#[derive(BorshDeserialize, BorshSerialize, Default, Debug, Clone, PartialEq)]
+pub struct DepositUsage {
+    /// Start of window, block timestamp in nanoseconds
+    pub window_start: u64,
+    pub amount: Balance,
+    pub deposits: u32,
+}
+
+/// Helper for receiving contracts that limits deposits of each sender in `mt_on_transfer`.
+/// Amounts that do not fit into limits are returned as unused, so MT contract refunds them.
+///
+/// ```ignore
+/// fn mt_on_transfer(&mut self, sender_id: AccountId, previous_owner_ids: Vec<AccountId>,
+///                   token_ids: Vec<TokenId>, amounts: Vec<U128>, msg: String) -> PromiseOrValue<Vec<U128>> {
+///     PromiseOrValue::Value(self.limiter.accept(&sender_id, &token_ids, &amounts))
+/// }
+/// ```
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DepositLimiter {
+    /// Deposits smaller than this are refunded completely
+    pub min_amount: Balance,
+    /// Maximum amount that sender may deposit in one token during window
+    pub max_amount_per_window: Balance,
+    /// Maximum number of deposits that sender may make in one token during window
+    pub max_deposits_per_window: u32,
+    /// Length of window in nanoseconds
+    pub window: u64,
+    /// Usage by (MT contract, sender, token)
+    usage: LookupMap<(AccountId, AccountId, TokenId), DepositUsage>,
+}
+
+impl DepositLimiter {
+    pub fn new<P: IntoStorageKey>(
+        prefix: P,
+        min_amount: Balance,
+        max_amount_per_window: Balance,
+        max_deposits_per_window: u32,
+        window: u64,
+    ) -> Self {
+        require!(window > 0, "Window cannot be 0");
+
+        Self { min_amount, max_amount_per_window, max_deposits_per_window, window, usage: LookupMap::new(prefix) }
+    }
+
+    /// Get current usage of sender. MT contract is taken from predecessor
+    pub fn usage_of(&self, sender_id: &AccountId, token_id: &TokenId) -> DepositUsage {
+        let key = (env::predecessor_account_id(), sender_id.clone(), token_id.clone());
+
+        self.usage
+            .get(&key)
+            .filter(|usage| usage.window_start + self.window > env::block_timestamp())
+            .unwrap_or_default()
+    }
+
+    /// Count deposits of sender. MT contract is taken from predecessor.
+    ///
+    /// returns: unused amount for each token, to be returned from `mt_on_transfer`
+    pub fn accept(&mut self, sender_id: &AccountId, token_ids: &[TokenId], amounts: &[U128]) -> Vec<U128> {
+        require!(token_ids.len() == amounts.len(), "Length of token_ids and amounts must be equal");
+
+        let now = env::block_timestamp();
+        let contract_id = env::predecessor_account_id();
+
+        token_ids
+            .iter()
+            .zip(amounts.iter())
+            .map(|(token_id, amount)| {
+                if amount.0 < self.min_amount {
+                    return *amount;
+                }
+
+                let key = (contract_id.clone(), sender_id.clone(), token_id.clone());
+                let mut usage = self
+                    .usage
+                    .get(&key)
+                    .filter(|usage| usage.window_start + self.window > now)
+                    .unwrap_or(DepositUsage { window_start: now, amount: 0, deposits: 0 });
+
+                if usage.deposits >= self.max_deposits_per_window {
+                    return *amount;
+                }
+
+                let accepted = std::cmp::min(amount.0, self.max_amount_per_window.saturating_sub(usage.amount));
+                if accepted == 0 {
+                    return *amount;
+                }
+
+                usage.amount += accepted;
+                usage.deposits += 1;
+                self.usage.insert(&key, &usage);
+
+                U128(amount.0 - accepted)
+            })
+            .collect()
+    }
+}
@@ -3,21 +3,37 @@
  */
 
 mod core_impl;
+mod limits;
 mod receiver;
 mod resolver;
 
 pub use self::core_impl::*;
+pub use self::limits::*;
 pub use self::receiver::*;
 pub use self::resolver::*;
 
 pub type ApprovalId = u64;
 
+/// Owner and token where grantee is approved
+pub type GranteeEntry = (AccountId, TokenId);
+
+use crate::multi_token::json_types::Amount;
 use crate::multi_token::token::TokenId;
-use near_sdk::{AccountId, Balance, PromiseOrValue};
+use near_sdk::{AccountId, PromiseOrValue};
 use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
 
 use super::token::Token;
 
+/// Outcome of single element of best-effort batch transfer.
+/// Serialized as `"Ok"` or `{"Err": "<reason>"}`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TransferResult {
+    Ok,
+    Err(String),
+}
+
 /// Describes functionality according to this - https://eips.ethereum.org/EIPS/eip-1155
 /// And this - <https://github.com/shipsgold/NEPs/blob/master/specs/Standards/MultiToken/Core.md>
 pub trait MultiTokenCore {
@@ -35,7 +51,7 @@ pub trait MultiTokenCore {
         &mut self,
         receiver_id: AccountId,
         token_id: TokenId,
-        amount: Balance,
+        amount: Amount,
         approval: Option<u64>,
     );
 
@@ -52,6 +68,7 @@ pub trait MultiTokenCore {
     /// * `approval`: expected approval IDs per `token_ids`. If a `token_id` does
     ///    not have a corresponding approval id then the entry in the array must be marked null.
     ///    The `approval_ids` are numbers smaller than 2^5
+    /// * `batch_id`: optional ID echoed into every emitted event, so indexers can group them
     ///
     /// returns: ()
     ///
@@ -59,8 +76,26 @@ pub trait MultiTokenCore {
         &mut self,
         receiver_id: AccountId,
         token_ids: Vec<TokenId>,
-        amounts: Vec<Balance>,
-        approvals: Vec<Option<u64>>);
+        amounts: Vec<Amount>,
+        approvals: Vec<Option<u64>>,
+        batch_id: Option<String>);
+
+    /// Same as `mt_batch_transfer`, but elements that can't be transferred are skipped
+    /// instead of failing the whole batch
+    ///
+    /// Checks that apply to the whole batch (deposit, lengths, paused transfers, frozen
+    /// accounts) still panic.
+    ///
+    /// returns: Vec<TransferResult>, status of each element in order of `token_ids`
+    ///
+    fn mt_batch_transfer_best_effort(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        approvals: Vec<Option<u64>>,
+        batch_id: Option<String>,
+    ) -> Vec<TransferResult>;
 
 
     /// Transfer MT and call a method on receiver contract. A successful
@@ -76,13 +111,14 @@ pub trait MultiTokenCore {
     /// * `memo`: Used as context
     /// * `msg`: Additional msg that will be passed to receiving contract
     ///
-    /// returns: PromiseOrValue<bool>
+    /// returns: PromiseOrValue<bool>. If `msg` validation is enabled and `msg` is rejected,
+    /// nothing is transferred and `false` is returned
     ///
     fn mt_transfer_call(
         &mut self,
         receiver_id: AccountId,
         token_id: TokenId,
-        amount: Balance,
+        amount: Amount,
         approval_id: Option<u64>,
         msg: String,
     ) -> PromiseOrValue<bool>;
@@ -95,6 +131,11 @@ pub trait MultiTokenCore {
     /// function call. It allows you to attach any Multi Token, token in a call to a
     /// receiver contract.
     ///
+    /// Gas kept for the transfer and the resolver grows with number of tokens, see
+    /// [gas_reserved_for_batch_transfer_call](crate::multi_token::gas::gas_reserved_for_batch_transfer_call),
+    /// the rest is forwarded to `mt_on_transfer`. Contract panics if prepaid gas doesn't leave receiver
+    /// at least `GAS_FOR_ON_TRANSFER_PER_TOKEN` per token or batch is longer than `MAX_BATCH_TRANSFER_CALL_LEN`
+    ///
     /// # Arguments
     ///
     /// * `receiver_id`: NEAR account receiving MT
@@ -108,16 +149,19 @@ pub trait MultiTokenCore {
     ///    ApprovalId See Approval Management standard for full explanation.
     /// * `memo`: Used as context
     /// * `msg`: Additional msg that will be passed to receiving contract
+    /// * `batch_id`: optional ID echoed into transfer events, refunds in `mt_resolve_transfer` are not grouped
     ///
-    /// returns: PromiseOrValue<bool>
+    /// returns: PromiseOrValue<bool>. If `msg` validation is enabled and `msg` is rejected,
+    /// nothing is transferred and `false` is returned
     ///
     fn mt_batch_transfer_call(
         &mut self,
         receiver_id: AccountId,
         token_ids: Vec<TokenId>,
-        amounts: Vec<U128>,
+        amounts: Vec<Amount>,
         approval_ids: Vec<Option<u64>>,
         msg: String,
+        batch_id: Option<String>,
     ) -> PromiseOrValue<bool>;
 
 
@@ -129,7 +173,7 @@ pub trait MultiTokenCore {
     ///
     /// * `owner`: Account to check
     /// # `id`: Vector of token IDs
-    fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<u128>;
+    fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<U128>;
 
 
     /// Get all info about token
@@ -3,7 +3,7 @@ use near_sdk::json_types::U128;
 use near_sdk::AccountId;
 use crate::multi_token::core::ApprovalId;
 
-//// `resolve_transfer` will be called after `on_transfer`
+/// `resolve_transfer` will be called after `on_transfer`
 pub trait MultiTokenResolver {
     /// Finalize an `mt_transfer_call` or `mt_batch_transfer_call` chain of cross-contract calls. Generically
     /// referred to as `mt_transfer_call` as it applies to `mt_batch_transfer_call` as well.
@@ -18,8 +18,10 @@ pub trait MultiTokenResolver {
     ///    transfer token back to sender
     ///
     /// Requirements:
-    /// * Contract MUST forbid calls to this function by any account except self
-    /// * If promise chain failed, contract MUST revert token transfer
+    /// * Contract MUST forbid calls to this function by any account except self.
+    ///   [MultiToken](crate::multi_token::core::MultiToken) panics itself if predecessor is not current account
+    /// * If promise chain failed, contract MUST revert token transfer: the whole amount
+    ///   is returned to `sender_id` and counted as unused
     /// * If promise chain resolves with `true`, contract MUST return token to
     ///   `sender_id`
     ///
@@ -51,7 +53,6 @@ pub trait MultiTokenResolver {
     /// Example: if sender_id calls `mt_transfer_call({ "amounts": ["100"], token_ids: ["55"], receiver_id: "games" })`,
     /// but `receiver_id` only uses 80, `mt_on_transfer` will resolve with `["20"]`, and `mt_resolve_transfer`
     /// will return `["80"]`.
-
     fn mt_resolve_transfer(
         &mut self,
         sender_id: AccountId,
@@ -0,0 +1,112 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base64VecU8, U128};
+use near_sdk::serde_json::json;
+use near_sdk::{env, ext_contract, require, AccountId, Balance, Gas, Promise};
+
+use crate::multi_token::{
+    core::MultiToken,
+    keeper::KeeperTask,
+    utils::{expect_extension, unauthorized_assert, Entity},
+};
+
+use super::MultiTokenCroncat;
+
+pub use crate::multi_token::gas::{GAS_FOR_CRONCAT_MANAGER, GAS_FOR_SCHEDULED_RUN};
+
+/// Deposit required by `remove_task` of Croncat manager
+const ONE_YOCTO: Balance = 1;
+
+/// Manager contract of Croncat, see <https://github.com/CronCats/contracts>
+#[ext_contract(ext_croncat)]
+pub trait CroncatManager {
+    fn create_task(
+        &mut self,
+        contract_id: AccountId,
+        function_id: String,
+        cadence: String,
+        recurring: Option<bool>,
+        deposit: Option<U128>,
+        gas: Option<Gas>,
+        arguments: Option<Base64VecU8>,
+    ) -> Base64VecU8;
+
+    fn remove_task(&mut self, task_hash: Base64VecU8);
+}
+
+/// Croncat manager scheduled calls are accepted from
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Croncat {
+    pub manager_id: AccountId,
+}
+
+impl MultiToken {
+    /// Allow scheduling of operations through Croncat `manager_id`
+    pub fn enable_croncat(&mut self, manager_id: AccountId) {
+        self.croncat = Some(Croncat { manager_id });
+    }
+
+    /// Panic unless caller is configured Croncat manager. Use in methods scheduled with
+    /// `internal_croncat_create_task`
+    pub fn assert_croncat_call(&self) {
+        let croncat = expect_extension(self.croncat.as_ref(), "Croncat", Entity::Contract);
+        require!(env::predecessor_account_id() == croncat.manager_id, "Only Croncat manager can call this method");
+    }
+
+    /// Register recurring call of `function_id` of the contract on Croncat manager,
+    /// attaching `deposit` to pay agents. Manager returns hash of the task
+    pub fn internal_croncat_create_task(
+        &self,
+        function_id: &str,
+        cadence: String,
+        arguments: Vec<u8>,
+        gas: Gas,
+        deposit: Balance,
+    ) -> Promise {
+        let croncat = expect_extension(self.croncat.as_ref(), "Croncat", Entity::Contract);
+
+        ext_croncat::create_task(
+            env::current_account_id(),
+            function_id.to_string(),
+            cadence,
+            Some(true),
+            Some(U128(0)),
+            Some(gas),
+            Some(arguments.into()),
+            croncat.manager_id.clone(),
+            deposit,
+            GAS_FOR_CRONCAT_MANAGER,
+        )
+    }
+}
+
+impl MultiTokenCroncat for MultiToken {
+    fn mt_croncat_schedule(&mut self, task: KeeperTask, limit: u64, cadence: String) -> Promise {
+        unauthorized_assert(&self.owner_id);
+        require!(limit != 0, "Limit cannot be 0");
+        expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract);
+
+        let arguments = json!({ "task": task, "limit": limit }).to_string().into_bytes();
+        let deposit = env::attached_deposit();
+        self.internal_croncat_create_task("mt_croncat_run", cadence, arguments, GAS_FOR_SCHEDULED_RUN, deposit)
+    }
+
+    fn mt_croncat_unschedule(&mut self, task_hash: Base64VecU8) -> Promise {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+
+        let croncat = expect_extension(self.croncat.as_ref(), "Croncat", Entity::Contract);
+        ext_croncat::remove_task(task_hash, croncat.manager_id.clone(), ONE_YOCTO, GAS_FOR_CRONCAT_MANAGER)
+    }
+
+    fn mt_croncat_run(&mut self, task: KeeperTask, limit: u64) -> u64 {
+        self.assert_croncat_call();
+        require!(limit != 0, "Limit cannot be 0");
+        expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract);
+
+        self.internal_run_keeper_task(task, limit)
+    }
+
+    fn mt_croncat_manager(&self) -> AccountId {
+        expect_extension(self.croncat.as_ref(), "Croncat", Entity::Contract).manager_id.clone()
+    }
+}
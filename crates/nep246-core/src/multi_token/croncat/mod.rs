@@ -0,0 +1,50 @@
+/*! Scheduled operations through [Croncat](https://cron.cat)
+
+Owner registers recurring tasks on configured Croncat manager, agents of the manager then call
+the contract on schedule. Keeper tasks (expiry sweeps, record GC) are scheduled directly with
+`mt_croncat_schedule` and run through `mt_croncat_run`, which accepts calls only from the manager.
+Contracts schedule their own methods, e.g. vesting releases, with `internal_croncat_create_task`
+and guard them with `assert_croncat_call`.
+ */
+
+mod croncat_impl;
+
+pub use croncat_impl::*;
+
+use crate::multi_token::keeper::KeeperTask;
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::{AccountId, Promise};
+
+pub trait MultiTokenCroncat {
+    /// Schedule recurring run of keeper task on Croncat manager. Attached deposit is forwarded
+    /// to the manager to pay its agents, task hash is returned by the manager
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    ///
+    /// # Arguments:
+    /// * `cadence` - Cron expression, e.g. `0 0 * * * *` for every hour
+    /// * `limit` - Passed to every run of the task
+    fn mt_croncat_schedule(&mut self, task: KeeperTask, limit: u64, cadence: String) -> Promise;
+
+    /// Remove task from Croncat manager, unused deposit is refunded by the manager
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn mt_croncat_unschedule(&mut self, task_hash: Base64VecU8) -> Promise;
+
+    /// Run keeper task on schedule. Cooldowns and rewards of keepers don't apply,
+    /// agents are paid by the manager
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if caller is not Croncat manager
+    /// * Keeper extension must be enabled
+    ///
+    /// # Returns
+    /// Number of processed items
+    fn mt_croncat_run(&mut self, task: KeeperTask, limit: u64) -> u64;
+
+    /// Get account of Croncat manager
+    fn mt_croncat_manager(&self) -> AccountId;
+}
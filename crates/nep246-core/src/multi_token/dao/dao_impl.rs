@@ -0,0 +1,99 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupSet;
+use near_sdk::{env, ext_contract, log, require, AccountId, Balance, IntoStorageKey, Promise, PromiseResult};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::Token,
+    utils::{expect_extension, Entity},
+};
+
+use super::{DaoProposal, MintOrder, MultiTokenDaoMint};
+
+pub use crate::multi_token::gas::{GAS_FOR_DAO_MINT, GAS_FOR_DAO_VIEW};
+
+const NO_DEPOSIT: Balance = 0;
+
+#[ext_contract(ext_dao)]
+trait SputnikDao {
+    fn get_proposal(&self, id: u64) -> DaoProposal;
+}
+
+#[ext_contract(ext_self)]
+trait MtDaoResolver {
+    fn mt_on_dao_proposal(&mut self, proposal_id: u64, order: MintOrder) -> Option<Token>;
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DaoMint {
+    pub dao_id: AccountId,
+    /// Proposals that were already used to mint
+    pub used_proposals: LookupSet<u64>,
+}
+
+impl MultiToken {
+    /// Allow minting by approved proposals of `dao_id`
+    pub fn enable_dao_mint<P: IntoStorageKey>(&mut self, prefix: P, dao_id: AccountId) {
+        self.dao_mint = Some(DaoMint { dao_id, used_proposals: LookupSet::new(prefix) });
+    }
+}
+
+impl MultiTokenDaoMint for MultiToken {
+    fn mt_dao_mint(&mut self, proposal_id: u64, order: MintOrder) -> Promise {
+        self.assert_call_deposit();
+        let dao = expect_extension(self.dao_mint.as_ref(), "DAO mint", Entity::Contract);
+
+        require!(!dao.used_proposals.contains(&proposal_id), "Proposal was already used");
+        require!(
+            env::prepaid_gas() > GAS_FOR_DAO_VIEW + GAS_FOR_DAO_MINT,
+            "Not enough prepaid gas"
+        );
+
+        ext_dao::get_proposal(proposal_id, dao.dao_id.clone(), NO_DEPOSIT, GAS_FOR_DAO_VIEW).then(
+            ext_self::mt_on_dao_proposal(
+                proposal_id,
+                order,
+                env::current_account_id(),
+                NO_DEPOSIT,
+                GAS_FOR_DAO_MINT,
+            ),
+        )
+    }
+
+    fn mt_on_dao_proposal(&mut self, proposal_id: u64, order: MintOrder) -> Option<Token> {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method mt_on_dao_proposal is private"
+        );
+
+        let proposal = match env::promise_result(0) {
+            PromiseResult::Successful(value) => {
+                near_sdk::serde_json::from_slice::<DaoProposal>(&value).ok()
+            }
+            _ => None,
+        };
+
+        let proposal = match proposal {
+            Some(proposal) => proposal,
+            None => {
+                log!("Could not fetch proposal {}", proposal_id);
+                return None;
+            }
+        };
+
+        if proposal.status != "Approved" || !proposal.description.contains(&order.hash()) {
+            log!("Proposal {} does not approve this mint", proposal_id);
+            return None;
+        }
+
+        let dao = expect_extension(self.dao_mint.as_mut(), "DAO mint", Entity::Contract);
+        // Could be used by another mint while this one was waiting for DAO
+        if !dao.used_proposals.insert(&proposal_id) {
+            log!("Proposal {} was already used", proposal_id);
+            return None;
+        }
+
+        let MintOrder { owner_id, amount, metadata } = order;
+        Some(self.internal_mint(owner_id, Some(amount.0), metadata, None))
+    }
+}
@@ -0,0 +1,60 @@
+/*! Minting authorized by proposals of Sputnik DAO
+
+Mint is executed only if DAO has an approved proposal with hash of [MintOrder]
+in its description. Every proposal can be used for a single mint.
+ */
+
+mod dao_impl;
+
+pub use dao_impl::*;
+
+use crate::multi_token::metadata::TokenMetadata;
+use crate::multi_token::token::Token;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, Promise};
+
+/// Parameters of mint that DAO votes for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintOrder {
+    pub owner_id: AccountId,
+    pub amount: U128,
+    pub metadata: Option<TokenMetadata>,
+}
+
+impl MintOrder {
+    /// Hex-encoded sha256 of borsh-serialized order. Must be included in description of proposal
+    pub fn hash(&self) -> String {
+        env::sha256(&self.try_to_vec().unwrap_or_else(|_| env::abort()))
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Fields of Sputnik DAO proposal that are checked before mint
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DaoProposal {
+    pub description: String,
+    pub status: String,
+}
+
+pub trait MultiTokenDaoMint {
+    /// Mint token if DAO approved proposal with hash of `order` in description
+    ///
+    /// # Arguments:
+    /// * `proposal_id` - ID of proposal in DAO
+    /// * `order` - parameters of mint
+    ///
+    /// returns: Promise resolving with minted token, or `None` if proposal is not approved
+    fn mt_dao_mint(&mut self, proposal_id: u64, order: MintOrder) -> Promise;
+
+    /// Callback after proposal was fetched from DAO
+    ///
+    /// # Requirements:
+    /// * Contract MUST forbid calls to this function by any account except self
+    fn mt_on_dao_proposal(&mut self, proposal_id: u64, order: MintOrder) -> Option<Token>;
+}
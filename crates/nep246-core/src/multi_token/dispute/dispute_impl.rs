@@ -0,0 +1,92 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    events::{MtDisputeLock, MtDisputeRelease},
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::MultiTokenDispute;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Disputes {
+    pub arbiter_id: AccountId,
+    /// Longest lock arbiter can place, in nanoseconds
+    pub max_lock_duration: u64,
+    /// Expiry of lock by (owner, token)
+    pub locks: LookupMap<(AccountId, TokenId), u64>,
+}
+
+impl MultiToken {
+    /// Allow `arbiter_id` to lock balances during disputes
+    pub fn enable_disputes<P: IntoStorageKey>(&mut self, prefix: P, arbiter_id: AccountId, max_lock_duration: u64) {
+        self.disputes = Some(Disputes { arbiter_id, max_lock_duration, locks: LookupMap::new(prefix) });
+    }
+
+    /// Change arbiter. Can only be called by contract owner
+    pub fn set_dispute_arbiter(&mut self, arbiter_id: AccountId) {
+        unauthorized_assert(&self.owner_id);
+
+        self.disputes.as_mut().expect("Disputes are not enabled").arbiter_id = arbiter_id;
+    }
+
+    /// Whether balance of `owner_id` in token is locked by unexpired dispute
+    pub fn internal_is_dispute_locked(&self, owner_id: &AccountId, token_id: &TokenId) -> bool {
+        self.disputes.as_ref().map_or(false, |disputes| {
+            disputes
+                .locks
+                .get(&(owner_id.clone(), token_id.clone()))
+                .map_or(false, |expires_at| env::block_timestamp() < expires_at)
+        })
+    }
+
+    /// Panics if balance of `owner_id` in token is locked
+    pub fn assert_not_dispute_locked(&self, owner_id: &AccountId, token_id: &TokenId) {
+        require!(!self.internal_is_dispute_locked(owner_id, token_id), "Balance is locked by dispute");
+    }
+}
+
+impl MultiTokenDispute for MultiToken {
+    fn mt_dispute_lock(&mut self, owner_id: AccountId, token_id: TokenId, duration: u64, reason: Option<String>) {
+        self.assert_storage_call_deposit();
+        let disputes = expect_extension(self.disputes.as_ref(), "Disputes", Entity::Contract);
+        unauthorized_assert(&disputes.arbiter_id);
+        require!(duration > 0 && duration <= disputes.max_lock_duration, "Invalid lock duration");
+        // Contract holds escrowed balances of others, locking them would block unrelated settlements
+        require!(owner_id != env::current_account_id(), "Cannot lock balance of the contract itself");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let expires_at = env::block_timestamp() + duration;
+        self.disputes.as_mut().unwrap().locks.insert(&(owner_id.clone(), token_id.clone()), &expires_at);
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+
+        MtDisputeLock { owner_id: &owner_id, token_id: &token_id, expires_at, reason: reason.as_deref() }.emit();
+    }
+
+    fn mt_dispute_release(&mut self, owner_id: AccountId, token_id: TokenId, reason: Option<String>) {
+        self.assert_call_deposit();
+        let disputes = expect_extension(self.disputes.as_mut(), "Disputes", Entity::Contract);
+        unauthorized_assert(&disputes.arbiter_id);
+
+        require!(disputes.locks.remove(&(owner_id.clone(), token_id.clone())).is_some(), "Balance is not locked");
+
+        MtDisputeRelease { owner_id: &owner_id, token_id: &token_id, reason: reason.as_deref() }.emit();
+    }
+
+    fn mt_dispute_lock_of(&self, owner_id: AccountId, token_id: TokenId) -> Option<u64> {
+        self.disputes
+            .as_ref()?
+            .locks
+            .get(&(owner_id, token_id))
+            .filter(|expires_at| env::block_timestamp() < *expires_at)
+    }
+
+    fn mt_dispute_arbiter(&self) -> AccountId {
+        expect_extension(self.disputes.as_ref(), "Disputes", Entity::Contract).arbiter_id.clone()
+    }
+}
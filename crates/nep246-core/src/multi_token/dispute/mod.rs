@@ -0,0 +1,37 @@
+/*! Transfer locks during disputes
+
+Arbiter chosen by contract owner can lock balance of account in a token for a limited time,
+e.g. while a chargeback is investigated. Locked balance can't leave the account until arbiter
+releases it or the lock expires. Locks and releases are reported with events.
+ */
+
+mod dispute_impl;
+
+pub use dispute_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::AccountId;
+
+pub trait MultiTokenDispute {
+    /// Lock balance of `owner_id` in token for `duration` nanoseconds. Locking again replaces expiry
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than arbiter
+    /// * Contract MUST panic if `duration` is 0 or exceeds maximum lock duration
+    /// * Contract MUST panic if `owner_id` is the contract itself, which holds escrowed balances
+    fn mt_dispute_lock(&mut self, owner_id: AccountId, token_id: TokenId, duration: u64, reason: Option<String>);
+
+    /// Release lock before it expires
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than arbiter
+    fn mt_dispute_release(&mut self, owner_id: AccountId, token_id: TokenId, reason: Option<String>);
+
+    /// Get expiry of active lock of `owner_id` in token, in nanoseconds
+    fn mt_dispute_lock_of(&self, owner_id: AccountId, token_id: TokenId) -> Option<u64>;
+
+    /// Get current arbiter
+    fn mt_dispute_arbiter(&self) -> AccountId;
+}
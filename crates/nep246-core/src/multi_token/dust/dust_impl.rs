@@ -0,0 +1,61 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::MultiTokenMinTransfer;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MinTransferAmounts {
+    pub by_id: LookupMap<TokenId, Balance>,
+}
+
+impl MultiToken {
+    /// Allow token creators to set minimum transfer amount
+    pub fn enable_min_transfer_amounts<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.min_transfer_amounts = Some(MinTransferAmounts { by_id: LookupMap::new(prefix) });
+    }
+
+    pub(crate) fn internal_min_transfer_amount(&self, token_id: &TokenId) -> Balance {
+        self.min_transfer_amounts.as_ref().and_then(|min| min.by_id.get(token_id)).unwrap_or(0)
+    }
+
+    /// Panics if `amount` is below minimum transfer amount of token
+    pub fn assert_min_transfer_amount(&self, token_id: &TokenId, amount: Balance) {
+        let min = self.internal_min_transfer_amount(token_id);
+        if amount < min {
+            MtError::BelowMinAmount { min, amount }.panic();
+        }
+    }
+}
+
+impl MultiTokenMinTransfer for MultiToken {
+    fn mt_set_min_transfer_amount(&mut self, token_id: TokenId, amount: Amount) {
+        self.assert_storage_call_deposit();
+        let creator_id = self.owner_by_id.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        unauthorized_assert(&creator_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let min = expect_extension(self.min_transfer_amounts.as_mut(), "Minimum transfer amount", Entity::Contract);
+        if amount.0 == 0 {
+            min.by_id.remove(&token_id);
+        } else {
+            min.by_id.insert(&token_id, &amount.0);
+        }
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_min_transfer_amount(&self, token_id: TokenId) -> U128 {
+        U128(self.internal_min_transfer_amount(&token_id))
+    }
+}
@@ -0,0 +1,25 @@
+/*! Protection from dust transfers
+
+Token creator may set minimum amount for transfers of the token, so receivers can't be
+flooded with millions of 1-unit transfers. Contract-initiated moves (escrow, staking) are not limited.
+ */
+
+mod dust_impl;
+
+pub use dust_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+
+pub trait MultiTokenMinTransfer {
+    /// Set minimum amount of token that can be transferred, 0 to remove the limit
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than creator of the token
+    fn mt_set_min_transfer_amount(&mut self, token_id: TokenId, amount: Amount);
+
+    /// Get minimum amount of token that can be transferred
+    fn mt_min_transfer_amount(&self, token_id: TokenId) -> U128;
+}
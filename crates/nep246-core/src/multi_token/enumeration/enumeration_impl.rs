@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use near_sdk::{json_types::U128, AccountId};
+
+use crate::multi_token::{core::MultiToken, token::{Token, TokenId}};
+
+impl MultiToken {
+    /// Token built from ownership and supply. Fields of extensions the contract doesn't use are `None`
+    pub fn enum_get_token(&self, owner_id: AccountId, token_id: TokenId) -> Token {
+        let metadata = self.token_metadata_by_id.as_ref().and_then(|by_id| by_id.get(&token_id));
+        let supply = self.total_supply.get(&token_id).unwrap_or(0);
+        let approvals = self.approvals_by_id.as_ref().map(|by_id| by_id.get(&token_id).unwrap_or_default());
+        let next_approval_id = self.next_approval_id_by_id.as_ref().and_then(|by_id| by_id.get(&token_id));
+        let kind = self.internal_token_kind(&token_id);
+
+        Token { token_id, owner_id, metadata, approvals, supply: U128(supply), kind, balances: HashMap::new(), next_approval_id }
+    }
+}
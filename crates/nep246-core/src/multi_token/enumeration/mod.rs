@@ -0,0 +1,6 @@
+//! State of enumeration extension. Views are in `nep246-enumeration` crate
+
+mod enumeration_impl;
+mod ordering;
+
+pub use ordering::*;
@@ -0,0 +1,87 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap};
+use near_sdk::{env, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::{Token, TokenId},
+};
+
+/// Bytes tokens are ordered by, compared lexicographically. Numbers should be big-endian
+pub type SortKey = Vec<u8>;
+
+/// Sort key extractor supplied by contract, see [MultiToken::internal_order_token]
+pub trait TokenSortKey {
+    fn sort_key(token: &Token) -> SortKey;
+}
+
+/// Order of mint, tokens minted in the same block are ordered by mint sequence
+pub struct ByMintTime;
+
+impl TokenSortKey for ByMintTime {
+    fn sort_key(token: &Token) -> SortKey {
+        [env::block_timestamp().to_be_bytes().to_vec(), ByNumericId::sort_key(token)].concat()
+    }
+}
+
+/// Numeric order of IDs, so "10" goes after "9". Non-numeric IDs go last
+pub struct ByNumericId;
+
+impl TokenSortKey for ByNumericId {
+    fn sort_key(token: &Token) -> SortKey {
+        token.token_id.parse::<u64>().unwrap_or(u64::MAX).to_be_bytes().to_vec()
+    }
+}
+
+/// Which key minted tokens are put into order index with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum TokenOrderKind {
+    /// See [ByMintTime]
+    MintTime,
+    /// See [ByNumericId]
+    NumericId,
+    /// Contract indexes tokens itself with [MultiToken::internal_order_token]
+    Custom,
+}
+
+/// Index of tokens in custom order, used by `mt_tokens` instead of order of IDs
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TokenOrder {
+    pub kind: TokenOrderKind,
+    pub index: TreeMap<(SortKey, TokenId), ()>,
+    pub key_by_id: LookupMap<TokenId, SortKey>,
+}
+
+impl MultiToken {
+    /// Enumerate tokens in order of `kind`. Tokens are indexed at mint and removed when burned out,
+    /// tokens minted before should be indexed by contract
+    pub fn enable_token_order<P: IntoStorageKey>(&mut self, prefix: P, kind: TokenOrderKind) {
+        let prefix = prefix.into_storage_key();
+        self.token_order = Some(TokenOrder {
+            kind,
+            index: TreeMap::new([prefix.clone(), "i".into()].concat()),
+            key_by_id: LookupMap::new([prefix, "k".into()].concat()),
+        });
+    }
+
+    /// Put token into order index with key given by `K`. Indexing the token again moves it to new key.
+    /// Called on mint for built-in kinds, contract with [TokenOrderKind::Custom] calls it itself
+    pub fn internal_order_token<K: TokenSortKey>(&mut self, token: &Token) {
+        let order = self.token_order.as_mut().expect("Token order is not enabled");
+        let key = K::sort_key(token);
+
+        if let Some(old_key) = order.key_by_id.insert(&token.token_id, &key) {
+            order.index.remove(&(old_key, token.token_id.clone()));
+        }
+        order.index.insert(&(key, token.token_id.clone()), &());
+    }
+
+    /// Index minted token if contract uses built-in order
+    pub(crate) fn internal_order_minted(&mut self, token: &Token) {
+        match self.token_order.as_ref().map(|order| order.kind) {
+            Some(TokenOrderKind::MintTime) => self.internal_order_token::<ByMintTime>(token),
+            Some(TokenOrderKind::NumericId) => self.internal_order_token::<ByNumericId>(token),
+            Some(TokenOrderKind::Custom) | None => {}
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{require, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity},
+};
+
+use super::{EvmMapping, MultiTokenErc1155Compat};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Erc1155Compat {
+    pub mapping_by_id: LookupMap<TokenId, EvmMapping>,
+    pub id_by_mapping: LookupMap<EvmMapping, TokenId>,
+}
+
+impl MultiToken {
+    /// Start storing EVM mappings of tokens
+    pub fn enable_erc1155_compat<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix: Vec<u8> = prefix.into_storage_key();
+
+        self.erc1155_compat = Some(Erc1155Compat {
+            mapping_by_id: LookupMap::new([prefix.clone(), "i".into()].concat()),
+            id_by_mapping: LookupMap::new([prefix, "m".into()].concat()),
+        });
+    }
+}
+
+impl MultiTokenErc1155Compat for MultiToken {
+    fn mt_set_evm_mapping(&mut self, token_id: TokenId, mapping: Option<EvmMapping>) {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+        require!(self.owner_by_id.get(&token_id).is_some(), "This token does not exist");
+
+        let compat = expect_extension(self.erc1155_compat.as_mut(), "ERC-1155 compatibility", Entity::Contract);
+
+        if let Some(old) = compat.mapping_by_id.remove(&token_id) {
+            compat.id_by_mapping.remove(&old);
+        }
+
+        if let Some(mapping) = mapping {
+            require!(mapping.is_valid(), "Invalid EVM mapping");
+            let mapping = mapping.normalized();
+            require!(compat.id_by_mapping.get(&mapping).is_none(), "EVM mapping is used by another token");
+
+            compat.id_by_mapping.insert(&mapping, &token_id);
+            compat.mapping_by_id.insert(&token_id, &mapping);
+        }
+    }
+
+    fn mt_evm_mapping(&self, token_id: TokenId) -> Option<EvmMapping> {
+        expect_extension(self.erc1155_compat.as_ref(), "ERC-1155 compatibility", Entity::Contract).mapping_by_id.get(&token_id)
+    }
+
+    fn mt_token_by_evm_mapping(&self, mapping: EvmMapping) -> Option<TokenId> {
+        expect_extension(self.erc1155_compat.as_ref(), "ERC-1155 compatibility", Entity::Contract).id_by_mapping.get(&mapping.normalized())
+    }
+}
@@ -0,0 +1,57 @@
+/*! Mapping between NEP-246 tokens and ERC-1155 tokens on Aurora, used by bridges
+
+ */
+
+mod erc1155_compat_impl;
+
+pub use erc1155_compat_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Location of the token in EVM
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EvmMapping {
+    /// Address of ERC-1155 contract, `0x`-prefixed hex
+    pub address: String,
+    /// ID of token in ERC-1155 contract, uint256 as base-10 string
+    pub token_index: String,
+}
+
+impl EvmMapping {
+    pub fn is_valid(&self) -> bool {
+        let address_valid = self.address.len() == 42
+            && self.address.starts_with("0x")
+            && self.address[2..].chars().all(|c| c.is_ascii_hexdigit());
+        // uint256 has at most 78 decimal digits
+        let index_valid = !self.token_index.is_empty()
+            && self.token_index.len() <= 78
+            && self.token_index.chars().all(|c| c.is_ascii_digit());
+
+        address_valid && index_valid
+    }
+
+    /// Addresses are case-insensitive, keep single representation in storage
+    pub(crate) fn normalized(self) -> Self {
+        Self { address: self.address.to_lowercase(), token_index: self.token_index }
+    }
+}
+
+/// ERC-1155 compatibility extension
+pub trait MultiTokenErc1155Compat {
+    /// Set or remove EVM mapping of token
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if mapping is invalid or already used by another token
+    fn mt_set_evm_mapping(&mut self, token_id: TokenId, mapping: Option<EvmMapping>);
+
+    /// Get EVM mapping of token
+    fn mt_evm_mapping(&self, token_id: TokenId) -> Option<EvmMapping>;
+
+    /// Get token mapped to given ERC-1155 token
+    fn mt_token_by_evm_mapping(&self, mapping: EvmMapping) -> Option<TokenId>;
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use near_sdk::{env, AccountId, Balance};
+
+use crate::multi_token::utils::Entity;
+
+/// Errors that multi token panics with
+#[derive(Debug, Clone, PartialEq)]
+pub enum MtError {
+    /// Owner tried to approve own account
+    ApproveSelf,
+    /// Owner tried to approve account of the contract
+    ApproveContract,
+    /// Token with given ID was never minted
+    TokenNotFound,
+    /// Account has no balance entry in the token
+    NotRegistered(AccountId),
+    /// Transfer of zero tokens
+    ZeroAmount,
+    /// Account tried to send more than it has
+    InsufficientBalance { balance: Balance, amount: Balance },
+    /// Approval ID passed by sender doesn't match the stored one
+    ApprovalIdMismatch { expected: u64, actual: u64 },
+    /// Vectors passed to batch method have different lengths
+    LengthMismatch,
+    /// Batch method was called without tokens
+    EmptyBatch,
+    /// Transfer of less than minimum amount set for the token
+    BelowMinAmount { min: Balance, amount: Balance },
+    /// Transfer of token before its lock after mint is over
+    TransferLocked { until: u64 },
+    /// Non-fungible token moved in amount other than 1
+    NonFungibleAmount { amount: Balance },
+    /// Supply of non-fungible token would exceed 1
+    NonFungibleSupply,
+    /// Account transferred token less than its cooldown ago
+    TransferCooldown { until: u64 },
+    /// Tokens sent to the account they are taken from
+    SenderIsReceiver,
+    /// Method of extension that contract or token doesn't use
+    ExtensionDisabled { extension: &'static str, entity: Entity },
+}
+
+impl Display for MtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MtError::ApproveSelf => write!(f, "Owner cannot approve own account"),
+            MtError::ApproveContract => write!(f, "Contract account cannot be approved"),
+            MtError::TokenNotFound => write!(f, "Token not found"),
+            MtError::NotRegistered(account_id) => write!(f, "The account {} is not registered", account_id),
+            MtError::ZeroAmount => write!(f, "Amount must be positive"),
+            MtError::InsufficientBalance { balance, amount } => {
+                write!(f, "The account doesn't have enough balance: {} < {}", balance, amount)
+            }
+            MtError::ApprovalIdMismatch { expected, actual } => {
+                write!(f, "The actual approval_id {} is different from given {}", actual, expected)
+            }
+            MtError::LengthMismatch => write!(f, "Length of token_ids, amounts and approval_ids must be equal"),
+            MtError::EmptyBatch => write!(f, "Batch must contain at least one token"),
+            MtError::BelowMinAmount { min, amount } => {
+                write!(f, "Amount {} is below minimum transfer amount {}", amount, min)
+            }
+            MtError::TransferLocked { until } => write!(f, "Token is locked for transfers until {}", until),
+            MtError::NonFungibleAmount { amount } => {
+                write!(f, "Non-fungible token moves only as a whole unit, got amount {}", amount)
+            }
+            MtError::NonFungibleSupply => write!(f, "Supply of non-fungible token cannot exceed 1"),
+            MtError::TransferCooldown { until } => write!(f, "Account cannot transfer token until {}", until),
+            MtError::SenderIsReceiver => write!(f, "Sender and receiver must differ"),
+            MtError::ExtensionDisabled { extension, entity } => write!(f, "{} is not supported by {}", extension, entity),
+        }
+    }
+}
+
+impl MtError {
+    /// Abort execution with this error
+    pub fn panic(&self) -> ! {
+        env::panic_str(&self.to_string())
+    }
+}
+
+/// Panic before touching any state if batch of `len` tokens is empty
+/// or any of vectors accompanying token IDs has different length
+pub fn assert_batch_lengths(len: usize, others: &[usize]) {
+    if len == 0 {
+        MtError::EmptyBatch.panic();
+    }
+    if others.iter().any(|other| *other != len) {
+        MtError::LengthMismatch.panic();
+    }
+}
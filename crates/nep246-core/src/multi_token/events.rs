@@ -0,0 +1,238 @@
+use near_sdk::AccountId;
+use serde::Serialize;
+
+use crate::event::NearEvent;
+
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtMint<'a> {
+    pub owner_id: &'a AccountId,
+    pub token_ids: &'a [&'a str],
+    pub amounts: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<&'a str>
+}
+
+impl MtMint<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtMint<'_>]) {
+        new_246_v1(Nep246EventKind::MtMint(data)).emit()
+    }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtTransfer<'a> {
+    pub old_owner_id: &'a AccountId,
+    pub new_owner_id: &'a AccountId,
+    pub token_ids: &'a [&'a str],
+    pub amounts: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<&'a AccountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<&'a str>
+}
+
+impl MtTransfer<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtTransfer<'_>]) {
+        new_246_v1(Nep246EventKind::MtTransfer(data)).emit()
+    }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtBurn<'a> {
+    pub owner_id: &'a AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<&'a AccountId>,
+    pub token_ids: &'a [&'a str],
+    pub amounts: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<&'a str>
+}
+
+impl MtBurn<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtBurn<'_>]) {
+        new_246_v1(Nep246EventKind::MtBurn(data)).emit()
+    }
+}
+
+/// Why tokens were returned to sender in `mt_resolve_transfer`
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevertReason {
+    /// `mt_on_transfer` failed, whole amount is returned
+    ReceiverPanicked,
+    /// Receiver asked to return unused tokens
+    RefundRequested,
+    /// Receiver asked to return more than was sent, refund is clamped to sent amount
+    ClampedAmount,
+    /// Receiver returned something that is not an amount, whole amount is returned
+    InvalidResponse,
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtTransferRevert<'a> {
+    pub old_owner_id: &'a AccountId,
+    pub new_owner_id: &'a AccountId,
+    pub token_ids: &'a [&'a str],
+    pub amounts: &'a [&'a str],
+    pub reason: RevertReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>
+}
+
+impl MtTransferRevert<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtTransferRevert<'_>]) {
+        new_246_v1(Nep246EventKind::MtTransferRevert(data)).emit()
+    }
+}
+
+/// Why `msg` of `*_transfer_call` was rejected before calling receiver
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MsgRejectReason {
+    /// `msg` is longer than configured maximum
+    TooLong,
+    /// `msg` is not valid JSON
+    InvalidJson,
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtTransferCallRejected<'a> {
+    pub sender_id: &'a AccountId,
+    pub receiver_id: &'a AccountId,
+    pub token_ids: &'a [&'a str],
+    pub reason: MsgRejectReason,
+}
+
+impl MtTransferCallRejected<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtTransferCallRejected<'_>]) {
+        new_246_v1(Nep246EventKind::MtTransferCallRejected(data)).emit()
+    }
+}
+
+/// Outcome of `*_transfer_call` after receiver was called, amounts are positionally matched to `token_ids`
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtTransferCallResult<'a> {
+    pub sender_id: &'a AccountId,
+    pub receiver_id: &'a AccountId,
+    pub token_ids: &'a [&'a str],
+    /// Amounts sent to receiver
+    pub requested: &'a [&'a str],
+    /// Amounts returned to owners
+    pub refunded: &'a [&'a str],
+    /// Amounts that stayed with receiver
+    pub kept: &'a [&'a str],
+}
+
+impl MtTransferCallResult<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtTransferCallResult<'_>]) {
+        new_246_v1(Nep246EventKind::MtTransferCallResult(data)).emit()
+    }
+}
+
+/// Balance of account in token is locked by arbiter until `expires_at`
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtDisputeLock<'a> {
+    pub owner_id: &'a AccountId,
+    pub token_id: &'a str,
+    /// Block timestamp in nanoseconds
+    pub expires_at: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'a str>,
+}
+
+impl MtDisputeLock<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtDisputeLock<'_>]) {
+        new_246_v1(Nep246EventKind::MtDisputeLock(data)).emit()
+    }
+}
+
+/// Lock of balance is released by arbiter before expiry
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct MtDisputeRelease<'a> {
+    pub owner_id: &'a AccountId,
+    pub token_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'a str>,
+}
+
+impl MtDisputeRelease<'_> {
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    pub fn emit_many(data: &[MtDisputeRelease<'_>]) {
+        new_246_v1(Nep246EventKind::MtDisputeRelease(data)).emit()
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct Nep246Event<'a> {
+    version:  &'static str,
+    #[serde(flatten)]
+    event_kind: Nep246EventKind<'a>
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+enum Nep246EventKind<'a> {
+    MtMint(&'a [MtMint<'a>]),
+    MtTransfer(&'a [MtTransfer<'a>]),
+    MtBurn(&'a [MtBurn<'a>]),
+    MtTransferRevert(&'a [MtTransferRevert<'a>]),
+    MtTransferCallRejected(&'a [MtTransferCallRejected<'a>]),
+    MtTransferCallResult(&'a [MtTransferCallResult<'a>]),
+    MtDisputeLock(&'a [MtDisputeLock<'a>]),
+    MtDisputeRelease(&'a [MtDisputeRelease<'a>]),
+}
+
+fn new_246<'a>(version: &'static str, event_kind: Nep246EventKind<'a>) -> NearEvent<'a> {
+    NearEvent::Nep246(Nep246Event { version, event_kind })
+}
+
+fn new_246_v1(event_kind: Nep246EventKind) -> NearEvent {
+    new_246("1.0.0", event_kind)
+}
@@ -0,0 +1,116 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::TreeMap;
+use near_sdk::json_types::U64;
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    metadata::TokenMetadata,
+    token::{Token, TokenId},
+    utils::{expect_extension, Entity},
+};
+
+use super::MultiTokenExpiring;
+
+/// Tokens with expiry, ordered by `expires_at` in milliseconds
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ExpiringTokens {
+    pub queue: TreeMap<(u64, TokenId), ()>,
+}
+
+impl MultiToken {
+    /// Index expiry of tokens minted after this call
+    pub fn enable_expiring_tokens<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.expiring_tokens = Some(ExpiringTokens { queue: TreeMap::new(prefix) });
+    }
+
+    /// Add token to expiry index if extension is used and metadata has valid `expires_at`
+    pub(crate) fn internal_index_expiry(&mut self, token_id: &TokenId, metadata: Option<&TokenMetadata>) {
+        let expires_at = metadata.and_then(|metadata| metadata.expires_at.as_ref()).and_then(|at| at.parse::<u64>().ok());
+        if let (Some(expiring), Some(expires_at)) = (self.expiring_tokens.as_mut(), expires_at) {
+            expiring.queue.insert(&(expires_at, token_id.clone()), &());
+        }
+    }
+
+    fn internal_balance_or_zero(&self, token_id: &TokenId, account_id: &AccountId) -> u128 {
+        self.balances_per_token.get(token_id).and_then(|balances| balances.get(account_id)).unwrap_or(0)
+    }
+
+    /// Burn balances of expired tokens, at most `limit` balances. Returns number of burned balances
+    pub(crate) fn internal_burn_expired(&mut self, limit: u64) -> u64 {
+        let now = env::block_timestamp() / 1_000_000;
+        let escrow_id = env::current_account_id();
+
+        let mut burned = 0;
+        // Tokens with locked balances stay in the index, so walk past them instead of taking min each time
+        let mut cursor: Option<(u64, TokenId)> = None;
+        loop {
+            let expiring = expect_extension(self.expiring_tokens.as_ref(), "Expiring tokens", Entity::Contract);
+            let next = match &cursor {
+                Some(key) => expiring.queue.higher(key),
+                None => expiring.queue.min(),
+            };
+            let key = match next {
+                Some(key) if key.0 <= now => key,
+                _ => break,
+            };
+            let token_id = &key.1;
+            let holders = self.holders_per_token.get(token_id).map(|holders| holders.to_vec()).unwrap_or_default();
+
+            let mut done = true;
+            for account_id in holders {
+                if burned == limit {
+                    return burned;
+                }
+                // Contract holds balances of stakes, swaps and channels which keep their own records
+                if account_id == escrow_id {
+                    continue;
+                }
+                if self.internal_is_dispute_locked(&account_id, token_id) {
+                    done = false;
+                    continue;
+                }
+                let balance = self.internal_balance_or_zero(token_id, &account_id);
+                if balance > 0 {
+                    self.internal_burn(&account_id, token_id, balance, Some("expired".to_string()));
+                    burned += 1;
+                }
+                self.internal_remove_holder(token_id, &account_id);
+            }
+
+            if done {
+                self.expiring_tokens.as_mut().unwrap().queue.remove(&key);
+            }
+            cursor = Some(key);
+        }
+
+        burned
+    }
+}
+
+impl MultiTokenExpiring for MultiToken {
+    fn mt_expired_tokens_for_owner(&self, account_id: AccountId, now: U64, from_index: Option<u64>, limit: u64) -> Vec<Token> {
+        let expiring = expect_extension(self.expiring_tokens.as_ref(), "Expiring tokens", Entity::Contract);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        expiring
+            .queue
+            .iter()
+            .take_while(|((expires_at, _), _)| *expires_at <= now.0)
+            .filter(|((_, token_id), _)| self.internal_balance_or_zero(token_id, &account_id) > 0)
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit as usize)
+            .map(|((_, token_id), _)| {
+                let owner_id = self.owner_by_id.get(&token_id).unwrap();
+                self.enum_get_token(owner_id, token_id)
+            })
+            .collect()
+    }
+
+    fn mt_burn_expired(&mut self, limit: u64) -> u64 {
+        require!(limit != 0, "Limit cannot be 0");
+
+        self.internal_burn_expired(limit)
+    }
+}
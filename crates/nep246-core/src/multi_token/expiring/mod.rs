@@ -0,0 +1,34 @@
+/*! Tokens that expire, e.g. tickets and passes
+
+Tokens minted with `expires_at` in metadata are indexed by expiry, so holders can find their
+expired tokens and anyone can sweep expired balances without scanning all tokens.
+ */
+
+mod expiring_impl;
+
+pub use expiring_impl::*;
+
+use crate::multi_token::token::Token;
+use near_sdk::json_types::U64;
+use near_sdk::AccountId;
+
+pub trait MultiTokenExpiring {
+    /// Get tokens expired at `now` in which account has non-zero balance, earliest expiry first
+    ///
+    /// # Arguments:
+    /// * `account_id` - Holder
+    /// * `now` - Unix epoch in milliseconds, same unit as `expires_at` of metadata
+    /// * `from_index` - Index to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of tokens to return
+    ///
+    /// returns: List of [Token]s
+    fn mt_expired_tokens_for_owner(&self, account_id: AccountId, now: U64, from_index: Option<u64>, limit: u64) -> Vec<Token>;
+
+    /// Burn up to `limit` balances in tokens which expired by current block, earliest expiry first.
+    /// Token leaves the index once all its balances are burned. Balances locked by dispute and balances
+    /// held by the contract itself on behalf of stakes, swaps or channels are skipped
+    ///
+    /// # Returns
+    /// Number of burned balances
+    fn mt_burn_expired(&mut self, limit: u64) -> u64;
+}
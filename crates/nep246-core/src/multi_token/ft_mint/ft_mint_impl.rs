@@ -0,0 +1,91 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, serde_json, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::{assert_batch_lengths, MtError},
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{FtMintOrder, MultiTokenFtMint};
+
+/// Prices of tokens in FT accepted as payment for mint
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct FtSales {
+    pub payment_token_id: AccountId,
+    pub price_by_id: LookupMap<TokenId, Balance>,
+}
+
+impl MultiToken {
+    /// Accept payments in `payment_token_id` FT for mints of priced tokens
+    pub fn enable_ft_mint<P: IntoStorageKey>(&mut self, prefix: P, payment_token_id: AccountId) {
+        self.ft_sales = Some(FtSales { payment_token_id, price_by_id: LookupMap::new(prefix) });
+    }
+
+    /// Handle `ft_on_transfer`: mint order from `msg` to `sender_id`.
+    /// Returns unused part of `amount` to be refunded by FT contract
+    pub fn internal_ft_mint(&mut self, sender_id: AccountId, amount: U128, msg: String) -> U128 {
+        let sales = expect_extension(self.ft_sales.as_ref(), "FT mint", Entity::Contract);
+        require!(env::predecessor_account_id() == sales.payment_token_id, "Payment token is not accepted");
+
+        let order: FtMintOrder = serde_json::from_str(&msg).unwrap_or_else(|_| env::panic_str("Invalid mint order"));
+        assert_batch_lengths(order.token_ids.len(), &[order.amounts.len()]);
+
+        let cost = order
+            .token_ids
+            .iter()
+            .zip(order.amounts.iter())
+            .try_fold(0u128, |cost, (token_id, units)| {
+                let price = sales.price_by_id.get(token_id)?;
+                cost.checked_add(price.checked_mul(units.0)?)
+            })
+            .unwrap_or_else(|| env::panic_str("Token is not for sale or cost overflows"));
+        require!(cost <= amount.0, "Not enough payment for mint order");
+
+        for (token_id, units) in order.token_ids.iter().zip(order.amounts.iter()) {
+            if units.0 == 0 {
+                MtError::ZeroAmount.panic();
+            }
+            let registered = self
+                .balances_per_token
+                .get(token_id)
+                .unwrap_or_else(|| MtError::TokenNotFound.panic())
+                .contains_key(&sender_id);
+            if !registered {
+                self.internal_register_account(token_id, &sender_id);
+            }
+            self.internal_deposit(token_id, &sender_id, units.0);
+            self.emit_mint(&sender_id, token_id, &units.0, Some("ft_mint".to_string()));
+        }
+
+        U128(amount.0 - cost)
+    }
+}
+
+impl MultiTokenFtMint for MultiToken {
+    fn mt_set_ft_price(&mut self, token_id: TokenId, price: Option<Amount>) {
+        self.assert_storage_call_deposit();
+        let creator = self.owner_by_id.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        require!(env::predecessor_account_id() == creator, "Only creator can set price");
+
+        let sales = expect_extension(self.ft_sales.as_mut(), "FT mint", Entity::Contract);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        match price {
+            Some(price) => sales.price_by_id.insert(&token_id, &price.0),
+            None => sales.price_by_id.remove(&token_id),
+        };
+        tracker.stop();
+        tracker.refund(creator);
+    }
+
+    fn mt_ft_price(&self, token_id: TokenId) -> Option<U128> {
+        let sales = expect_extension(self.ft_sales.as_ref(), "FT mint", Entity::Contract);
+        sales.price_by_id.get(&token_id).map(U128)
+    }
+}
@@ -0,0 +1,38 @@
+/*! Minting paid with fungible tokens
+
+Creators price units of their tokens in a single FT chosen by the contract (e.g. USDC).
+Buyer calls `ft_transfer_call` on that FT with [FtMintOrder] as `msg`, the contract mints
+ordered amounts to the buyer and returns unused part of payment, which FT contract refunds.
+Invalid orders panic, so the whole payment is refunded.
+
+Storage of buyer registrations is paid by the contract. Proceeds stay on the contract account.
+ */
+
+mod ft_mint_impl;
+
+pub use ft_mint_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Tokens to mint, passed as `msg` of `ft_transfer_call`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtMintOrder {
+    pub token_ids: Vec<TokenId>,
+    pub amounts: Vec<Amount>,
+}
+
+pub trait MultiTokenFtMint {
+    /// Set or remove price of one unit of token in payment FT. Token without price can't be minted for FT
+    ///
+    /// # Requirements:
+    /// * Caller must be creator of the token
+    /// * Caller must attach a deposit to cover storage, unused part is refunded
+    fn mt_set_ft_price(&mut self, token_id: TokenId, price: Option<Amount>);
+
+    /// Get price of one unit of token in payment FT
+    fn mt_ft_price(&self, token_id: TokenId) -> Option<U128>;
+}
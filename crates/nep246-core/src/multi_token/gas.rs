@@ -0,0 +1,125 @@
+//! Gas constants used by cross-contract calls of the library and helpers to budget them.
+//!
+//! All amounts are public, so contracts composing promises on top of `mt_*_call` methods
+//! can compute how much gas to attach instead of guessing. Amounts are measured on
+//! `near-sdk` 4.0 with some headroom, and they don't depend on the size of a batch
+//! unless stated otherwise.
+
+use near_sdk::Gas;
+
+const TGAS: u64 = 1_000_000_000_000;
+
+/// Gas given to `mt_resolve_transfer`. Resolver reads one promise result and at most
+/// moves refunded balances back, which is a couple of storage reads and writes per token
+pub const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(5 * TGAS);
+
+/// Gas kept by `mt_transfer_call` and `mt_batch_transfer_call` for themselves: the transfer,
+/// scheduling of both promises and the resolver. Everything above is forwarded to `mt_on_transfer`
+pub const GAS_FOR_MT_TRANSFER_CALL: Gas = Gas(25 * TGAS + GAS_FOR_RESOLVE_TRANSFER.0);
+
+/// Estimated cost of moving a single balance inside of a batch: two balance writes,
+/// approval bookkeeping and one more entry of the event
+pub const GAS_PER_BATCH_ELEMENT: Gas = Gas(TGAS);
+
+/// Extra gas `mt_resolve_transfer` needs for every token of a batch after the first one:
+/// reading its kept amount and possibly moving the refund back
+pub const GAS_PER_RESOLVE_ELEMENT: Gas = Gas(2 * TGAS);
+
+/// Least gas `mt_on_transfer` of receiver gets for every token of `mt_batch_transfer_call`.
+/// Everything left after reserved gas is forwarded too, so this is only a floor
+pub const GAS_FOR_ON_TRANSFER_PER_TOKEN: Gas = Gas(2 * TGAS);
+
+/// Most tokens `mt_batch_transfer_call` accepts, so that reserved gas and the floor of
+/// receiver gas of a full batch fit into 300 TGas of a single call
+pub const MAX_BATCH_TRANSFER_CALL_LEN: u64 = 50;
+
+/// Gas kept free before revoking each token in `mt_revoke`: approval record rewrite,
+/// grantee index update and the rest of the call
+pub const GAS_PER_REVOKE: Gas = Gas(5 * TGAS);
+
+/// Gas given to `mt_resolve_approve` for each `mt_on_approve` call when strict approvals are enabled
+pub const GAS_FOR_RESOLVE_APPROVE: Gas = Gas(10 * TGAS);
+
+/// Gas for `get_proposal` view call to the DAO
+pub const GAS_FOR_DAO_VIEW: Gas = Gas(10 * TGAS);
+
+/// Gas for minting callback after DAO proposal is fetched
+pub const GAS_FOR_DAO_MINT: Gas = Gas(20 * TGAS);
+
+/// Gas for the call to the compliance oracle
+pub const GAS_FOR_COMPLIANCE_CHECK: Gas = Gas(10 * TGAS);
+
+/// Gas for callback which finishes or cancels the transfer once compliance oracle answered
+pub const GAS_FOR_COMPLIANCE_RESOLVE: Gas = Gas(15 * TGAS);
+
+/// Gas for `ft_transfer` or `mt_batch_transfer` of other contracts while rescuing stuck tokens
+pub const GAS_FOR_RESCUE: Gas = Gas(10 * TGAS);
+
+/// Gas kept by `upgrade` itself for deploying the code
+pub const GAS_FOR_UPGRADE_DEPLOY: Gas = Gas(20 * TGAS);
+
+/// Gas for `create_task` and `remove_task` of Croncat manager
+pub const GAS_FOR_CRONCAT_MANAGER: Gas = Gas(20 * TGAS);
+
+/// Gas Croncat agents attach to scheduled `mt_croncat_run`. Keeper tasks are bounded by
+/// their `limit`, so this is enough for a few dozen items of any task
+pub const GAS_FOR_SCHEDULED_RUN: Gas = Gas(100 * TGAS);
+
+/// Gas for `mt_burn_from_self` called by consumer on the MT contract
+pub const GAS_FOR_BURN_FROM_SELF: Gas = Gas(15 * TGAS);
+
+/// Gas for `mt_resolve_consume` of consumer, including `mt_revert_consume` if burning failed
+pub const GAS_FOR_RESOLVE_CONSUME: Gas = Gas(15 * TGAS);
+
+/// Minimal prepaid gas `mt_transfer_call` and `mt_batch_transfer_call` accept
+pub const fn min_gas_for_transfer_call() -> Gas {
+    Gas(GAS_FOR_MT_TRANSFER_CALL.0 + GAS_FOR_RESOLVE_TRANSFER.0)
+}
+
+/// Gas forwarded to `mt_on_transfer` of receiver when `prepaid` gas is attached to transfer call
+pub fn gas_for_receiver(prepaid: Gas) -> Gas {
+    prepaid - GAS_FOR_MT_TRANSFER_CALL
+}
+
+/// Gas given to `mt_resolve_transfer` of a batch of `count` tokens
+pub fn gas_for_resolve_batch(count: u64) -> Gas {
+    GAS_FOR_RESOLVE_TRANSFER + GAS_PER_RESOLVE_ELEMENT * count.saturating_sub(1)
+}
+
+/// Gas kept by `mt_batch_transfer_call` of `count` tokens for itself, resolver included.
+/// Same as [GAS_FOR_MT_TRANSFER_CALL] for a single token, every further token adds
+/// [GAS_PER_BATCH_ELEMENT] and [GAS_PER_RESOLVE_ELEMENT]
+pub fn gas_reserved_for_batch_transfer_call(count: u64) -> Gas {
+    GAS_FOR_MT_TRANSFER_CALL + (GAS_PER_BATCH_ELEMENT + GAS_PER_RESOLVE_ELEMENT) * count.saturating_sub(1)
+}
+
+/// Minimal prepaid gas `mt_batch_transfer_call` of `count` tokens accepts
+pub fn min_gas_for_batch_transfer_call(count: u64) -> Gas {
+    gas_reserved_for_batch_transfer_call(count) + GAS_FOR_ON_TRANSFER_PER_TOKEN * count
+}
+
+/// Gas forwarded to `mt_on_transfer` of receiver of `count` tokens when `prepaid` gas is attached
+pub fn gas_for_batch_receiver(prepaid: Gas, count: u64) -> Gas {
+    prepaid - gas_reserved_for_batch_transfer_call(count)
+}
+
+/// Prepaid gas to attach to `mt_batch_transfer_call` of `count` tokens so that
+/// receiver gets at least `receiver_gas` for its `mt_on_transfer`
+pub fn gas_for_batch_transfer_call(count: u64, receiver_gas: Gas) -> Gas {
+    let floor = GAS_FOR_ON_TRANSFER_PER_TOKEN * count;
+    gas_reserved_for_batch_transfer_call(count) + if receiver_gas > floor { receiver_gas } else { floor }
+}
+
+/// Gas kept by approval methods for themselves when notifying `calls` grantees
+pub fn gas_reserved_for_approve(calls: u64, strict: bool) -> Gas {
+    if strict {
+        GAS_FOR_MT_TRANSFER_CALL + GAS_FOR_RESOLVE_APPROVE * calls
+    } else {
+        GAS_FOR_MT_TRANSFER_CALL
+    }
+}
+
+/// Splits what is left of `prepaid` after `reserved` evenly between `count` elements of a batch
+pub fn gas_per_batch_element(prepaid: Gas, reserved: Gas, count: u64) -> Gas {
+    (prepaid - reserved) / count
+}
@@ -0,0 +1,69 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{core::MultiToken, token::TokenId, utils::expect_extension, utils::Entity};
+
+use super::{MultiTokenHistory, TransferRecord};
+
+/// Bounded log of transfers for every token
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TransferHistory {
+    /// Maximum number of records kept per token
+    pub capacity: u64,
+    /// Records of each token, oldest first
+    pub records_by_id: LookupMap<TokenId, Vec<TransferRecord>>,
+}
+
+impl MultiToken {
+    /// Start keeping last `capacity` transfers of every token
+    pub fn enable_transfer_history<P: IntoStorageKey>(&mut self, prefix: P, capacity: u64) {
+        require!(capacity > 0, "Capacity cannot be 0");
+
+        self.transfer_history = Some(TransferHistory { capacity, records_by_id: LookupMap::new(prefix) });
+    }
+
+    /// Remember transfer if history, portfolio or activity extension is used. Drops oldest record when buffer is full
+    pub fn internal_record_transfer(
+        &mut self,
+        old_owner_id: &AccountId,
+        new_owner_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+    ) {
+        self.internal_touch_portfolio(old_owner_id, token_id);
+        self.internal_touch_portfolio(new_owner_id, token_id);
+        self.internal_activity_transferred(token_id);
+
+        if let Some(history) = self.transfer_history.as_mut() {
+            let mut records = history.records_by_id.get(token_id).unwrap_or_default();
+            if records.len() as u64 >= history.capacity {
+                records.drain(..=(records.len() - history.capacity as usize));
+            }
+            records.push(TransferRecord {
+                old_owner_id: old_owner_id.clone(),
+                new_owner_id: new_owner_id.clone(),
+                amount: amount.into(),
+                timestamp: env::block_timestamp(),
+            });
+            history.records_by_id.insert(token_id, &records);
+        }
+    }
+}
+
+impl MultiTokenHistory for MultiToken {
+    fn mt_recent_transfers(&self, token_id: TokenId, limit: u64) -> Vec<TransferRecord> {
+        let history = expect_extension(self.transfer_history.as_ref(), "Transfer history", Entity::Contract);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        history
+            .records_by_id
+            .get(&token_id)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .take(limit as usize)
+            .collect()
+    }
+}
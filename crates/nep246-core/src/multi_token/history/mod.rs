@@ -0,0 +1,32 @@
+mod history_impl;
+
+pub use history_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Single transfer stored in on-chain history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TransferRecord {
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub amount: U128,
+    /// Block timestamp of transfer, in nanoseconds
+    pub timestamp: u64,
+}
+
+/// Optional extension that keeps last N transfers of every token on-chain
+pub trait MultiTokenHistory {
+    /// Get most recent transfers of token, newest first
+    ///
+    /// # Arguments:
+    /// * `token_id` - ID of token
+    /// * `limit` - The maximum number of transfers to return
+    ///
+    /// returns: List of [TransferRecord]s, empty if token was never transferred
+    fn mt_recent_transfers(&self, token_id: TokenId, limit: u64) -> Vec<TransferRecord>;
+}
@@ -0,0 +1,83 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{HolderCap, MultiTokenHolderCap};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HolderCaps {
+    pub by_id: LookupMap<TokenId, HolderCap>,
+}
+
+impl MultiToken {
+    /// Allow token creators to limit holders of their tokens
+    pub fn enable_holder_caps<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.holder_caps = Some(HolderCaps { by_id: LookupMap::new(prefix) });
+    }
+
+    fn internal_holder_cap(&self, token_id: &TokenId) -> Option<HolderCap> {
+        self.holder_caps.as_ref().and_then(|caps| caps.by_id.get(token_id))
+    }
+
+    /// Whether `account_id` is allowed to hold `new_balance` of token
+    pub(crate) fn internal_fits_wallet_cap(&self, token_id: &TokenId, account_id: &AccountId, new_balance: Balance) -> bool {
+        if account_id == &env::current_account_id() {
+            return true;
+        }
+        self.internal_holder_cap(token_id)
+            .and_then(|cap| cap.max_per_wallet)
+            .map_or(true, |max| new_balance <= max.0)
+    }
+
+    /// Panics if `account_id` would hold more than allowed with `new_balance`
+    pub(crate) fn assert_wallet_cap(&self, token_id: &TokenId, account_id: &AccountId, new_balance: Balance) {
+        require!(
+            self.internal_fits_wallet_cap(token_id, account_id, new_balance),
+            format!("Account {} can't hold more of token", account_id)
+        );
+    }
+
+    /// Whether `account_id` can become one more holder of token
+    pub(crate) fn internal_fits_holders_cap(&self, token_id: &TokenId, account_id: &AccountId) -> bool {
+        if account_id == &env::current_account_id() {
+            return true;
+        }
+        self.internal_holder_cap(token_id).and_then(|cap| cap.max_holders).map_or(true, |max| {
+            self.holders_per_token.get(token_id).map_or(0, |holders| holders.len()) < max
+        })
+    }
+
+    /// Panics if registering `account_id` would exceed maximum number of holders
+    pub(crate) fn assert_holders_cap(&self, token_id: &TokenId, account_id: &AccountId) {
+        require!(self.internal_fits_holders_cap(token_id, account_id), "Token reached maximum number of holders");
+    }
+}
+
+impl MultiTokenHolderCap for MultiToken {
+    fn mt_set_holder_cap(&mut self, token_id: TokenId, cap: Option<HolderCap>) {
+        self.assert_storage_call_deposit();
+        let creator_id = self.owner_by_id.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        unauthorized_assert(&creator_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let caps = expect_extension(self.holder_caps.as_mut(), "Holder cap", Entity::Contract);
+        match cap {
+            Some(cap) => caps.by_id.insert(&token_id, &cap),
+            None => caps.by_id.remove(&token_id),
+        };
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+    }
+
+    fn mt_holder_cap(&self, token_id: TokenId) -> Option<HolderCap> {
+        self.internal_holder_cap(&token_id)
+    }
+}
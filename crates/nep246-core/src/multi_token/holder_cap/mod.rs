@@ -0,0 +1,38 @@
+/*! Per-token limits of holders
+
+Token creator may limit how much of the token single account can hold and how many accounts
+can be registered in the token. Limits are checked whenever balance grows or account is registered,
+so they apply to mints and transfers alike. Token owner which sends all of its balance away keeps its record,
+but frees its holder slot until it receives the token again. Contract account itself is not limited, so escrows keep working.
+ */
+
+mod holder_cap_impl;
+
+pub use holder_cap_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct HolderCap {
+    /// Maximum balance of single account
+    pub max_per_wallet: Option<U128>,
+    /// Maximum number of accounts registered in token
+    pub max_holders: Option<u64>,
+}
+
+pub trait MultiTokenHolderCap {
+    /// Set or remove limits of holders. Accounts already above the limits keep their balances,
+    /// but can't receive more
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than creator of the token
+    fn mt_set_holder_cap(&mut self, token_id: TokenId, cap: Option<HolderCap>);
+
+    /// Get limits of holders of token
+    fn mt_holder_cap(&self, token_id: TokenId) -> Option<HolderCap>;
+}
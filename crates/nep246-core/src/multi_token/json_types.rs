@@ -0,0 +1,86 @@
+//! Amounts of tokens as they are passed in JSON arguments.
+//!
+//! [Amount] is serialized as a string, just like [U128], but accepts both `"100"` and `100`
+//! when deserialized: several client libraries and early drafts of the standard send plain numbers.
+//! Numbers are only exact up to `2^53` in most JSON implementations and `serde_json` reads
+//! them as `u64` at most, so larger amounts still have to be passed as strings.
+
+use std::fmt;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::de::{self, Deserializer, Unexpected, Visitor};
+use near_sdk::serde::{Deserialize, Serialize, Serializer};
+use near_sdk::Balance;
+
+/// Amount of token in arguments of public methods, same as [U128] on the wire
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, BorshDeserialize, BorshSerialize)]
+pub struct Amount(pub Balance);
+
+impl From<Balance> for Amount {
+    fn from(amount: Balance) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<U128> for Amount {
+    fn from(amount: U128) -> Self {
+        Self(amount.0)
+    }
+}
+
+impl From<Amount> for U128 {
+    fn from(amount: Amount) -> Self {
+        U128(amount.0)
+    }
+}
+
+impl From<Amount> for Balance {
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+struct AmountVisitor;
+
+impl<'de> Visitor<'de> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-negative integer or a string with one")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Amount, E> {
+        value.parse().map(Amount).map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Amount, E> {
+        Ok(Amount(value.into()))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Amount, E> {
+        Ok(Amount(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Amount, E> {
+        u64::try_from(value)
+            .map(|value| Amount(value.into()))
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Amount, E> {
+        Err(E::custom(format!("amount {} is not an integer, pass amounts above 2^64 as strings", value)))
+    }
+}
@@ -0,0 +1,223 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, UnorderedMap};
+use near_sdk::json_types::{U128, U64};
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey, Promise};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{KeeperTask, KeeperTaskConfig, KeeperTaskStatus, MultiTokenKeeper};
+
+/// Registered tasks and rewards of keepers
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Keeper {
+    pub tasks: UnorderedMap<KeeperTask, KeeperTaskConfig>,
+    /// Block timestamp of last run which processed anything
+    pub last_run_by_task: LookupMap<KeeperTask, u64>,
+    /// Last token scanned by tasks walking over all tokens
+    pub cursor_by_task: LookupMap<KeeperTask, TokenId>,
+    pub rewards_by_keeper: LookupMap<AccountId, Balance>,
+    pub reward_pool: Balance,
+}
+
+impl MultiToken {
+    /// Allow keepers to run maintenance tasks through `mt_keeper_execute`
+    pub fn enable_keeper<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix = prefix.into_storage_key();
+        self.keeper = Some(Keeper {
+            tasks: UnorderedMap::new([prefix.clone(), "t".into()].concat()),
+            last_run_by_task: LookupMap::new([prefix.clone(), "l".into()].concat()),
+            cursor_by_task: LookupMap::new([prefix.clone(), "c".into()].concat()),
+            rewards_by_keeper: LookupMap::new([prefix, "r".into()].concat()),
+            reward_pool: 0,
+        });
+    }
+
+    /// Scan at most `limit` tokens starting after cursor of task, wrapping around after the last one.
+    /// Returns sum of what `visit` returned for scanned tokens
+    fn internal_keeper_scan<F>(&mut self, task: KeeperTask, limit: u64, mut visit: F) -> u64
+    where
+        F: FnMut(&mut Self, &TokenId) -> u64,
+    {
+        let mut cursor = self.keeper.as_ref().unwrap().cursor_by_task.get(&task);
+        let mut processed = 0;
+        for _ in 0..limit {
+            let next = match &cursor {
+                Some(token_id) => self.owner_by_id.higher(token_id),
+                None => self.owner_by_id.min(),
+            };
+            let token_id = match next {
+                Some(token_id) => token_id,
+                None => {
+                    cursor = None;
+                    break;
+                }
+            };
+            processed += visit(self, &token_id);
+            cursor = Some(token_id);
+        }
+
+        let keeper = self.keeper.as_mut().unwrap();
+        match cursor {
+            Some(token_id) => keeper.cursor_by_task.insert(&task, &token_id),
+            None => keeper.cursor_by_task.remove(&task),
+        };
+        processed
+    }
+
+    /// Remove zero balance records of token, except of accounts locked by disputes
+    fn internal_collect_empty_records(&mut self, token_id: &TokenId) -> u64 {
+        let mut balances = match self.balances_per_token.get(token_id) {
+            Some(balances) => balances,
+            None => return 0,
+        };
+        let holders = self.holders_per_token.get(token_id).map(|holders| holders.to_vec()).unwrap_or_default();
+
+        let mut removed = 0;
+        for account_id in holders {
+            if balances.get(&account_id) != Some(0) || self.internal_is_dispute_locked(&account_id, token_id) {
+                continue;
+            }
+            let mut tracker = StorageTracker::default();
+            tracker.start();
+            balances.remove(&account_id);
+            self.internal_remove_holder(token_id, &account_id);
+            tracker.stop();
+            // Account could pay for the record from its NEP-145 storage balance, give it back
+            self.internal_storage_release(&account_id, tracker.bytes_released);
+            removed += 1;
+        }
+        removed
+    }
+
+    /// Drop holder entries of token whose balance record doesn't exist anymore
+    fn internal_repair_holder_index(&mut self, token_id: &TokenId) -> u64 {
+        let balances = match self.balances_per_token.get(token_id) {
+            Some(balances) => balances,
+            None => return 0,
+        };
+        let holders = self.holders_per_token.get(token_id).map(|holders| holders.to_vec()).unwrap_or_default();
+
+        let mut removed = 0;
+        for account_id in holders.iter().filter(|account_id| !balances.contains_key(account_id)) {
+            self.internal_remove_holder(token_id, account_id);
+            removed += 1;
+        }
+        removed
+    }
+
+    pub(crate) fn internal_run_keeper_task(&mut self, task: KeeperTask, limit: u64) -> u64 {
+        match task {
+            KeeperTask::ExpiredApprovals => self.internal_sweep_expired_approvals(limit),
+            KeeperTask::ExpiredTokens => self.internal_burn_expired(limit),
+            KeeperTask::EmptyRecords => {
+                self.internal_keeper_scan(task, limit, |token, token_id| token.internal_collect_empty_records(token_id))
+            }
+            KeeperTask::HolderIndex => {
+                self.internal_keeper_scan(task, limit, |token, token_id| token.internal_repair_holder_index(token_id))
+            }
+        }
+    }
+}
+
+impl MultiTokenKeeper for MultiToken {
+    fn mt_keeper_execute(&mut self, task: KeeperTask, limit: u64) -> u64 {
+        require!(limit != 0, "Limit cannot be 0");
+
+        let keeper = expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract);
+        let config = keeper.tasks.get(&task).unwrap_or_else(|| env::panic_str("Task is not registered"));
+        let now = env::block_timestamp();
+        if let Some(last_run) = keeper.last_run_by_task.get(&task) {
+            require!(now >= last_run.saturating_add(config.cooldown.0), "Task is cooling down");
+        }
+
+        let processed = self.internal_run_keeper_task(task, limit);
+        if processed == 0 {
+            return 0;
+        }
+
+        let keeper = self.keeper.as_mut().unwrap();
+        keeper.last_run_by_task.insert(&task, &now);
+
+        let reward = config.reward_per_item.0.saturating_mul(Balance::from(processed)).min(keeper.reward_pool);
+        if reward > 0 {
+            let keeper_id = env::predecessor_account_id();
+            keeper.reward_pool -= reward;
+            let accrued = keeper.rewards_by_keeper.get(&keeper_id).unwrap_or(0);
+            keeper.rewards_by_keeper.insert(&keeper_id, &(accrued + reward));
+        }
+
+        processed
+    }
+
+    fn mt_keeper_set_task(&mut self, task: KeeperTask, config: Option<KeeperTaskConfig>) {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+
+        match task {
+            KeeperTask::ExpiredApprovals => {
+                expect_extension(self.approval_expiry.as_ref(), "Approval expiry", Entity::Contract);
+            }
+            KeeperTask::ExpiredTokens => {
+                expect_extension(self.expiring_tokens.as_ref(), "Expiring tokens", Entity::Contract);
+            }
+            KeeperTask::EmptyRecords | KeeperTask::HolderIndex => {}
+        }
+
+        let keeper = expect_extension(self.keeper.as_mut(), "Keeper", Entity::Contract);
+        match config {
+            Some(config) => {
+                keeper.tasks.insert(&task, &config);
+            }
+            None => {
+                keeper.tasks.remove(&task);
+                keeper.last_run_by_task.remove(&task);
+                keeper.cursor_by_task.remove(&task);
+            }
+        }
+    }
+
+    fn mt_keeper_fund(&mut self) {
+        let keeper = expect_extension(self.keeper.as_mut(), "Keeper", Entity::Contract);
+        keeper.reward_pool += env::attached_deposit();
+    }
+
+    fn mt_keeper_claim(&mut self) -> Promise {
+        self.assert_call_deposit();
+        let keeper_id = env::predecessor_account_id();
+
+        let keeper = expect_extension(self.keeper.as_mut(), "Keeper", Entity::Contract);
+        let reward = keeper.rewards_by_keeper.remove(&keeper_id).unwrap_or(0);
+        require!(reward > 0, "Nothing to claim");
+
+        Promise::new(keeper_id).transfer(reward)
+    }
+
+    fn mt_keeper_tasks(&self) -> Vec<KeeperTaskStatus> {
+        let keeper = expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract);
+
+        keeper
+            .tasks
+            .iter()
+            .map(|(task, config)| {
+                let next_run_at = keeper
+                    .last_run_by_task
+                    .get(&task)
+                    .map_or(0, |last_run| last_run.saturating_add(config.cooldown.0));
+                KeeperTaskStatus { task, config, next_run_at: U64(next_run_at) }
+            })
+            .collect()
+    }
+
+    fn mt_keeper_reward_of(&self, account_id: AccountId) -> U128 {
+        let keeper = expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract);
+        U128(keeper.rewards_by_keeper.get(&account_id).unwrap_or(0))
+    }
+
+    fn mt_keeper_pool(&self) -> U128 {
+        U128(expect_extension(self.keeper.as_ref(), "Keeper", Entity::Contract).reward_pool)
+    }
+}
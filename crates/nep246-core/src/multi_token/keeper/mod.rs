@@ -0,0 +1,91 @@
+/*! Keeper API for maintenance of contract state
+
+All maintenance tasks run through a single `mt_keeper_execute(task, limit)`, so keeper networks
+like Croncat can be pointed at one method. Owner registers tasks with a cooldown and a reward
+paid for every processed item from a pool anyone can fund. Several keepers racing for the same
+task are safe: calls are executed one after another, each continues where previous one stopped
+and keeper is rewarded only for items it actually processed.
+ */
+
+mod keeper_impl;
+
+pub use keeper_impl::*;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{U128, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, Promise};
+
+/// Maintenance task keepers can execute
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum KeeperTask {
+    /// Remove expired approvals, one item is one approval. Requires approval expiry
+    ExpiredApprovals,
+    /// Burn balances of expired tokens, one item is one balance. Requires expiring tokens
+    ExpiredTokens,
+    /// Remove zero balance records and their holder entries, one item is one removed record.
+    /// Released storage is credited to NEP-145 storage balance of the account, if it has one.
+    /// `limit` is number of tokens scanned
+    EmptyRecords,
+    /// Drop holder index entries of accounts without balance record, one item is one dropped entry.
+    /// `limit` is number of tokens scanned
+    HolderIndex,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct KeeperTaskConfig {
+    /// Minimal time between two runs of task, in nanoseconds
+    pub cooldown: U64,
+    /// Reward for every processed item, paid while reward pool lasts
+    pub reward_per_item: U128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct KeeperTaskStatus {
+    pub task: KeeperTask,
+    pub config: KeeperTaskConfig,
+    /// Block timestamp in nanoseconds from which task can run again
+    pub next_run_at: U64,
+}
+
+pub trait MultiTokenKeeper {
+    /// Process at most `limit` items of maintenance task. Caller is credited with
+    /// `reward_per_item` for every processed item, claimable with `mt_keeper_claim`
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if task is not registered
+    /// * Contract MUST panic if cooldown of task since its last productive run is not over
+    ///
+    /// # Returns
+    /// Number of processed items, 0 if there was nothing to do
+    fn mt_keeper_execute(&mut self, task: KeeperTask, limit: u64) -> u64;
+
+    /// Register task, replace its config or remove it with `None`
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn mt_keeper_set_task(&mut self, task: KeeperTask, config: Option<KeeperTaskConfig>);
+
+    /// Add attached deposit to reward pool
+    fn mt_keeper_fund(&mut self);
+
+    /// Transfer rewards accrued by caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller has nothing to claim
+    fn mt_keeper_claim(&mut self) -> Promise;
+
+    /// Get registered tasks and when they can run next
+    fn mt_keeper_tasks(&self) -> Vec<KeeperTaskStatus>;
+
+    /// Get rewards accrued by keeper and not claimed yet
+    fn mt_keeper_reward_of(&self, account_id: AccountId) -> U128;
+
+    /// Get amount left in reward pool
+    fn mt_keeper_pool(&self) -> U128;
+}
@@ -0,0 +1,216 @@
+//! Layouts of state written by previous releases and their migration to the current ones.
+//!
+//! Contract deployed with the previous release keeps its state readable after upgrade this way:
+//! - `migrate` reads root state with [MultiTokenV0] in place of [MultiToken] and converts it with `into()`.
+//!   Extensions added since then start disabled and are enabled with their `enable_*` methods.
+//! - [MultiToken::internal_migrate_tokens] is called until every token is done (e.g. with owner-gated
+//!   `mt_migrate_tokens`). It rewrites approvals, token metadata and tokens of owner which changed their layout.
+//! - Holders of every token, which weren't indexed before, are indexed with `mt_rebuild_holder_index`
+//!   over candidates uploaded with `mt_add_holder_candidates`.
+//!
+//! Any further change of stored layout must add its previous version here, the same way.
+
+use std::collections::HashMap;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap, UnorderedMap, UnorderedSet};
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance, StorageUsage};
+
+use crate::multi_token::{
+    core::{ApprovalId, MultiToken, StorageKey},
+    metadata::TokenMetadata,
+    token::{Approval, TokenId},
+};
+
+/// Approval as it was stored before compact encoding
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct LegacyApproval {
+    pub amount: u128,
+    pub approval_id: ApprovalId,
+}
+
+impl From<LegacyApproval> for Approval {
+    fn from(legacy: LegacyApproval) -> Self {
+        Approval { amount: U128(legacy.amount), approval_id: legacy.approval_id, share: None }
+    }
+}
+
+/// Token metadata as it was stored before `mime_type`, `attributes` and `kind` were added
+#[derive(Debug, Clone, PartialEq, Default, BorshDeserialize, BorshSerialize)]
+pub struct LegacyTokenMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<String>,
+    pub issued_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub starts_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub extra: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+impl From<LegacyTokenMetadata> for TokenMetadata {
+    fn from(legacy: LegacyTokenMetadata) -> Self {
+        TokenMetadata {
+            title: legacy.title,
+            description: legacy.description,
+            media: legacy.media,
+            media_hash: legacy.media_hash,
+            mime_type: None,
+            issued_at: legacy.issued_at,
+            expires_at: legacy.expires_at,
+            starts_at: legacy.starts_at,
+            updated_at: legacy.updated_at,
+            extra: legacy.extra,
+            attributes: None,
+            kind: None,
+            reference: legacy.reference,
+            reference_hash: legacy.reference_hash,
+        }
+    }
+}
+
+/// Root state of [MultiToken] as it was stored by the previous release
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MultiTokenV0 {
+    pub owner_id: AccountId,
+    pub extra_storage_in_bytes_per_emission: StorageUsage,
+    pub owner_by_id: TreeMap<TokenId, AccountId>,
+    pub total_supply: LookupMap<TokenId, Balance>,
+    pub token_metadata_by_id: Option<LookupMap<TokenId, LegacyTokenMetadata>>,
+    pub tokens_per_owner: Option<LookupMap<AccountId, UnorderedSet<TokenId>>>,
+    pub balances_per_token: UnorderedMap<TokenId, LookupMap<AccountId, u128>>,
+    pub approvals_by_id: Option<LookupMap<TokenId, HashMap<AccountId, LegacyApproval>>>,
+    /// Shares storage prefix with `approvals_by_id`, its entries are dropped by [MultiToken::internal_migrate_tokens]
+    pub approvals_number_by_id: Option<LookupMap<TokenId, usize>>,
+    pub next_approval_id_by_id: Option<LookupMap<TokenId, u64>>,
+    pub next_token_id: u64,
+}
+
+/// Storage prefix of the collection, the same for any types of its keys and values
+fn prefix_of<K, V>(map: &LookupMap<K, V>) -> Vec<u8> {
+    Vec::<u8>::try_from_slice(&map.try_to_vec().unwrap()).unwrap()
+}
+
+impl From<MultiTokenV0> for MultiToken {
+    fn from(old: MultiTokenV0) -> Self {
+        let mut tokens = MultiToken::new(
+            Vec::<u8>::new(),
+            old.owner_id,
+            old.token_metadata_by_id.as_ref().map(prefix_of),
+            old.tokens_per_owner.as_ref().map(prefix_of),
+            old.approvals_by_id.as_ref().map(prefix_of),
+        );
+
+        tokens.extra_storage_in_bytes_per_emission = old.extra_storage_in_bytes_per_emission;
+        tokens.owner_by_id = old.owner_by_id;
+        tokens.total_supply = old.total_supply;
+        tokens.balances_per_token = old.balances_per_token;
+        tokens.next_approval_id_by_id = old.next_approval_id_by_id;
+        tokens.next_token_id = old.next_token_id;
+        tokens
+    }
+}
+
+impl MultiToken {
+    /// Rewrite approvals, metadata and owner's index of at most `limit` tokens starting from `from_index`
+    /// (in order of `owner_by_id`) from legacy layout to the current one. Returns index to continue from,
+    /// migration is complete once it's equal to number of tokens.
+    ///
+    /// Must be run exactly once over all tokens right after upgrade. Entries already in current layout are kept as is.
+    pub fn internal_migrate_tokens(&mut self, from_index: u64, limit: u64) -> u64 {
+        let token_ids: Vec<TokenId> = self
+            .owner_by_id
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|(token_id, _)| token_id)
+            .collect();
+        let next_index = from_index + token_ids.len() as u64;
+
+        for token_id in token_ids {
+            self.migrate_approvals(&token_id);
+            self.migrate_token_metadata(&token_id);
+            self.migrate_tokens_of_owner(&token_id);
+        }
+
+        next_index
+    }
+
+    fn migrate_approvals(&mut self, token_id: &TokenId) {
+        let owner_id = self.owner_by_id.get(token_id).unwrap();
+        let raw = match self.approvals_by_id.as_mut() {
+            Some(approvals) => approvals.remove_raw(&token_id.try_to_vec().unwrap()),
+            None => return,
+        };
+        let raw = match raw {
+            Some(raw) => raw,
+            None => return,
+        };
+
+        let migrated: HashMap<AccountId, Approval> = match HashMap::<AccountId, LegacyApproval>::try_from_slice(&raw) {
+            Ok(legacy) => legacy.into_iter().map(|(account_id, approval)| (account_id, approval.into())).collect(),
+            Err(_) => match HashMap::<AccountId, Approval>::try_from_slice(&raw) {
+                Ok(current) => current,
+                // Approvals counter written over the map under the same key, approvals themselves are lost
+                Err(_) if u64::try_from_slice(&raw).is_ok() => return,
+                Err(_) => env::panic_str("Unknown layout of stored approvals"),
+            },
+        };
+
+        for account_id in migrated.keys() {
+            self.internal_index_grantee(account_id, &owner_id, token_id);
+        }
+        self.approvals_by_id.as_mut().unwrap().insert(token_id, &migrated);
+    }
+
+    fn migrate_token_metadata(&mut self, token_id: &TokenId) {
+        let metadata = match self.token_metadata_by_id.as_mut() {
+            Some(metadata) => metadata,
+            None => return,
+        };
+        let raw = match metadata.remove_raw(&token_id.try_to_vec().unwrap()) {
+            Some(raw) => raw,
+            None => return,
+        };
+
+        let migrated: TokenMetadata = match LegacyTokenMetadata::try_from_slice(&raw) {
+            Ok(legacy) => legacy.into(),
+            Err(_) => TokenMetadata::try_from_slice(&raw)
+                .unwrap_or_else(|_| env::panic_str("Unknown layout of stored token metadata")),
+        };
+        metadata.insert(token_id, &migrated);
+    }
+
+    /// Tokens of owner were kept in `UnorderedSet`, now in `TreeMap` for stable order of pages.
+    /// Whole set is moved once, on the first token of owner. Serialized collection ends with
+    /// prefix of its vector: `e` for elements of set, `n` for nodes of tree
+    fn migrate_tokens_of_owner(&mut self, token_id: &TokenId) {
+        let owner_id = self.owner_by_id.get(token_id).unwrap();
+        let per_owner = match self.tokens_per_owner.as_mut() {
+            Some(per_owner) => per_owner,
+            None => return,
+        };
+        let key = owner_id.try_to_vec().unwrap();
+        let raw = match per_owner.remove_raw(&key) {
+            Some(raw) => raw,
+            None => return,
+        };
+        if raw.last() != Some(&b'e') {
+            per_owner.insert_raw(&key, &raw);
+            return;
+        }
+
+        let mut legacy = UnorderedSet::<TokenId>::try_from_slice(&raw)
+            .unwrap_or_else(|_| env::panic_str("Unknown layout of stored tokens of owner"));
+        let mut token_ids = TreeMap::new(StorageKey::TokensPerOwner { account_hash: env::sha256(owner_id.as_bytes()) });
+        legacy.iter().for_each(|token_id| {
+            token_ids.insert(&token_id, &());
+        });
+        legacy.clear();
+        per_owner.insert(&owner_id, &token_ids);
+    }
+}
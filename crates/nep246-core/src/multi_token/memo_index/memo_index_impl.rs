@@ -0,0 +1,109 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{MultiTokenMemoIndex, TaggedTransfer, MAX_MEMO_TAG_LEN, MAX_MEMO_VALUE_LEN};
+
+/// Bounded log of transfers for every memo tag
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MemoIndex {
+    /// Maximum number of records kept per tag
+    pub capacity: u64,
+    /// Records of each tag, oldest first
+    pub records_by_tag: LookupMap<String, Vec<TaggedTransfer>>,
+}
+
+/// Split memo into tag and value. Tag is ASCII alphanumeric, `_` or `-`, both parts are non-empty
+pub fn parse_memo_tag(memo: &str) -> Option<(&str, &str)> {
+    let (tag, value) = memo.split_once(':')?;
+    let valid_tag = !tag.is_empty()
+        && tag.len() <= MAX_MEMO_TAG_LEN
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid_tag && !value.is_empty() && value.len() <= MAX_MEMO_VALUE_LEN {
+        Some((tag, value))
+    } else {
+        None
+    }
+}
+
+impl MultiToken {
+    /// Start indexing last `capacity` transfers of every memo tag
+    pub fn enable_memo_index<P: IntoStorageKey>(&mut self, prefix: P, capacity: u64) {
+        require!(capacity > 0, "Capacity cannot be 0");
+
+        self.memo_index = Some(MemoIndex { capacity, records_by_tag: LookupMap::new(prefix) });
+    }
+
+    /// Remember transfer under tag of memo if index is used and memo is tagged. Drops oldest record when buffer is full
+    pub fn internal_index_memo(
+        &mut self,
+        old_owner_id: &AccountId,
+        new_owner_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+        memo: &str,
+    ) {
+        let (index, (tag, value)) = match (self.memo_index.as_mut(), parse_memo_tag(memo)) {
+            (Some(index), Some(parsed)) => (index, parsed),
+            _ => return,
+        };
+
+        let tag = tag.to_string();
+        let mut records = index.records_by_tag.get(&tag).unwrap_or_default();
+        if records.len() as u64 >= index.capacity {
+            records.drain(..=(records.len() - index.capacity as usize));
+        }
+        records.push(TaggedTransfer {
+            value: value.to_string(),
+            old_owner_id: old_owner_id.clone(),
+            new_owner_id: new_owner_id.clone(),
+            token_id: token_id.clone(),
+            amount: U128(amount),
+            timestamp: env::block_timestamp(),
+        });
+        index.records_by_tag.insert(&tag, &records);
+    }
+}
+
+impl MultiTokenMemoIndex for MultiToken {
+    fn mt_transfer_with_memo(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        approval_id: Option<u64>,
+        memo: String,
+    ) {
+        self.assert_storage_call_deposit();
+        let sender_id = env::predecessor_account_id();
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        self.internal_transfer_with_memo(&sender_id, &receiver_id, &token_id, approval_id, amount.0, Some(memo));
+        tracker.stop();
+        tracker.refund(sender_id);
+    }
+
+    fn mt_transfers_by_tag(&self, tag: String, limit: u64) -> Vec<TaggedTransfer> {
+        let index = expect_extension(self.memo_index.as_ref(), "Memo index", Entity::Contract);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        index
+            .records_by_tag
+            .get(&tag)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .take(limit as usize)
+            .collect()
+    }
+}
@@ -0,0 +1,65 @@
+/*! Lookup of transfers by memo tag
+
+Memo in `tag:value` form, e.g. `order:8412`, puts transfer into a bounded per-tag index of recent
+transfers, so payment references like order IDs can be checked on-chain without an indexer.
+Memos without a tag are only echoed into the transfer event.
+ */
+
+mod memo_index_impl;
+
+pub use memo_index_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Maximum length of tag part of memo
+pub const MAX_MEMO_TAG_LEN: usize = 32;
+
+/// Maximum length of value part of memo
+pub const MAX_MEMO_VALUE_LEN: usize = 128;
+
+/// Transfer stored in index of its memo tag
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TaggedTransfer {
+    /// Part of memo after the tag
+    pub value: String,
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub token_id: TokenId,
+    pub amount: U128,
+    /// Block timestamp of transfer, in nanoseconds
+    pub timestamp: u64,
+}
+
+pub trait MultiTokenMemoIndex {
+    /// Transfer token with memo. Tagged memo is added to index of its tag
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage of index entry, unused part is refunded
+    /// * Same requirements as `mt_transfer`
+    ///
+    /// # Arguments:
+    /// * `memo` - `tag:value` to index transfer, any other text is only included in event
+    fn mt_transfer_with_memo(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: Amount,
+        approval_id: Option<u64>,
+        memo: String,
+    );
+
+    /// Get most recent transfers with given memo tag, newest first
+    ///
+    /// # Arguments:
+    /// * `tag` - Tag part of memo, without `:`
+    /// * `limit` - The maximum number of transfers to return
+    ///
+    /// returns: List of [TaggedTransfer]s, empty if tag was never used
+    fn mt_transfers_by_tag(&self, tag: String, limit: u64) -> Vec<TaggedTransfer>;
+}
@@ -0,0 +1,181 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::require;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::multi_token::token::{TokenId, TokenKind};
+
+/// Version of standard
+pub const MT_METADATA_SPEC: &str = "mt-0.0.1";
+
+/// Metadata that will be permanently set at the contract init
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MtContractMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon: Option<String>,
+    pub base_uri: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+/// Metadata for each token
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenMetadata {
+    pub title: Option<String>,
+    /// Free-form description
+    pub description: Option<String>,
+    /// URL to associated media, preferably to decentralized, content-addressed storage
+    pub media: Option<String>,
+    /// Base64-encoded sha256 hash of content referenced by the `media` field. Required if `media` is included.
+    pub media_hash: Option<String>,
+    /// MIME type of content referenced by the `media` field, e.g. `image/png`
+    pub mime_type: Option<String>,
+    /// When token was issued or minted, Unix epoch in milliseconds
+    pub issued_at: Option<String>,
+    /// When token expires, Unix epoch in milliseconds
+    pub expires_at: Option<String>,
+    /// When token starts being valid, Unix epoch in milliseconds
+    pub starts_at: Option<String>,
+    /// When token was last updated, Unix epoch in milliseconds
+    pub updated_at: Option<String>,
+    /// Anything extra the MT wants to store on-chain. Can be stringified JSON.
+    pub extra: Option<String>,
+    /// Traits of token in OpenSea-compatible shape, so rarity tools don't have to parse `extra`
+    pub attributes: Option<Vec<TokenAttribute>>,
+    /// Kind of token chosen at mint, filled by contracts that distinguish them
+    pub kind: Option<TokenKind>,
+    /// URL to an off-chain JSON file with more info.
+    pub reference: Option<String>,
+    /// Base64-encoded sha256 hash of JSON from reference field. Required if `reference` is included.
+    pub reference_hash: Option<String>,
+}
+
+/// Maximum number of attributes of a single token
+pub const MAX_TOKEN_ATTRIBUTES: usize = 32;
+
+/// Maximum length of `trait_type` and text values of attributes
+pub const MAX_ATTRIBUTE_LEN: usize = 128;
+
+/// Value of attribute, JSON string or integer
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde", untagged)]
+pub enum AttributeValue {
+    Number(i64),
+    Text(String),
+}
+
+/// Trait of token, e.g. `{"trait_type": "Eyes", "value": "Laser"}`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenAttribute {
+    pub trait_type: String,
+    pub value: AttributeValue,
+    /// How marketplaces render the value: `number`, `boost_number`, `boost_percentage` or `date`.
+    /// All of them require a number, `date` is Unix epoch in seconds
+    pub display_type: Option<String>,
+}
+
+impl TokenAttribute {
+    pub fn assert_valid(&self) {
+        require!(
+            !self.trait_type.is_empty() && self.trait_type.len() <= MAX_ATTRIBUTE_LEN,
+            "Trait type must be 1 to 128 bytes long"
+        );
+        if let AttributeValue::Text(value) = &self.value {
+            require!(value.len() <= MAX_ATTRIBUTE_LEN, "Attribute value cannot be longer than 128 bytes");
+        }
+        if let Some(display_type) = &self.display_type {
+            require!(
+                matches!(display_type.as_str(), "number" | "boost_number" | "boost_percentage" | "date"),
+                "Unknown display type of attribute"
+            );
+            require!(matches!(self.value, AttributeValue::Number(_)), "Display type requires a number");
+        }
+    }
+}
+
+/// Maximum number of tokens in single `mt_metadata_token_by_ids` call
+pub const MAX_METADATA_BATCH: usize = 100;
+
+/// Compose URI of token:
+/// * `media` of token (or `reference` if there is no media) is used as path
+/// * Absolute paths (with scheme, e.g. `ipfs://`) are returned as is
+/// * Relative paths are joined with `base_uri`
+/// * Without path, `base_uri` is used if it contains `{id}`
+/// * `{id}` is replaced with token ID, like in ERC-1155
+pub fn token_uri(base_uri: Option<&str>, token_id: &TokenId, metadata: Option<&TokenMetadata>) -> Option<String> {
+    let path = metadata.and_then(|metadata| metadata.media.as_ref().or(metadata.reference.as_ref()));
+
+    let uri = match (base_uri, path) {
+        (_, Some(path)) if path.contains("://") => path.clone(),
+        (Some(base_uri), Some(path)) => {
+            format!("{}/{}", base_uri.trim_end_matches('/'), path.trim_start_matches('/'))
+        }
+        (None, Some(path)) => path.clone(),
+        (Some(base_uri), None) if base_uri.contains("{id}") => base_uri.to_string(),
+        _ => return None,
+    };
+
+    Some(uri.replace("{id}", token_id.as_str()))
+}
+
+/// Check if `mime_type` looks like `type/subtype`
+pub fn is_valid_mime_type(mime_type: &str) -> bool {
+    let mut parts = mime_type.splitn(2, '/');
+    let is_token = |part: &str| {
+        !part.is_empty()
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+    };
+
+    matches!((parts.next(), parts.next()), (Some(kind), Some(subtype)) if is_token(kind) && is_token(subtype))
+}
+
+impl MtContractMetadata {
+    pub fn assert_valid(&self) {
+        require!(self.spec == MT_METADATA_SPEC, "Spec is not NFT metadata");
+        require!(
+            self.reference.is_some() == self.reference_hash.is_some(),
+            "Reference and reference hash must be present"
+        );
+        if let Some(reference_hash) = &self.reference_hash {
+            require!(reference_hash.len() == 32, "Hash has to be 32 bytes");
+        }
+    }
+}
+
+impl TokenMetadata {
+    pub fn assert_valid(&self) {
+        require!(self.media.is_some() == self.media_hash.is_some());
+        if let Some(media_hash) = &self.media_hash {
+            require!(media_hash.len() == 32, "Media hash has to be 32 bytes");
+        }
+
+        if let Some(mime_type) = &self.mime_type {
+            require!(self.media.is_some(), "MIME type requires media");
+            require!(is_valid_mime_type(mime_type), "Invalid MIME type");
+        }
+
+        require!(self.reference.is_some() == self.reference_hash.is_some());
+        if let Some(reference_hash) = &self.reference_hash {
+            require!(reference_hash.len() == 32, "Reference hash has to be 32 bytes");
+        }
+
+        self.assert_valid_attributes();
+    }
+
+    /// Check shape of attributes, done on every mint
+    pub fn assert_valid_attributes(&self) {
+        if let Some(attributes) = &self.attributes {
+            require!(attributes.len() <= MAX_TOKEN_ATTRIBUTES, "Too many attributes");
+            for (idx, attribute) in attributes.iter().enumerate() {
+                attribute.assert_valid();
+                require!(
+                    attributes[..idx].iter().all(|other| other.trait_type != attribute.trait_type),
+                    "Trait types of attributes must be unique"
+                );
+            }
+        }
+    }
+}
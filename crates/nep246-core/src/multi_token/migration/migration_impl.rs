@@ -0,0 +1,187 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap};
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::{MultiToken, StorageKey},
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{MultiTokenMigration, MultiTokenRekey};
+
+/// Progress of unfinished re-keying
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Rekeys {
+    /// Last walked token, by old account
+    pub cursor_by_account: LookupMap<AccountId, TokenId>,
+}
+
+impl MultiToken {
+    /// Allow contract owner to re-key accounts
+    pub fn enable_rekey<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.rekeys = Some(Rekeys { cursor_by_account: LookupMap::new(prefix) });
+    }
+
+    /// Make `new_id` creator of token instead of `old_id`, keeping approvals and enumeration entries
+    fn internal_rekey_creator(&mut self, token_id: &TokenId, old_id: &AccountId, new_id: &AccountId) {
+        if self.owner_by_id.get(token_id).as_ref() != Some(old_id) {
+            return;
+        }
+        self.owner_by_id.insert(token_id, new_id);
+
+        let grantees: Vec<AccountId> = self
+            .approvals_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(token_id))
+            .map(|approvals| approvals.into_keys().collect())
+            .unwrap_or_default();
+        for grantee_id in grantees {
+            self.internal_unindex_grantee(&grantee_id, old_id, token_id);
+            self.internal_index_grantee(&grantee_id, new_id, token_id);
+        }
+
+        if let Some(per_owner) = self.tokens_per_owner.as_mut() {
+            if let Some(mut token_ids) = per_owner.get(old_id) {
+                token_ids.remove(token_id);
+                if token_ids.is_empty() {
+                    per_owner.remove(old_id);
+                } else {
+                    per_owner.insert(old_id, &token_ids);
+                }
+            }
+            let mut token_ids = per_owner.get(new_id).unwrap_or_else(|| {
+                TreeMap::new(StorageKey::TokensPerOwner { account_hash: env::sha256(new_id.as_bytes()) })
+            });
+            token_ids.insert(token_id, &());
+            per_owner.insert(new_id, &token_ids);
+        }
+    }
+
+    /// Move balance of `sender_id` to `receiver_id` and drop balance entry of sender
+    fn internal_move_holding(
+        &mut self,
+        token_id: &TokenId,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        memo: &str,
+    ) -> bool {
+        let mut balances = match self.balances_per_token.get(token_id) {
+            Some(balances) => balances,
+            None => return false,
+        };
+        let balance = match balances.get(sender_id) {
+            Some(balance) => balance,
+            None => return false,
+        };
+
+        if balance > 0 {
+            self.assert_not_transfer_locked(token_id);
+            self.assert_not_cooling_down(token_id, sender_id);
+            self.assert_min_transfer_amount(token_id, balance);
+            if !balances.contains_key(receiver_id) {
+                self.internal_register_account(token_id, receiver_id);
+            }
+            self.internal_withdraw(token_id, sender_id, balance);
+            self.internal_deposit(token_id, receiver_id, balance);
+
+            self.emit_transfer(sender_id, receiver_id, token_id, balance, None, Some(memo.to_string()));
+            self.internal_record_transfer(sender_id, receiver_id, token_id, balance);
+            self.internal_mark_secondary_sale(token_id, sender_id);
+        }
+
+        // Approvals are given by owner of the token
+        if self.owner_by_id.get(token_id).as_ref() == Some(sender_id) {
+            if let Some(approvals) = self.approvals_by_id.as_mut().and_then(|by_id| by_id.remove(token_id)) {
+                approvals
+                    .keys()
+                    .for_each(|grantee_id| self.internal_unindex_grantee(grantee_id, sender_id, token_id));
+            }
+        }
+
+        balances.remove(sender_id);
+        self.internal_remove_holder(token_id, sender_id);
+
+        balance > 0
+    }
+}
+
+impl MultiTokenMigration for MultiToken {
+    fn mt_transfer_all(&mut self, receiver_id: AccountId, limit: u64) -> Vec<TokenId> {
+        self.assert_storage_call_deposit();
+        require!(limit != 0, "Limit cannot be 0");
+
+        let sender_id = env::predecessor_account_id();
+        if sender_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        self.assert_transferable(&sender_id, &receiver_id);
+        self.assert_no_compliance();
+
+        // Every walked token leaves portfolio of sender, so the page is always taken from its start
+        let token_ids: Vec<TokenId> = expect_extension(self.portfolios.as_ref(), "Portfolio", Entity::Contract)
+            .activity_by_account
+            .get(&sender_id)
+            .map(|activity| activity.keys().take(limit as usize).collect())
+            .unwrap_or_default();
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        for token_id in token_ids.iter() {
+            if !self.internal_move_holding(token_id, &sender_id, &receiver_id, "transfer_all") {
+                // Nothing to move, portfolio entry may outlive balance entry of sender
+                self.internal_drop_from_portfolio(&sender_id, token_id);
+            }
+        }
+        tracker.stop();
+
+        // Released records may have been paid by anyone, so they are only credited to storage balance of sender
+        self.internal_storage_release(&sender_id, tracker.bytes_released);
+        tracker.refund(sender_id);
+
+        token_ids
+    }
+}
+
+impl MultiTokenRekey for MultiToken {
+    fn mt_rekey_account(&mut self, old_account_id: AccountId, new_account_id: AccountId, limit: u64) -> bool {
+        self.assert_storage_call_deposit();
+        unauthorized_assert(&self.owner_id);
+        require!(limit != 0, "Limit cannot be 0");
+        require!(old_account_id != new_account_id, "Sender and receiver must differ");
+
+        let mut cursor = expect_extension(self.rekeys.as_ref(), "Rekey", Entity::Contract).cursor_by_account.get(&old_account_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let mut done = false;
+        for _ in 0..limit {
+            let next = match &cursor {
+                Some(token_id) => self.owner_by_id.higher(token_id),
+                None => self.owner_by_id.min(),
+            };
+            let token_id = match next {
+                Some(token_id) => token_id,
+                None => {
+                    done = true;
+                    break;
+                }
+            };
+
+            self.internal_rekey_creator(&token_id, &old_account_id, &new_account_id);
+            self.internal_move_holding(&token_id, &old_account_id, &new_account_id, "rekey");
+            cursor = Some(token_id);
+        }
+
+        let rekeys = self.rekeys.as_mut().unwrap();
+        match cursor {
+            Some(token_id) if !done => rekeys.cursor_by_account.insert(&old_account_id, &token_id),
+            _ => rekeys.cursor_by_account.remove(&old_account_id),
+        };
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+
+        done
+    }
+}
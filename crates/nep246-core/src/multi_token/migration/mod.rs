@@ -0,0 +1,46 @@
+/*! Moving holdings between accounts of the same user, e.g. when rotating keys */
+
+mod migration_impl;
+
+pub use migration_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::AccountId;
+
+pub trait MultiTokenMigration {
+    /// Move whole balance of caller in every token it holds to `receiver_id`.
+    /// Tokens are walked in the same order as in `mt_portfolio` of caller. Walked tokens leave
+    /// the portfolio, so a large portfolio is moved by repeating the call until it returns nothing.
+    ///
+    /// Balance entries of caller are removed and approvals given by caller on moved tokens are dropped.
+    /// Storage they used is credited to storage balance of caller (see `storage_balance_of`),
+    /// not refunded, because it could be paid by someone else. Registering `receiver_id` in tokens it
+    /// doesn't hold yet is paid from attached deposit.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach at least 1 yoctoⓃ for security purposes
+    /// * Contract MUST track portfolios, see [crate::multi_token::portfolio]
+    /// * Contract MUST panic if any moved token is transfer-locked, cooling down for caller
+    ///   or its balance is below minimal transfer amount
+    ///
+    /// # Arguments:
+    /// * `receiver_id`: Account receiving holdings
+    /// * `limit` - The maximum number of tokens to walk
+    ///
+    /// returns: IDs of walked tokens, including ones where caller was registered without balance
+    fn mt_transfer_all(&mut self, receiver_id: AccountId, limit: u64) -> Vec<TokenId>;
+}
+
+/// Re-keying of accounts by contract owner, e.g. when a project rotates its named subaccounts
+pub trait MultiTokenRekey {
+    /// Move everything of `old_account_id` to `new_account_id`: balances, approvals it granted,
+    /// tokens created for it and its enumeration entries. Walks at most `limit` tokens per call,
+    /// progress is kept between calls, so the method is called until it returns `true`.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if called by someone other than contract owner
+    ///
+    /// returns: `true` if all tokens were walked
+    fn mt_rekey_account(&mut self, old_account_id: AccountId, new_account_id: AccountId, limit: u64) -> bool;
+}
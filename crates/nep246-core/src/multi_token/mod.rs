@@ -0,0 +1,102 @@
+pub mod core;
+
+pub mod token;
+
+pub mod approval;
+
+pub mod metadata;
+
+pub mod enumeration;
+
+pub mod utils;
+pub mod security;
+pub mod gas;
+pub mod accessors;
+pub mod json_types;
+
+pub mod errors;
+
+pub mod events;
+
+pub mod state;
+
+pub mod history;
+
+pub mod multisig;
+
+pub mod clawback;
+
+pub mod erc1155_compat;
+
+pub mod dao;
+
+pub mod burn;
+
+pub mod swap;
+
+pub mod payout;
+
+pub mod migration;
+
+pub mod portfolio;
+
+pub mod compliance;
+
+pub mod operator;
+
+pub mod staking;
+
+pub mod conversion;
+
+pub mod dust;
+
+pub mod holder_cap;
+
+pub mod source_metadata;
+
+pub mod storage_usage;
+
+pub mod rescue;
+
+pub mod dispute;
+
+pub mod expiring;
+
+pub mod memo_index;
+
+pub mod ft_mint;
+
+pub mod keeper;
+
+pub mod croncat;
+
+pub mod transfer_lock;
+
+pub mod consumer;
+
+pub mod token_kind;
+
+pub mod transfer_cooldown;
+
+pub mod storage_management;
+
+pub mod activity;
+
+pub mod channel;
+
+pub mod blind_mint;
+
+pub mod legacy;
+
+#[cfg(feature = "raw-views")]
+pub mod raw;
+
+#[cfg(feature = "upgrade")]
+pub mod upgrade;
+
+#[cfg(feature = "packed-args")]
+pub mod packed;
+
+#[cfg(feature = "test-utils")]
+pub mod testing;
+
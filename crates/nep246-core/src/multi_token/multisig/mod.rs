@@ -0,0 +1,72 @@
+mod multisig_impl;
+
+pub use multisig_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+pub type ProposalId = u64;
+
+/// Sensitive operations that can only be executed after council confirmed them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AdminAction {
+    /// Stop all transfers
+    Pause,
+    /// Resume transfers
+    Unpause,
+    /// Forbid account to send or receive tokens
+    FreezeAccount { account_id: AccountId },
+    UnfreezeAccount { account_id: AccountId },
+    /// Move tokens without owner's permission
+    ForceTransfer { token_id: TokenId, owner_id: AccountId, receiver_id: AccountId, amount: Amount },
+    /// Make metadata of token immutable, see [crate::multi_token::core::MultiToken::internal_update_token_metadata]
+    FreezeMetadata { token_id: TokenId },
+}
+
+/// Action waiting for confirmations of council members
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub proposer_id: AccountId,
+    pub action: AdminAction,
+    pub confirmations: Vec<AccountId>,
+    pub executed: bool,
+}
+
+/// Council of accounts where m-of-n confirmations are required for admin actions
+pub trait MultiTokenMultisig {
+    /// Propose an admin action. Proposal is confirmed by proposer right away.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not a council member
+    ///
+    /// # Arguments:
+    /// * `action` - action to execute once proposal gets enough confirmations
+    ///
+    /// returns: ID of the new proposal
+    fn mt_propose(&mut self, action: AdminAction) -> ProposalId;
+
+    /// Confirm proposal. Action is executed when number of confirmations reaches threshold.
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not a council member or already confirmed proposal
+    /// * Contract MUST panic if proposal was already executed
+    ///
+    /// returns: `true` if proposal was executed by this confirmation
+    fn mt_confirm(&mut self, proposal_id: ProposalId) -> bool;
+
+    /// Get proposal by ID
+    fn mt_proposal(&self, proposal_id: ProposalId) -> Option<Proposal>;
+
+    /// Get members of council and number of confirmations required
+    fn mt_council(&self) -> (Vec<AccountId>, u64);
+
+    /// Check if transfers are paused
+    fn mt_is_paused(&self) -> bool;
+}
@@ -0,0 +1,167 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, LookupSet, UnorderedSet};
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, Entity},
+};
+
+use super::{AdminAction, MultiTokenMultisig, Proposal, ProposalId};
+
+/// State of council and everything it controls
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Council {
+    pub members: UnorderedSet<AccountId>,
+    /// Confirmations required to execute proposal
+    pub threshold: u64,
+    pub proposals: LookupMap<ProposalId, Proposal>,
+    pub next_proposal_id: ProposalId,
+    pub paused: bool,
+    pub frozen_accounts: LookupSet<AccountId>,
+    pub frozen_metadata: LookupSet<TokenId>,
+}
+
+impl MultiToken {
+    /// Put admin actions under control of council
+    pub fn enable_multisig<P: IntoStorageKey>(&mut self, prefix: P, members: Vec<AccountId>, threshold: u64) {
+        require!(threshold > 0, "Threshold cannot be 0");
+
+        let prefix: Vec<u8> = prefix.into_storage_key();
+        let mut council_members = UnorderedSet::new([prefix.clone(), "m".into()].concat());
+        members.iter().for_each(|member| {
+            council_members.insert(member);
+        });
+        // Repeated member confirms only once, so it's counted once
+        require!(threshold <= council_members.len(), "Threshold is bigger than council");
+
+        self.council = Some(Council {
+            members: council_members,
+            threshold,
+            proposals: LookupMap::new([prefix.clone(), "p".into()].concat()),
+            next_proposal_id: 0,
+            paused: false,
+            frozen_accounts: LookupSet::new([prefix.clone(), "f".into()].concat()),
+            frozen_metadata: LookupSet::new([prefix, "d".into()].concat()),
+        });
+    }
+
+    /// Panics if transfers are paused or one of accounts is frozen
+    pub fn assert_transferable(&self, sender_id: &AccountId, receiver_id: &AccountId) {
+        if let Some(council) = &self.council {
+            require!(!council.paused, "Transfers are paused");
+            require!(!council.frozen_accounts.contains(sender_id), "Sender is frozen");
+            require!(!council.frozen_accounts.contains(receiver_id), "Receiver is frozen");
+        }
+    }
+
+    /// Panics if metadata of token was frozen by council
+    pub fn assert_metadata_not_frozen(&self, token_id: &TokenId) {
+        if let Some(council) = &self.council {
+            require!(!council.frozen_metadata.contains(token_id), "Metadata is frozen");
+        }
+    }
+
+    fn internal_execute(&mut self, action: AdminAction) {
+        let council = self.council.as_mut().unwrap();
+
+        match action {
+            AdminAction::Pause => council.paused = true,
+            AdminAction::Unpause => council.paused = false,
+            AdminAction::FreezeAccount { account_id } => {
+                council.frozen_accounts.insert(&account_id);
+            }
+            AdminAction::UnfreezeAccount { account_id } => {
+                council.frozen_accounts.remove(&account_id);
+            }
+            AdminAction::FreezeMetadata { token_id } => {
+                council.frozen_metadata.insert(&token_id);
+            }
+            AdminAction::ForceTransfer { token_id, owner_id, receiver_id, amount } => {
+                if owner_id == receiver_id {
+                    MtError::SenderIsReceiver.panic();
+                }
+
+                self.internal_withdraw(&token_id, &owner_id, amount.0);
+                self.internal_deposit(&token_id, &receiver_id, amount.0);
+
+                self.emit_transfer(
+                    &owner_id,
+                    &receiver_id,
+                    &token_id,
+                    amount.0,
+                    Some(&env::current_account_id()),
+                    Some("force transfer".to_string()),
+                );
+                self.internal_record_transfer(&owner_id, &receiver_id, &token_id, amount.0);
+            }
+        }
+    }
+
+    fn assert_council_member(&self) -> AccountId {
+        let council = expect_extension(self.council.as_ref(), "Multisig council", Entity::Contract);
+        let caller = env::predecessor_account_id();
+
+        require!(council.members.contains(&caller), "Caller is not a council member");
+
+        caller
+    }
+}
+
+impl MultiTokenMultisig for MultiToken {
+    fn mt_propose(&mut self, action: AdminAction) -> ProposalId {
+        self.assert_call_deposit();
+        let proposer_id = self.assert_council_member();
+
+        let council = self.council.as_mut().unwrap();
+        let proposal_id = council.next_proposal_id;
+        council.next_proposal_id += 1;
+
+        let proposal =
+            Proposal { proposer_id, action, confirmations: vec![], executed: false };
+        council.proposals.insert(&proposal_id, &proposal);
+
+        self.mt_confirm(proposal_id);
+
+        proposal_id
+    }
+
+    fn mt_confirm(&mut self, proposal_id: ProposalId) -> bool {
+        self.assert_call_deposit();
+        let member_id = self.assert_council_member();
+
+        let council = self.council.as_mut().unwrap();
+        let mut proposal = council.proposals.get(&proposal_id).expect("Proposal not found");
+
+        require!(!proposal.executed, "Proposal already executed");
+        require!(!proposal.confirmations.contains(&member_id), "Already confirmed");
+
+        proposal.confirmations.push(member_id);
+
+        let ready = proposal.confirmations.len() as u64 >= council.threshold;
+        proposal.executed = ready;
+        council.proposals.insert(&proposal_id, &proposal);
+
+        if ready {
+            self.internal_execute(proposal.action);
+        }
+
+        ready
+    }
+
+    fn mt_proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
+        expect_extension(self.council.as_ref(), "Multisig council", Entity::Contract).proposals.get(&proposal_id)
+    }
+
+    fn mt_council(&self) -> (Vec<AccountId>, u64) {
+        let council = expect_extension(self.council.as_ref(), "Multisig council", Entity::Contract);
+
+        (council.members.to_vec(), council.threshold)
+    }
+
+    fn mt_is_paused(&self) -> bool {
+        self.council.as_ref().map(|council| council.paused).unwrap_or(false)
+    }
+}
@@ -0,0 +1,51 @@
+mod operator_impl;
+
+pub use operator_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::AccountId;
+
+/// System operators (e.g. game server) chosen by contract owner may move tokens of users
+/// who opted in, without per-token approvals. Users can opt out at any time.
+pub trait MultiTokenSystemOperator {
+    /// Add or remove system operator
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn mt_set_system_operator(&mut self, operator_id: AccountId, enabled: bool);
+
+    /// Allow system operator to move tokens of caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    /// * Contract MUST panic if `operator_id` is not a system operator
+    fn mt_opt_in_operator(&mut self, operator_id: AccountId);
+
+    /// Forbid system operator to move tokens of caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    fn mt_opt_out_operator(&mut self, operator_id: AccountId);
+
+    /// Transfer tokens of `owner_id` on their behalf
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not a system operator or `owner_id` didn't opt in
+    fn mt_operator_transfer(
+        &mut self,
+        owner_id: AccountId,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        memo: Option<String>,
+    );
+
+    /// Get all system operators
+    fn mt_system_operators(&self) -> Vec<AccountId>;
+
+    /// Check if `owner_id` allowed system operator to move their tokens
+    fn mt_is_operator_opted_in(&self, owner_id: AccountId, operator_id: AccountId) -> bool;
+}
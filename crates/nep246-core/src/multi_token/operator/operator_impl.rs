@@ -0,0 +1,117 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupSet, UnorderedSet};
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::{assert_batch_lengths, MtError},
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::MultiTokenSystemOperator;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SystemOperators {
+    pub operators: UnorderedSet<AccountId>,
+    /// Pairs of (owner, operator) where owner opted in
+    pub opt_ins: LookupSet<(AccountId, AccountId)>,
+}
+
+impl MultiToken {
+    /// Allow system operators chosen by contract owner
+    pub fn enable_system_operators<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix: Vec<u8> = prefix.into_storage_key();
+
+        self.system_operators = Some(SystemOperators {
+            operators: UnorderedSet::new([prefix.clone(), "o".into()].concat()),
+            opt_ins: LookupSet::new([prefix, "i".into()].concat()),
+        });
+    }
+
+    /// Whether `operator_id` is a system operator that `owner_id` opted in for
+    pub fn internal_is_system_operator_of(&self, operator_id: &AccountId, owner_id: &AccountId) -> bool {
+        self.system_operators.as_ref().map_or(false, |system| {
+            system.operators.contains(operator_id) && system.opt_ins.contains(&(owner_id.clone(), operator_id.clone()))
+        })
+    }
+}
+
+impl MultiTokenSystemOperator for MultiToken {
+    fn mt_set_system_operator(&mut self, operator_id: AccountId, enabled: bool) {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+
+        let system = expect_extension(self.system_operators.as_mut(), "System operators", Entity::Contract);
+        if enabled {
+            system.operators.insert(&operator_id);
+        } else {
+            system.operators.remove(&operator_id);
+        }
+    }
+
+    fn mt_opt_in_operator(&mut self, operator_id: AccountId) {
+        self.assert_storage_call_deposit();
+
+        let owner_id = env::predecessor_account_id();
+        let system = expect_extension(self.system_operators.as_mut(), "System operators", Entity::Contract);
+        require!(system.operators.contains(&operator_id), "Account is not a system operator");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        system.opt_ins.insert(&(owner_id.clone(), operator_id));
+        tracker.stop();
+        tracker.refund(owner_id);
+    }
+
+    fn mt_opt_out_operator(&mut self, operator_id: AccountId) {
+        self.assert_call_deposit();
+
+        let owner_id = env::predecessor_account_id();
+        let system = expect_extension(self.system_operators.as_mut(), "System operators", Entity::Contract);
+        system.opt_ins.remove(&(owner_id, operator_id));
+    }
+
+    fn mt_operator_transfer(
+        &mut self,
+        owner_id: AccountId,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<Amount>,
+        memo: Option<String>,
+    ) {
+        self.assert_call_deposit();
+
+        let operator_id = env::predecessor_account_id();
+        require!(self.internal_is_system_operator_of(&operator_id, &owner_id), "Owner didn't opt in for this operator");
+        if owner_id == receiver_id {
+            MtError::SenderIsReceiver.panic();
+        }
+        assert_batch_lengths(token_ids.len(), &[amounts.len()]);
+        self.assert_no_compliance();
+
+        for (token_id, amount) in token_ids.iter().zip(amounts) {
+            if amount.0 == 0 {
+                MtError::ZeroAmount.panic();
+            }
+            self.assert_can_move(token_id, &owner_id, &receiver_id, amount.0);
+
+            self.internal_withdraw(token_id, &owner_id, amount.0);
+            self.internal_deposit(token_id, &receiver_id, amount.0);
+            self.internal_stamp_transfer(token_id, &owner_id);
+
+            self.emit_transfer(&owner_id, &receiver_id, token_id, amount.0, Some(&operator_id), memo.clone());
+            self.internal_record_transfer(&owner_id, &receiver_id, token_id, amount.0);
+            self.internal_mark_secondary_sale(token_id, &owner_id);
+        }
+    }
+
+    fn mt_system_operators(&self) -> Vec<AccountId> {
+        expect_extension(self.system_operators.as_ref(), "System operators", Entity::Contract).operators.to_vec()
+    }
+
+    fn mt_is_operator_opted_in(&self, owner_id: AccountId, operator_id: AccountId) -> bool {
+        self.internal_is_system_operator_of(&operator_id, &owner_id)
+    }
+}
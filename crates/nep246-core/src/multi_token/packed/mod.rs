@@ -0,0 +1,52 @@
+/*! Transfers with Borsh-encoded arguments for callers that want to skip JSON parsing.
+
+Arguments are passed as a single base64 string with Borsh-encoded struct, results are unchanged.
+Byte layouts of arguments:
+* `u128` - 16 bytes, little-endian
+* `u64` - 8 bytes, little-endian
+* `Vec<T>` - `u32` little-endian length followed by each element
+* `Option<T>` - `0u8` for `None`, `1u8` followed by `T` for `Some`
+* `String`, `AccountId`, [TokenId] - `u32` little-endian length followed by UTF-8 bytes
+* Structs - fields in order of declaration
+ */
+
+mod packed_impl;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::AccountId;
+
+/// Arguments of `mt_transfer_packed`, same as of `mt_transfer`
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct PackedTransfer {
+    pub receiver_id: AccountId,
+    pub token_id: TokenId,
+    pub amount: u128,
+    pub approval_id: Option<u64>,
+}
+
+/// Arguments of `mt_batch_transfer_packed`, same as of `mt_batch_transfer`
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct PackedBatchTransfer {
+    pub receiver_id: AccountId,
+    pub token_ids: Vec<TokenId>,
+    pub amounts: Vec<u128>,
+    pub approval_ids: Vec<Option<u64>>,
+    pub batch_id: Option<String>,
+}
+
+/// Transfers with packed arguments, enabled by `packed-args` feature
+pub trait MultiTokenPacked {
+    /// Same as `mt_transfer`
+    ///
+    /// # Arguments:
+    /// * `args` - base64 of Borsh-encoded [PackedTransfer]
+    fn mt_transfer_packed(&mut self, args: Base64VecU8);
+
+    /// Same as `mt_batch_transfer`
+    ///
+    /// # Arguments:
+    /// * `args` - base64 of Borsh-encoded [PackedBatchTransfer]
+    fn mt_batch_transfer_packed(&mut self, args: Base64VecU8);
+}
@@ -0,0 +1,25 @@
+use near_sdk::borsh::BorshDeserialize;
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::env;
+
+use crate::multi_token::core::{MultiToken, MultiTokenCore};
+use crate::multi_token::json_types::Amount;
+
+use super::{MultiTokenPacked, PackedBatchTransfer, PackedTransfer};
+
+fn unpack<T: BorshDeserialize>(args: &Base64VecU8) -> T {
+    T::try_from_slice(&args.0).unwrap_or_else(|_| env::panic_str("Invalid packed arguments"))
+}
+
+impl MultiTokenPacked for MultiToken {
+    fn mt_transfer_packed(&mut self, args: Base64VecU8) {
+        let PackedTransfer { receiver_id, token_id, amount, approval_id } = unpack(&args);
+        self.mt_transfer(receiver_id, token_id, Amount(amount), approval_id)
+    }
+
+    fn mt_batch_transfer_packed(&mut self, args: Base64VecU8) {
+        let PackedBatchTransfer { receiver_id, token_ids, amounts, approval_ids, batch_id } = unpack(&args);
+        let amounts = amounts.into_iter().map(Amount).collect();
+        self.mt_batch_transfer(receiver_id, token_ids, amounts, approval_ids, batch_id)
+    }
+}
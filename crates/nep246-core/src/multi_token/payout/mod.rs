@@ -0,0 +1,103 @@
+/*! Royalties of token creators
+
+Creator (account the token was minted to) sets royalty per token. Until the token is sold by
+someone else than creator, royalty can be changed freely up to [MAX_ROYALTY_BPS].
+After the first secondary sale shares can only go down, so collectors are protected from hikes.
+
+Tokens without own royalty fall back to royalty their creator set for a collection (token ID
+prefix, longest match wins) and then to contract default set by contract owner. Collection and
+default royalties are policies and can be changed at any time.
+
+Payout of a sale gives every royalty receiver its share rounded down, seller gets the rest.
+So sum of payout is always exactly the sale balance, dust never appears or disappears.
+ */
+
+mod payout_impl;
+
+pub use payout_impl::*;
+
+use std::collections::HashMap;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Shares of receivers in basis points, 10000 is 100%
+pub type Royalty = HashMap<AccountId, u16>;
+
+/// Maximum sum of shares in a royalty, in basis points
+pub const MAX_ROYALTY_BPS: u16 = 5_000;
+
+/// Limit of collection royalties one creator can set
+pub const MAX_COLLECTIONS_PER_CREATOR: usize = 16;
+
+/// Maximum number of receivers in a payout, used when caller doesn't give its own limit
+pub const MAX_LEN_PAYOUT: u32 = 10;
+
+/// Amounts each account receives from a sale, see [NEP-199](https://github.com/near/NEPs/blob/master/neps/nep-0199.md)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Payout {
+    pub payout: HashMap<AccountId, U128>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenRoyalty {
+    pub royalty: Royalty,
+    /// Whether token was already transferred by someone else than creator
+    pub secondary_sold: bool,
+}
+
+/// Level royalty of a token was resolved from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RoyaltySource {
+    Token,
+    /// Collection of creator, with its token ID prefix
+    Collection(String),
+    ContractDefault,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EffectiveRoyalty {
+    pub royalty: Royalty,
+    pub source: RoyaltySource,
+}
+
+pub trait MultiTokenRoyalty {
+    /// Set royalty of the token
+    ///
+    /// # Requirements:
+    /// * Caller must be creator of the token
+    /// * Caller must attach a deposit to cover storage, unused part is refunded
+    /// * Sum of shares must not exceed [MAX_ROYALTY_BPS]
+    /// * After first secondary sale no share can be increased and no receiver can be added
+    fn mt_set_royalty(&mut self, token_id: TokenId, royalty: Royalty);
+
+    /// Get royalty of the token, `None` if it was never set
+    fn mt_royalty(&self, token_id: TokenId) -> Option<TokenRoyalty>;
+
+    /// Set or remove royalty of caller's tokens whose ID starts with `collection_prefix`.
+    /// Applies to tokens without own royalty, including tokens minted later
+    ///
+    /// # Requirements:
+    /// * Caller must attach a deposit to cover storage, unused part is refunded
+    /// * Sum of shares must not exceed [MAX_ROYALTY_BPS]
+    /// * Caller can't have more than [MAX_COLLECTIONS_PER_CREATOR] collection royalties
+    fn mt_set_collection_royalty(&mut self, collection_prefix: String, royalty: Option<Royalty>);
+
+    /// Get royalty applied to the token: its own, then collection of its creator, then contract default.
+    /// `None` if none of them is set
+    fn mt_effective_royalty(&self, token_id: TokenId) -> Option<EffectiveRoyalty>;
+
+    /// Split `balance` of a sale of token by `owner_id` between royalty receivers and the seller
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if payout has more than `max_len_payout` receivers,
+    ///   [MAX_LEN_PAYOUT] if it's not given
+    fn mt_payout(&self, token_id: TokenId, owner_id: AccountId, balance: Amount, max_len_payout: Option<u32>) -> Payout;
+}
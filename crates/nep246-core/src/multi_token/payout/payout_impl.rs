@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, LookupSet};
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity, StorageTracker},
+};
+
+use super::{
+    EffectiveRoyalty, MultiTokenRoyalty, Payout, Royalty, RoyaltySource, TokenRoyalty, MAX_COLLECTIONS_PER_CREATOR,
+    MAX_LEN_PAYOUT, MAX_ROYALTY_BPS,
+};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Royalties {
+    pub royalty_by_id: LookupMap<TokenId, Royalty>,
+    pub secondary_sold: LookupSet<TokenId>,
+    /// Royalty by collection prefix, for each creator
+    pub collections_by_creator: LookupMap<AccountId, HashMap<String, Royalty>>,
+    /// Royalty of tokens without own or collection royalty
+    pub default_royalty: Option<Royalty>,
+}
+
+/// `share` basis points of `balance`, rounded down. Doesn't overflow for any balance
+pub fn bps_of(balance: Balance, share: u16) -> Balance {
+    let share = Balance::from(share);
+    balance / 10_000 * share + balance % 10_000 * share / 10_000
+}
+
+/// Split `balance` by `royalty`, each receiver's share is rounded down and the rest goes to `seller_id`.
+/// Receivers with nothing to get are left out, share of seller in royalty is merged with the rest.
+/// Sum of the result is always `balance`.
+pub fn split_payout(royalty: &Royalty, seller_id: &AccountId, balance: Balance) -> HashMap<AccountId, Balance> {
+    let total: u32 = royalty.values().map(|share| u32::from(*share)).sum();
+    require!(total <= 10_000, "Royalty cannot exceed 100%");
+
+    let mut payout: HashMap<AccountId, Balance> = royalty
+        .iter()
+        .filter(|(account_id, _)| *account_id != seller_id)
+        .map(|(account_id, share)| (account_id.clone(), bps_of(balance, *share)))
+        .filter(|(_, amount)| *amount > 0)
+        .collect();
+
+    let paid: Balance = payout.values().sum();
+    payout.insert(seller_id.clone(), balance - paid);
+    payout
+}
+
+fn assert_valid_royalty(royalty: &Royalty) {
+    let total: u32 = royalty.values().map(|share| u32::from(*share)).sum();
+    require!(total <= u32::from(MAX_ROYALTY_BPS), format!("Royalty cannot exceed {} bps", MAX_ROYALTY_BPS));
+}
+
+impl MultiToken {
+    /// Allow creators to set royalties of their tokens
+    pub fn enable_royalties<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix: Vec<u8> = prefix.into_storage_key();
+
+        self.royalties = Some(Royalties {
+            royalty_by_id: LookupMap::new([prefix.clone(), "r".into()].concat()),
+            secondary_sold: LookupSet::new([prefix.clone(), "s".into()].concat()),
+            collections_by_creator: LookupMap::new([prefix, "c".into()].concat()),
+            default_royalty: None,
+        });
+    }
+
+    /// Set royalty of tokens without own or collection royalty. Can only be called by contract owner
+    pub fn set_default_royalty(&mut self, royalty: Option<Royalty>) {
+        unauthorized_assert(&self.owner_id);
+        if let Some(royalty) = &royalty {
+            assert_valid_royalty(royalty);
+        }
+
+        expect_extension(self.royalties.as_mut(), "Royalties", Entity::Contract).default_royalty = royalty;
+    }
+
+    /// Royalty of token resolved through token, collection and contract levels
+    pub fn internal_effective_royalty(&self, token_id: &TokenId) -> Option<EffectiveRoyalty> {
+        let royalties = self.royalties.as_ref()?;
+
+        if let Some(royalty) = royalties.royalty_by_id.get(token_id) {
+            return Some(EffectiveRoyalty { royalty, source: RoyaltySource::Token });
+        }
+
+        let collection = self
+            .owner_by_id
+            .get(token_id)
+            .and_then(|creator| royalties.collections_by_creator.get(&creator))
+            .and_then(|collections| {
+                collections
+                    .into_iter()
+                    .filter(|(prefix, _)| token_id.starts_with(prefix.as_str()))
+                    .max_by_key(|(prefix, _)| prefix.len())
+            });
+        if let Some((prefix, royalty)) = collection {
+            return Some(EffectiveRoyalty { royalty, source: RoyaltySource::Collection(prefix) });
+        }
+
+        royalties
+            .default_royalty
+            .clone()
+            .map(|royalty| EffectiveRoyalty { royalty, source: RoyaltySource::ContractDefault })
+    }
+
+    /// Remember that token was sold by someone else than creator. Called on every transfer
+    pub fn internal_mark_secondary_sale(&mut self, token_id: &TokenId, owner_id: &AccountId) {
+        let creator = match self.owner_by_id.get(token_id) {
+            Some(creator) => creator,
+            None => return,
+        };
+        if let Some(royalties) = self.royalties.as_mut() {
+            if &creator != owner_id && !royalties.secondary_sold.contains(token_id) {
+                royalties.secondary_sold.insert(token_id);
+            }
+        }
+    }
+}
+
+impl MultiTokenRoyalty for MultiToken {
+    fn mt_set_royalty(&mut self, token_id: TokenId, royalty: Royalty) {
+        self.assert_storage_call_deposit();
+        let creator = self.owner_by_id.get(&token_id).expect("Token not found");
+        require!(env::predecessor_account_id() == creator, "Only creator can set royalty");
+
+        assert_valid_royalty(&royalty);
+
+        let royalties = expect_extension(self.royalties.as_mut(), "Royalties", Entity::Contract);
+
+        if royalties.secondary_sold.contains(&token_id) {
+            let old = royalties.royalty_by_id.get(&token_id).unwrap_or_default();
+            require!(
+                royalty.iter().all(|(account_id, share)| old.get(account_id).map_or(false, |old| share <= old)),
+                "Royalty can only decrease after first secondary sale"
+            );
+        }
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        royalties.royalty_by_id.insert(&token_id, &royalty);
+        tracker.stop();
+        tracker.refund(creator);
+    }
+
+    fn mt_royalty(&self, token_id: TokenId) -> Option<TokenRoyalty> {
+        let royalties = expect_extension(self.royalties.as_ref(), "Royalties", Entity::Contract);
+        royalties.royalty_by_id.get(&token_id).map(|royalty| TokenRoyalty {
+            royalty,
+            secondary_sold: royalties.secondary_sold.contains(&token_id),
+        })
+    }
+
+    fn mt_set_collection_royalty(&mut self, collection_prefix: String, royalty: Option<Royalty>) {
+        self.assert_storage_call_deposit();
+        let creator = env::predecessor_account_id();
+        if let Some(royalty) = &royalty {
+            assert_valid_royalty(royalty);
+        }
+
+        let royalties = expect_extension(self.royalties.as_mut(), "Royalties", Entity::Contract);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        let mut collections = royalties.collections_by_creator.get(&creator).unwrap_or_default();
+        match royalty {
+            Some(royalty) => {
+                collections.insert(collection_prefix, royalty);
+                require!(collections.len() <= MAX_COLLECTIONS_PER_CREATOR, "Too many collection royalties");
+            }
+            None => {
+                collections.remove(&collection_prefix);
+            }
+        }
+        if collections.is_empty() {
+            royalties.collections_by_creator.remove(&creator);
+        } else {
+            royalties.collections_by_creator.insert(&creator, &collections);
+        }
+        tracker.stop();
+        tracker.refund(creator);
+    }
+
+    fn mt_effective_royalty(&self, token_id: TokenId) -> Option<EffectiveRoyalty> {
+        expect_extension(self.royalties.as_ref(), "Royalties", Entity::Contract);
+
+        self.internal_effective_royalty(&token_id)
+    }
+
+    fn mt_payout(&self, token_id: TokenId, owner_id: AccountId, balance: Amount, max_len_payout: Option<u32>) -> Payout {
+        expect_extension(self.royalties.as_ref(), "Royalties", Entity::Contract);
+
+        let royalty = self.internal_effective_royalty(&token_id).map(|effective| effective.royalty).unwrap_or_default();
+        let payout = split_payout(&royalty, &owner_id, balance.0);
+
+        let max_len = max_len_payout.unwrap_or(MAX_LEN_PAYOUT);
+        require!(payout.len() <= max_len as usize, format!("Payout cannot have more than {} receivers", max_len));
+
+        Payout { payout: payout.into_iter().map(|(account_id, amount)| (account_id, U128(amount))).collect() }
+    }
+}
@@ -0,0 +1,34 @@
+/*! Per-account view of holdings, so wallets can render portfolio in one call */
+
+mod portfolio_impl;
+
+pub use portfolio_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Holding of account in a single token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PortfolioEntry {
+    pub token_id: TokenId,
+    pub balance: U128,
+    pub title: Option<String>,
+    pub media: Option<String>,
+    /// Block timestamp of last transfer of account in this token (or registration), in nanoseconds
+    pub last_transfer_timestamp: u64,
+}
+
+pub trait MultiTokenPortfolio {
+    /// Get holdings of account. Includes every token account is registered in
+    ///
+    /// # Arguments:
+    /// * `account_id` - Holder
+    /// * `from_index` - Index to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of entries to return
+    ///
+    /// returns: List of [PortfolioEntry]s
+    fn mt_portfolio(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<PortfolioEntry>;
+}
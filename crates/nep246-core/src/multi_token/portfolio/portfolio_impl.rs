@@ -0,0 +1,90 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, UnorderedMap};
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::TokenId,
+    utils::{expect_extension, Entity},
+};
+
+use super::{MultiTokenPortfolio, PortfolioEntry};
+
+/// Tokens of every account with timestamp of last activity in them
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Portfolios {
+    prefix: Vec<u8>,
+    pub activity_by_account: LookupMap<AccountId, UnorderedMap<TokenId, u64>>,
+}
+
+impl MultiToken {
+    /// Start tracking tokens of every account. Only activity after this call is tracked
+    pub fn enable_portfolio<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix: Vec<u8> = prefix.into_storage_key();
+
+        self.portfolios = Some(Portfolios {
+            activity_by_account: LookupMap::new([prefix.clone(), "a".into()].concat()),
+            prefix,
+        });
+    }
+
+    /// Remember activity of account in token
+    pub(crate) fn internal_touch_portfolio(&mut self, account_id: &AccountId, token_id: &TokenId) {
+        if let Some(portfolios) = self.portfolios.as_mut() {
+            let mut activity = portfolios.activity_by_account.get(account_id).unwrap_or_else(|| {
+                UnorderedMap::new([portfolios.prefix.clone(), "t".into(), env::sha256(account_id.as_bytes())].concat())
+            });
+            activity.insert(token_id, &env::block_timestamp());
+            portfolios.activity_by_account.insert(account_id, &activity);
+        }
+    }
+
+    /// Forget token in portfolio of account, e.g. after account unregistered from it
+    pub(crate) fn internal_drop_from_portfolio(&mut self, account_id: &AccountId, token_id: &TokenId) {
+        if let Some(portfolios) = self.portfolios.as_mut() {
+            if let Some(mut activity) = portfolios.activity_by_account.get(account_id) {
+                activity.remove(token_id);
+                if activity.is_empty() {
+                    portfolios.activity_by_account.remove(account_id);
+                } else {
+                    portfolios.activity_by_account.insert(account_id, &activity);
+                }
+            }
+        }
+    }
+}
+
+impl MultiTokenPortfolio for MultiToken {
+    fn mt_portfolio(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<PortfolioEntry> {
+        let portfolios = expect_extension(self.portfolios.as_ref(), "Portfolio", Entity::Contract);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        let activity = match portfolios.activity_by_account.get(&account_id) {
+            Some(activity) => activity,
+            None => return vec![],
+        };
+
+        activity
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit as usize)
+            .map(|(token_id, last_transfer_timestamp)| {
+                let balance = self
+                    .balances_per_token
+                    .get(&token_id)
+                    .and_then(|balances| balances.get(&account_id))
+                    .unwrap_or(0);
+                let metadata = self.token_metadata_by_id.as_ref().and_then(|by_id| by_id.get(&token_id));
+
+                PortfolioEntry {
+                    balance: balance.into(),
+                    title: metadata.as_ref().and_then(|metadata| metadata.title.clone()),
+                    media: metadata.and_then(|metadata| metadata.media),
+                    last_transfer_timestamp,
+                    token_id,
+                }
+            })
+            .collect()
+    }
+}
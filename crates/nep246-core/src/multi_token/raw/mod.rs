@@ -0,0 +1,33 @@
+/*! Borsh-encoded views for indexers that want to skip JSON serialization.
+
+Arguments are still passed as JSON, only results are encoded with Borsh.
+Byte layouts of results:
+* `u128` - 16 bytes, little-endian
+* `Vec<T>` - `u32` little-endian length followed by each element
+* `Option<T>` - `0u8` for `None`, `1u8` followed by `T` for `Some`
+* `String`, `AccountId` - `u32` little-endian length followed by UTF-8 bytes
+* [Token](crate::multi_token::token::Token) - fields in order of declaration
+ */
+
+mod raw_impl;
+
+use crate::multi_token::token::{Token, TokenId};
+use near_sdk::AccountId;
+
+/// Views returning Borsh-encoded results, enabled by `raw-views` feature
+pub trait MultiTokenRawViews {
+    /// Same as `mt_balance_of`, but balances are in order of `token_ids`
+    ///
+    /// returns: `Vec<u128>`, 0 for accounts that are not registered in token
+    fn mt_balance_of_raw(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<u128>;
+
+    /// Total supply of each token in order of `token_ids`
+    ///
+    /// returns: `Vec<u128>`, 0 for tokens that does not exist
+    fn mt_supply_raw(&self, token_ids: Vec<TokenId>) -> Vec<u128>;
+
+    /// Same as `mt_token`
+    ///
+    /// returns: `Option<Token>`
+    fn mt_token_raw(&self, token_id: TokenId) -> Option<Token>;
+}
@@ -0,0 +1,30 @@
+use near_sdk::AccountId;
+
+use crate::multi_token::{
+    core::{MultiToken, MultiTokenCore},
+    token::{Token, TokenId},
+};
+
+use super::MultiTokenRawViews;
+
+impl MultiTokenRawViews for MultiToken {
+    fn mt_balance_of_raw(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<u128> {
+        token_ids
+            .iter()
+            .map(|token_id| {
+                self.balances_per_token
+                    .get(token_id)
+                    .and_then(|balances| balances.get(&account_id))
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn mt_supply_raw(&self, token_ids: Vec<TokenId>) -> Vec<u128> {
+        token_ids.iter().map(|token_id| self.total_supply.get(token_id).unwrap_or(0)).collect()
+    }
+
+    fn mt_token_raw(&self, token_id: TokenId) -> Option<Token> {
+        self.mt_token(token_id)
+    }
+}
@@ -0,0 +1,38 @@
+/*! Recovery of tokens sent to the contract by mistake
+
+Contract owner can send FTs and tokens of other multi token contracts held by the contract
+to any account. Receivers that refund all unsolicited deposits are provided by
+`impl_multi_token_rescue!` of `nep-246`, so most mistakes are reverted without owner's help.
+ */
+
+mod rescue_impl;
+
+pub use rescue_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::{AccountId, Promise, PromiseOrValue};
+
+pub trait MultiTokenRescue {
+    /// Transfer FTs held by the contract
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn rescue_ft(&mut self, contract_id: AccountId, receiver_id: AccountId, amount: Amount) -> Promise;
+
+    /// Transfer tokens of other multi token contract held by the contract
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if `contract_id` is the contract itself, own tokens are moved with regular transfers
+    fn rescue_mt(&mut self, contract_id: AccountId, token_ids: Vec<TokenId>, amounts: Vec<Amount>, receiver_id: AccountId) -> Promise;
+}
+
+/// Receiver of FTs, see [NEP-141](https://github.com/near/NEPs/blob/master/neps/nep-0141.md)
+pub trait FungibleTokenReceiver {
+    /// Returns amount of unused tokens that FT contract refunds to sender
+    fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128>;
+}
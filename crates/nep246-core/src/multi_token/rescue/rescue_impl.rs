@@ -0,0 +1,59 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, require, AccountId, Balance, Promise};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::assert_batch_lengths,
+    json_types::Amount,
+    token::TokenId,
+    utils::unauthorized_assert,
+};
+
+use super::MultiTokenRescue;
+
+pub use crate::multi_token::gas::GAS_FOR_RESCUE;
+
+/// Deposit required by `ft_transfer` and `mt_batch_transfer` of other contracts
+const ONE_YOCTO: Balance = 1;
+
+#[ext_contract(ext_ft)]
+pub trait FungibleToken {
+    fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
+
+#[ext_contract(ext_mt)]
+pub trait ForeignMultiToken {
+    fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        memo: Option<String>,
+    );
+}
+
+impl MultiTokenRescue for MultiToken {
+    fn rescue_ft(&mut self, contract_id: AccountId, receiver_id: AccountId, amount: Amount) -> Promise {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+
+        ext_ft::ft_transfer(receiver_id, amount.into(), Some("rescue".to_string()), contract_id, ONE_YOCTO, GAS_FOR_RESCUE)
+    }
+
+    fn rescue_mt(&mut self, contract_id: AccountId, token_ids: Vec<TokenId>, amounts: Vec<Amount>, receiver_id: AccountId) -> Promise {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+        require!(contract_id != env::current_account_id(), "Own tokens can't be rescued");
+        assert_batch_lengths(token_ids.len(), &[amounts.len()]);
+
+        ext_mt::mt_batch_transfer(
+            receiver_id,
+            token_ids,
+            amounts.into_iter().map(U128::from).collect(),
+            Some("rescue".to_string()),
+            contract_id,
+            ONE_YOCTO,
+            GAS_FOR_RESCUE,
+        )
+    }
+}
@@ -0,0 +1,38 @@
+//! Deposit checks shared by all state-changing methods.
+//!
+//! Attached deposit proves that call was signed with a full access key, so function call
+//! keys given to dapps can't move tokens. Methods that don't store anything require exactly
+//! the minimum, methods that pay for storage require at least the minimum.
+
+use near_sdk::{env, require, Balance};
+
+use crate::multi_token::{core::MultiToken, utils::unauthorized_assert};
+
+/// Minimum deposit of state-changing methods unless changed by contract owner
+pub const DEFAULT_MIN_CALL_DEPOSIT: Balance = 1;
+
+impl MultiToken {
+    /// Change deposit required by state-changing methods. Can only be called by contract owner
+    pub fn set_min_call_deposit(&mut self, deposit: Balance) {
+        unauthorized_assert(&self.owner_id);
+        require!(deposit >= 1, "Minimum deposit cannot be 0");
+
+        self.min_call_deposit = deposit;
+    }
+
+    /// For methods that don't store new data: attached deposit must equal the minimum
+    pub fn assert_call_deposit(&self) {
+        require!(
+            env::attached_deposit() == self.min_call_deposit,
+            format!("Requires attached deposit of exactly {} yoctoNEAR", self.min_call_deposit)
+        );
+    }
+
+    /// For methods paying for storage from attached deposit: it must be at least the minimum
+    pub fn assert_storage_call_deposit(&self) {
+        require!(
+            env::attached_deposit() >= self.min_call_deposit,
+            format!("Requires attached deposit of at least {} yoctoNEAR", self.min_call_deposit)
+        );
+    }
+}
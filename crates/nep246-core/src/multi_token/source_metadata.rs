@@ -0,0 +1,53 @@
+//! Contract source metadata, see [NEP-330](https://github.com/near/NEPs/blob/master/neps/nep-0330.md).
+//!
+//! Metadata is filled at build time of the contract by `impl_contract_source_metadata!` of `nep-246`:
+//! version and link are taken from `Cargo.toml` of the contract, commit hash from `NEP330_COMMIT`
+//! environment variable if it's set during build.
+
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Version of NEP-246 implemented by this crate
+pub const NEP246_VERSION: &str = "1.0.0";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Standard {
+    pub standard: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractSourceMetadata {
+    /// Version of contract, usually version of its crate
+    pub version: Option<String>,
+    /// Commit the contract was built from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_hash: Option<String>,
+    /// Link to source code of contract
+    pub link: Option<String>,
+    /// Standards implemented by contract
+    pub standards: Vec<Standard>,
+}
+
+impl ContractSourceMetadata {
+    /// Metadata with NEP-246 and NEP-330 in list of standards, empty strings are treated as missing
+    pub fn new(version: &str, commit_hash: Option<&str>, link: &str) -> Self {
+        let non_empty = |value: &str| Some(value.to_string()).filter(|value| !value.is_empty());
+
+        Self {
+            version: non_empty(version),
+            commit_hash: commit_hash.and_then(non_empty),
+            link: non_empty(link),
+            standards: vec![
+                Standard { standard: "nep246".to_string(), version: NEP246_VERSION.to_string() },
+                Standard { standard: "nep330".to_string(), version: "1.1.0".to_string() },
+            ],
+        }
+    }
+}
+
+pub trait ContractSourceMetadataView {
+    /// Get source metadata of contract
+    fn contract_source_metadata(&self) -> ContractSourceMetadata;
+}
@@ -0,0 +1,63 @@
+/*! Staking of tokens in the contract itself
+
+Staked tokens are held by the contract account, so they can't be moved by holder until
+unstaked. If cooldown is configured, unstaked tokens become available only after it passes
+and are claimed with `mt_withdraw_unstaked`.
+ */
+
+mod staking_impl;
+
+pub use staking_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Stake of account in a single token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StakeInfo {
+    /// Amount counted for rewards
+    pub staked: U128,
+    /// Amount waiting for cooldown
+    pub unstaking: U128,
+    /// Block timestamp in nanoseconds when `unstaking` can be withdrawn
+    pub available_at: u64,
+}
+
+// `U128` doesn't implement `Default`
+impl Default for StakeInfo {
+    fn default() -> Self {
+        Self { staked: U128(0), unstaking: U128(0), available_at: 0 }
+    }
+}
+
+pub trait MultiTokenStaking {
+    /// Lock tokens of caller in contract
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage, unused part is refunded
+    fn mt_stake(&mut self, token_id: TokenId, amount: Amount);
+
+    /// Unlock staked tokens. Without cooldown they're returned immediately,
+    /// otherwise they're added to unstaking amount and cooldown is restarted
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    fn mt_unstake(&mut self, token_id: TokenId, amount: Amount);
+
+    /// Return unstaked tokens to caller once cooldown passed
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    fn mt_withdraw_unstaked(&mut self, token_id: TokenId);
+
+    /// Get amount of token staked by account, not including unstaking amount
+    fn mt_staked_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128;
+
+    /// Get full stake info of account in token
+    fn mt_stake_info(&self, account_id: AccountId, token_id: TokenId) -> Option<StakeInfo>;
+}
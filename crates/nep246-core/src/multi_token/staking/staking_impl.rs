@@ -0,0 +1,110 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    json_types::Amount,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{MultiTokenStaking, StakeInfo};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Staking {
+    /// Nanoseconds between unstake and withdrawal, 0 to return tokens immediately
+    pub cooldown: u64,
+    pub stakes: LookupMap<(AccountId, TokenId), StakeInfo>,
+}
+
+impl MultiToken {
+    /// Allow holders to stake tokens in contract
+    pub fn enable_staking<P: IntoStorageKey>(&mut self, prefix: P, cooldown: u64) {
+        self.staking = Some(Staking { cooldown, stakes: LookupMap::new(prefix) });
+    }
+
+    fn internal_save_stake(&mut self, key: &(AccountId, TokenId), stake: &StakeInfo) {
+        let staking = self.staking.as_mut().unwrap();
+        if stake.staked.0 == 0 && stake.unstaking.0 == 0 {
+            staking.stakes.remove(key);
+        } else {
+            staking.stakes.insert(key, stake);
+        }
+    }
+}
+
+impl MultiTokenStaking for MultiToken {
+    fn mt_stake(&mut self, token_id: TokenId, amount: Amount) {
+        self.assert_storage_call_deposit();
+        if amount.0 == 0 {
+            MtError::ZeroAmount.panic();
+        }
+
+        let account_id = env::predecessor_account_id();
+        let key = (account_id.clone(), token_id.clone());
+        let mut stake = expect_extension(self.staking.as_ref(), "Staking", Entity::Contract).stakes.get(&key).unwrap_or_default();
+
+        let escrow_id = env::current_account_id();
+        self.assert_can_move(&token_id, &account_id, &escrow_id, amount.0);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        self.internal_move(&token_id, &account_id, &escrow_id, amount.0, "stake");
+        stake.staked = U128(stake.staked.0 + amount.0);
+        self.internal_save_stake(&key, &stake);
+        tracker.stop();
+        tracker.refund(account_id);
+    }
+
+    fn mt_unstake(&mut self, token_id: TokenId, amount: Amount) {
+        self.assert_call_deposit();
+        if amount.0 == 0 {
+            MtError::ZeroAmount.panic();
+        }
+
+        let account_id = env::predecessor_account_id();
+        let key = (account_id.clone(), token_id.clone());
+        let staking = expect_extension(self.staking.as_ref(), "Staking", Entity::Contract);
+        let cooldown = staking.cooldown;
+        let mut stake = staking.stakes.get(&key).expect("Nothing is staked");
+
+        require!(stake.staked.0 >= amount.0, "Not enough staked tokens");
+        stake.staked = U128(stake.staked.0 - amount.0);
+
+        if cooldown == 0 {
+            self.internal_move(&token_id, &env::current_account_id(), &account_id, amount.0, "unstake");
+        } else {
+            stake.unstaking = U128(stake.unstaking.0 + amount.0);
+            stake.available_at = env::block_timestamp() + cooldown;
+        }
+        self.internal_save_stake(&key, &stake);
+    }
+
+    fn mt_withdraw_unstaked(&mut self, token_id: TokenId) {
+        self.assert_call_deposit();
+
+        let account_id = env::predecessor_account_id();
+        let key = (account_id.clone(), token_id.clone());
+        let mut stake =
+            expect_extension(self.staking.as_ref(), "Staking", Entity::Contract).stakes.get(&key).expect("Nothing is staked");
+
+        require!(stake.unstaking.0 > 0, "Nothing to withdraw");
+        require!(env::block_timestamp() >= stake.available_at, "Cooldown has not passed yet");
+
+        let amount = stake.unstaking.0;
+        stake.unstaking = U128(0);
+        self.internal_save_stake(&key, &stake);
+        self.internal_move(&token_id, &env::current_account_id(), &account_id, amount, "unstake");
+    }
+
+    fn mt_staked_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128 {
+        self.mt_stake_info(account_id, token_id).map_or(U128(0), |stake| stake.staked)
+    }
+
+    fn mt_stake_info(&self, account_id: AccountId, token_id: TokenId) -> Option<StakeInfo> {
+        expect_extension(self.staking.as_ref(), "Staking", Entity::Contract).stakes.get(&(account_id, token_id))
+    }
+}
@@ -0,0 +1,95 @@
+mod state_impl;
+
+use crate::multi_token::metadata::TokenMetadata;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base64VecU8, U128};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+/// Everything that is needed to recreate single token in another contract
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenState {
+    pub token_id: TokenId,
+    pub owner_id: AccountId,
+    pub supply: U128,
+    pub metadata: Option<TokenMetadata>,
+    /// Balances of all accounts registered in token
+    pub balances: Vec<(AccountId, U128)>,
+}
+
+/// Page of exported state
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StateChunk {
+    /// Index of the first token in this chunk
+    pub from_index: u64,
+    pub tokens: Vec<TokenState>,
+    /// sha256 of borsh-serialized `tokens`, used to verify chunk on import
+    pub hash: Base64VecU8,
+}
+
+/// Export & import of contract state, used to migrate tokens from one deployment to another
+pub trait MultiTokenState {
+    /// Get a page of contract state
+    ///
+    /// # Arguments:
+    /// * `from_index` - Index of token to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of tokens to return
+    ///
+    /// returns: [StateChunk] with tokens, their balances and hash of the chunk
+    fn mt_export_state(&self, from_index: Option<u64>, limit: u64) -> StateChunk;
+
+    /// Import chunk previously returned by `mt_export_state`
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if hash of the chunk does not match its content
+    /// * Contract MUST panic if any of the tokens already exists
+    ///
+    /// # Arguments:
+    /// * `chunk` - chunk to import
+    fn mt_import_state(&mut self, chunk: StateChunk);
+
+    /// Append accounts to the list of candidate holders used by `mt_rebuild_holder_index`.
+    /// Balances are stored in `LookupMap`, which can't be enumerated on-chain, so accounts which may
+    /// hold tokens minted before the holder index existed are uploaded first (e.g. collected from `mt_transfer` events).
+    /// Attached deposit covers storage of the list, unused part is refunded.
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if more than [MAX_REBUILD_ACCOUNTS] accounts are passed
+    ///
+    /// # Arguments:
+    /// * `account_ids` - accounts to append
+    ///
+    /// returns: total number of candidates
+    fn mt_add_holder_candidates(&mut self, account_ids: Vec<AccountId>) -> u64;
+
+    /// Backfill index of token holders for tokens minted before the index existed. Checks page of
+    /// candidates added with `mt_add_holder_candidates` against balances of the token, accounts
+    /// without balance entry in token are skipped. Call repeatedly with next `from_index` until it
+    /// reaches number of candidates, then move on to the next token.
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    /// * Contract MUST panic if `limit` is more than [MAX_REBUILD_ACCOUNTS]
+    ///
+    /// # Arguments:
+    /// * `token_id` - ID of token
+    /// * `from_index` - Index of candidate to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of candidates to check
+    ///
+    /// returns: number of accounts added to the index
+    fn mt_rebuild_holder_index(&mut self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> u64;
+
+    /// Remove all candidates once every token is rebuilt, releasing their storage
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn mt_clear_holder_candidates(&mut self);
+}
+
+/// Maximum number of accounts processed in a single `mt_add_holder_candidates` or `mt_rebuild_holder_index` call
+pub const MAX_REBUILD_ACCOUNTS: usize = 100;
@@ -0,0 +1,177 @@
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::collections::{LazyOption, LookupMap, TreeMap, UnorderedSet, Vector};
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId};
+
+use crate::multi_token::{
+    core::{MultiToken, StorageKey},
+    token::TokenId,
+    utils::{unauthorized_assert, StorageTracker},
+};
+
+use super::{MultiTokenState, StateChunk, TokenState, MAX_REBUILD_ACCOUNTS};
+
+fn hash_tokens(tokens: &[TokenState]) -> Vec<u8> {
+    env::sha256(&tokens.try_to_vec().unwrap_or_else(|_| env::abort()))
+}
+
+/// Candidate holders are kept outside of root state, they are only needed while holder index is rebuilt
+fn holder_candidates() -> LazyOption<Vector<AccountId>> {
+    LazyOption::new(StorageKey::HolderCandidates, None)
+}
+
+impl MultiToken {
+    fn export_token(&self, token_id: TokenId, owner_id: AccountId) -> TokenState {
+        let supply = self.total_supply.get(&token_id).unwrap_or(0);
+        let metadata = self.token_metadata_by_id.as_ref().and_then(|by_id| by_id.get(&token_id));
+        let balances = match (self.holders_per_token.get(&token_id), self.balances_per_token.get(&token_id)) {
+            (Some(holders), Some(balances)) => holders
+                .iter()
+                .map(|account_id| {
+                    let balance = balances.get(&account_id).unwrap_or(0);
+                    (account_id, U128(balance))
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        TokenState { token_id, owner_id, supply: U128(supply), metadata, balances }
+    }
+
+    fn import_token(&mut self, token: TokenState) {
+        let TokenState { token_id, owner_id, supply, metadata, balances } = token;
+
+        require!(self.owner_by_id.get(&token_id).is_none(), "Token already exists");
+        let held = balances
+            .iter()
+            .try_fold(0u128, |sum, (_, balance)| sum.checked_add(balance.0))
+            .unwrap_or_else(|| env::panic_str("Balance overflow"));
+        require!(held == supply.0, "Balances don't add up to total supply");
+
+        self.owner_by_id.insert(&token_id, &owner_id);
+        self.total_supply.insert(&token_id, &supply.0);
+
+        if let (Some(by_id), Some(metadata)) = (self.token_metadata_by_id.as_mut(), metadata) {
+            by_id.insert(&token_id, &metadata);
+        }
+
+        self.next_approval_id_by_id
+            .as_mut()
+            .and_then(|internal| internal.insert(&token_id, &0));
+
+        let mut new_set: LookupMap<AccountId, u128> =
+            LookupMap::new(StorageKey::BalancesInner { token_id: env::sha256(token_id.as_bytes()) });
+        for (account_id, balance) in balances.iter() {
+            new_set.insert(account_id, &balance.0);
+        }
+        self.balances_per_token.insert(&token_id, &new_set);
+
+        for (account_id, _) in balances.iter() {
+            self.internal_add_holder(&token_id, account_id);
+        }
+
+        if let Some(per_owner) = &mut self.tokens_per_owner {
+            let mut token_ids = per_owner.get(&owner_id).unwrap_or_else(|| {
+                TreeMap::new(StorageKey::TokensPerOwner {
+                    account_hash: env::sha256(owner_id.as_bytes()),
+                })
+            });
+            token_ids.insert(&token_id, &());
+            per_owner.insert(&owner_id, &token_ids);
+        }
+
+        // Keep ids of newly minted tokens from colliding with imported ones
+        if let Ok(id) = token_id.parse::<u64>() {
+            let next = id.checked_add(1).unwrap_or_else(|| env::panic_str("Token id overflow"));
+            self.next_token_id = std::cmp::max(self.next_token_id, next);
+        }
+    }
+}
+
+impl MultiTokenState for MultiToken {
+    fn mt_export_state(&self, from_index: Option<u64>, limit: u64) -> StateChunk {
+        let from_index = from_index.unwrap_or(0);
+
+        require!(limit != 0, "Limit cannot be 0");
+
+        let tokens: Vec<TokenState> = self
+            .owner_by_id
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|(token_id, owner_id)| self.export_token(token_id, owner_id))
+            .collect();
+
+        let hash = hash_tokens(&tokens).into();
+
+        StateChunk { from_index, tokens, hash }
+    }
+
+    fn mt_import_state(&mut self, chunk: StateChunk) {
+        unauthorized_assert(&self.owner_id);
+        self.assert_call_deposit();
+
+        require!(hash_tokens(&chunk.tokens) == chunk.hash.0, "Chunk hash mismatch");
+
+        chunk.tokens.into_iter().for_each(|token| self.import_token(token));
+    }
+
+    fn mt_add_holder_candidates(&mut self, account_ids: Vec<AccountId>) -> u64 {
+        unauthorized_assert(&self.owner_id);
+        self.assert_storage_call_deposit();
+
+        require!(account_ids.len() <= MAX_REBUILD_ACCOUNTS, "Too many accounts");
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+
+        let mut candidates = holder_candidates();
+        let mut list = candidates.get().unwrap_or_else(|| Vector::new(StorageKey::HolderCandidatesInner));
+        list.extend(account_ids);
+        candidates.set(&list);
+
+        tracker.stop();
+        tracker.refund(env::predecessor_account_id());
+
+        list.len()
+    }
+
+    fn mt_rebuild_holder_index(&mut self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> u64 {
+        unauthorized_assert(&self.owner_id);
+        self.assert_call_deposit();
+
+        require!(limit <= MAX_REBUILD_ACCOUNTS as u64, "Too many accounts");
+
+        let balances = self.balances_per_token.get(&token_id).expect("This token does not exist");
+        let candidates = match holder_candidates().get() {
+            Some(candidates) => candidates,
+            None => return 0,
+        };
+        let mut holders = self.holders_per_token.get(&token_id).unwrap_or_else(|| {
+            UnorderedSet::new(StorageKey::HoldersPerTokenInner { token_id: env::sha256(token_id.as_bytes()) })
+        });
+
+        let added = candidates
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(limit as usize)
+            .filter(|account_id| balances.contains_key(account_id))
+            .filter(|account_id| holders.insert(account_id))
+            .count();
+
+        self.holders_per_token.insert(&token_id, &holders);
+
+        added as u64
+    }
+
+    fn mt_clear_holder_candidates(&mut self) {
+        unauthorized_assert(&self.owner_id);
+        self.assert_call_deposit();
+
+        let mut candidates = holder_candidates();
+        if let Some(mut list) = candidates.get() {
+            list.clear();
+            candidates.remove();
+        }
+    }
+}
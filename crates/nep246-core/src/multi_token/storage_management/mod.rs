@@ -0,0 +1,80 @@
+/*! Storage management ([NEP-145](https://nomicon.io/Standards/StorageManagement)) with per-token registration
+
+Account deposits storage once with `storage_deposit` and is then charged only for records of tokens
+it registers in with `mt_storage_register`, at the actual cost of the records. Unregistering from
+a token with zero balance releases its cost back to available balance, which can be withdrawn.
+
+`storage_deposit` is idempotent: deposit of registered account with `registration_only` is refunded
+in full, and registration of new account with `registration_only` keeps only the minimum.
+ */
+
+mod storage_management_impl;
+
+pub use storage_management_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalance {
+    pub total: U128,
+    /// Part of `total` not used by records of account
+    pub available: U128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalanceBounds {
+    /// Deposit required to register account
+    pub min: U128,
+    /// There is no maximum, accounts can register in any number of tokens
+    pub max: Option<U128>,
+}
+
+pub trait StorageManagement {
+    /// Deposit storage for `account_id`, caller if not given
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if account is not registered and deposit is below minimum
+    /// * With `registration_only` contract MUST refund deposit above minimum, whole deposit
+    ///   if account is already registered
+    fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance;
+
+    /// Withdraw `amount` of available balance of caller, all of it if not given
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if amount exceeds available balance
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance;
+
+    /// Unregister caller and refund its storage balance. Returns `false` if caller was not registered
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is still registered in any token. `force` is not supported,
+    ///   balances in tokens are never burned implicitly
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool;
+
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds;
+
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
+
+    /// Register `account_id` (caller if not given) in tokens, paid from its storage balance.
+    /// Attached deposit is added to the balance first. Tokens account is registered in are skipped
+    ///
+    /// # Requirements:
+    /// * Account must be registered with `storage_deposit`
+    /// * Contract MUST panic if available balance doesn't cover new records
+    /// * Caller other than `account_id` must attach deposit covering all new records
+    fn mt_storage_register(&mut self, token_ids: Vec<TokenId>, account_id: Option<AccountId>) -> StorageBalance;
+
+    /// Unregister caller from tokens where it has zero balance, releasing their cost
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller has balance in any of tokens
+    fn mt_storage_unregister(&mut self, token_ids: Vec<TokenId>) -> StorageBalance;
+}
@@ -0,0 +1,220 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId, Balance, IntoStorageKey, Promise, StorageUsage};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{StorageBalance, StorageBalanceBounds, StorageManagement};
+
+/// Storage deposit of account and the part of it taken by records of the account
+#[derive(Debug, Clone, Copy, Default, BorshDeserialize, BorshSerialize)]
+pub struct AccountStorage {
+    pub total: Balance,
+    pub used: Balance,
+}
+
+impl AccountStorage {
+    fn available(&self) -> Balance {
+        self.total - self.used
+    }
+
+    fn to_view(self) -> StorageBalance {
+        StorageBalance { total: U128(self.total), available: U128(self.available()) }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct StorageAccounts {
+    pub storage_by_account: LookupMap<AccountId, AccountStorage>,
+    /// Bytes of `storage_by_account` record of account with the longest ID
+    pub account_storage_usage: StorageUsage,
+}
+
+impl MultiToken {
+    /// Require accounts to pay for their records in tokens through storage deposit
+    pub fn enable_storage_management<P: IntoStorageKey>(&mut self, prefix: P) {
+        let mut storage_by_account = LookupMap::new(prefix);
+        let initial = env::storage_usage();
+        let longest_id: AccountId = "a".repeat(64).parse().unwrap();
+        storage_by_account.insert(&longest_id, &AccountStorage::default());
+        let account_storage_usage = env::storage_usage() - initial;
+        storage_by_account.remove(&longest_id);
+
+        self.storage_accounts = Some(StorageAccounts { storage_by_account, account_storage_usage });
+    }
+
+    fn internal_storage_min(&self) -> Balance {
+        let management = expect_extension(self.storage_accounts.as_ref(), "Storage management", Entity::Contract);
+        Balance::from(management.account_storage_usage) * env::storage_byte_cost()
+    }
+
+    fn internal_account_storage(&self, account_id: &AccountId) -> Option<AccountStorage> {
+        expect_extension(self.storage_accounts.as_ref(), "Storage management", Entity::Contract).storage_by_account.get(account_id)
+    }
+
+    fn internal_set_account_storage(&mut self, account_id: &AccountId, storage: &AccountStorage) {
+        expect_extension(self.storage_accounts.as_mut(), "Storage management", Entity::Contract).storage_by_account.insert(account_id, storage);
+    }
+
+    /// Register account with minimal storage balance paid by someone else, e.g. minter airdropping
+    /// tokens to it. Returns amount to charge the payer, 0 if account is already registered
+    /// or contract doesn't manage storage
+    pub(crate) fn internal_storage_register_paid(&mut self, account_id: &AccountId) -> Balance {
+        if self.storage_accounts.is_none() || self.internal_account_storage(account_id).is_some() {
+            return 0;
+        }
+        let min = self.internal_storage_min();
+        self.internal_set_account_storage(account_id, &AccountStorage { total: min, used: min });
+        min
+    }
+
+    /// Give storage of `bytes` released by records of account back to its storage balance.
+    /// Records could be paid by someone else, e.g. created on mint, so it never goes below minimum
+    pub(crate) fn internal_storage_release(&mut self, account_id: &AccountId, bytes: StorageUsage) {
+        if self.storage_accounts.is_none() {
+            return;
+        }
+        if let Some(mut storage) = self.internal_account_storage(account_id) {
+            let released = Balance::from(bytes) * env::storage_byte_cost();
+            storage.used = storage.used.saturating_sub(released).max(self.internal_storage_min());
+            self.internal_set_account_storage(account_id, &storage);
+        }
+    }
+
+    fn internal_expect_account_storage(&self, account_id: &AccountId) -> AccountStorage {
+        self.internal_account_storage(account_id).unwrap_or_else(|| MtError::NotRegistered(account_id.clone()).panic())
+    }
+}
+
+impl StorageManagement for MultiToken {
+    fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let registration_only = registration_only.unwrap_or(false);
+
+        let (storage, refund) = match self.internal_account_storage(&account_id) {
+            Some(storage) if registration_only => (storage, amount),
+            Some(mut storage) => {
+                storage.total += amount;
+                (storage, 0)
+            }
+            None => {
+                let min = self.internal_storage_min();
+                require!(amount >= min, "The attached deposit is less than the minimum storage balance");
+                let total = if registration_only { min } else { amount };
+                (AccountStorage { total, used: min }, amount - total)
+            }
+        };
+
+        self.internal_set_account_storage(&account_id, &storage);
+        if refund > 0 {
+            Promise::new(env::predecessor_account_id()).transfer(refund);
+        }
+
+        storage.to_view()
+    }
+
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        self.assert_call_deposit();
+        let account_id = env::predecessor_account_id();
+        let mut storage = self.internal_expect_account_storage(&account_id);
+
+        let amount = amount.map_or(storage.available(), |amount| amount.0);
+        require!(amount <= storage.available(), "The amount is greater than the available storage balance");
+        storage.total -= amount;
+        self.internal_set_account_storage(&account_id, &storage);
+        if amount > 0 {
+            Promise::new(account_id).transfer(amount);
+        }
+
+        storage.to_view()
+    }
+
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        self.assert_call_deposit();
+        require!(!force.unwrap_or(false), "Force unregister is not supported, unregister from tokens first");
+        let account_id = env::predecessor_account_id();
+
+        let storage = match self.internal_account_storage(&account_id) {
+            Some(storage) => storage,
+            None => return false,
+        };
+        require!(storage.used == self.internal_storage_min(), "Account is still registered in tokens");
+
+        expect_extension(self.storage_accounts.as_mut(), "Storage management", Entity::Contract).storage_by_account.remove(&account_id);
+        Promise::new(account_id).transfer(storage.total);
+        true
+    }
+
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds { min: U128(self.internal_storage_min()), max: None }
+    }
+
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.internal_account_storage(&account_id).map(AccountStorage::to_view)
+    }
+
+    fn mt_storage_register(&mut self, token_ids: Vec<TokenId>, account_id: Option<AccountId>) -> StorageBalance {
+        require!(!token_ids.is_empty(), "Nothing to register");
+        let caller_id = env::predecessor_account_id();
+        let account_id = account_id.unwrap_or_else(|| caller_id.clone());
+        let attached = env::attached_deposit();
+        let mut storage = self.internal_expect_account_storage(&account_id);
+        storage.total += attached;
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        for token_id in token_ids.iter() {
+            let registered = self
+                .balances_per_token
+                .get(token_id)
+                .unwrap_or_else(|| MtError::TokenNotFound.panic())
+                .contains_key(&account_id);
+            if !registered {
+                self.internal_register_account(token_id, &account_id);
+            }
+        }
+        tracker.stop();
+
+        let cost = Balance::from(tracker.bytes_added) * env::storage_byte_cost();
+        if caller_id != account_id {
+            require!(attached >= cost, "Attached deposit must cover registration of another account");
+        }
+        require!(cost <= storage.available(), "Not enough storage balance, deposit more with storage_deposit");
+        storage.used += cost;
+        self.internal_set_account_storage(&account_id, &storage);
+
+        storage.to_view()
+    }
+
+    fn mt_storage_unregister(&mut self, token_ids: Vec<TokenId>) -> StorageBalance {
+        self.assert_call_deposit();
+        let account_id = env::predecessor_account_id();
+        self.internal_expect_account_storage(&account_id);
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        for token_id in token_ids.iter() {
+            let mut balances = self.balances_per_token.get(token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+            match balances.get(&account_id) {
+                Some(0) => {}
+                Some(_) => env::panic_str("Cannot unregister from token with positive balance"),
+                None => continue,
+            }
+            self.assert_not_dispute_locked(&account_id, token_id);
+            balances.remove(&account_id);
+            self.internal_remove_holder(token_id, &account_id);
+        }
+        tracker.stop();
+
+        self.internal_storage_release(&account_id, tracker.bytes_released);
+
+        self.internal_expect_account_storage(&account_id).to_view()
+    }
+}
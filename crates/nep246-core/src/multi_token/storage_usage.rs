@@ -0,0 +1,126 @@
+//! Storage attributable to a single account.
+//!
+//! Sizes are estimated from layout of collections, the same way the runtime charges them:
+//! key, value and fixed overhead of every record. Tokens of account are taken from portfolio
+//! extension if it's used, otherwise only tokens created for the account are counted.
+
+use near_sdk::json_types::U64;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, StorageUsage};
+
+use crate::multi_token::{
+    core::MultiToken,
+    token::TokenId,
+    utils::bytes_for_approved_account_id,
+};
+
+/// Bytes the runtime charges for every record on top of its key and value
+pub const STORAGE_RECORD_OVERHEAD: StorageUsage = 40;
+
+/// Prefix of per-token collections: enum tag, length and sha256 of token id
+const PER_TOKEN_PREFIX_LEN: StorageUsage = 1 + 4 + 32;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountStorageUsage {
+    /// Balance records and holder entries in every token of account
+    pub balances: U64,
+    /// Approvals granted by account
+    pub approvals: U64,
+    /// Entries of account in enumeration and portfolio indexes
+    pub enumeration: U64,
+    pub total: U64,
+}
+
+pub trait MultiTokenStorageUsage {
+    /// Get bytes of contract storage attributable to account
+    fn mt_storage_usage_of(&self, account_id: AccountId) -> AccountStorageUsage;
+}
+
+fn record(key_len: StorageUsage, value_len: StorageUsage) -> StorageUsage {
+    key_len + value_len + STORAGE_RECORD_OVERHEAD
+}
+
+/// Borsh size of account id
+fn account_len(account_id: &AccountId) -> StorageUsage {
+    4 + account_id.as_str().len() as StorageUsage
+}
+
+/// Borsh size of token id
+fn token_len(token_id: &TokenId) -> StorageUsage {
+    4 + token_id.len() as StorageUsage
+}
+
+/// Entry of `UnorderedSet`: element in vector and its index in map
+fn set_entry(prefix_len: StorageUsage, element_len: StorageUsage) -> StorageUsage {
+    record(prefix_len + 1 + 8, element_len) + record(prefix_len + 1 + element_len, 8)
+}
+
+/// Entry of `TreeMap` with unit values: empty value in map and tree node with key, links and height
+fn tree_entry(prefix_len: StorageUsage, key_len: StorageUsage) -> StorageUsage {
+    record(prefix_len + 1 + key_len, 0) + record(prefix_len + 1 + 8, 8 + key_len + 9 + 9 + 8)
+}
+
+impl MultiToken {
+    /// Tokens account holds balance in, or tokens created for it if portfolios are not tracked
+    fn internal_tokens_of(&self, account_id: &AccountId) -> Vec<TokenId> {
+        if let Some(portfolios) = self.portfolios.as_ref() {
+            return portfolios
+                .activity_by_account
+                .get(account_id)
+                .map(|activity| activity.keys_as_vector().to_vec())
+                .unwrap_or_default();
+        }
+        self.tokens_per_owner
+            .as_ref()
+            .and_then(|per_owner| per_owner.get(account_id))
+            .map(|tokens| tokens.iter().map(|(token_id, _)| token_id).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn internal_storage_usage_of(&self, account_id: &AccountId) -> AccountStorageUsage {
+        let tokens = self.internal_tokens_of(account_id);
+        let account = account_len(account_id);
+
+        let balances: StorageUsage = tokens
+            .iter()
+            .filter(|token_id| {
+                self.balances_per_token.get(token_id).map_or(false, |balances| balances.contains_key(account_id))
+            })
+            .map(|_| record(PER_TOKEN_PREFIX_LEN + account, 16) + set_entry(PER_TOKEN_PREFIX_LEN, account))
+            .sum();
+
+        let approvals: StorageUsage = tokens
+            .iter()
+            .filter(|token_id| self.owner_by_id.get(token_id).as_ref() == Some(account_id))
+            .filter_map(|token_id| self.approvals_by_id.as_ref().and_then(|by_id| by_id.get(token_id)))
+            .flat_map(|approvals| approvals.into_iter())
+            .map(|(grantee_id, approval)| bytes_for_approved_account_id(&grantee_id, &approval))
+            .sum();
+
+        let created: StorageUsage = self
+            .tokens_per_owner
+            .as_ref()
+            .and_then(|per_owner| per_owner.get(account_id))
+            .map(|tokens| tokens.iter().map(|(token_id, _)| tree_entry(PER_TOKEN_PREFIX_LEN, token_len(&token_id))).sum())
+            .unwrap_or(0);
+        let tracked: StorageUsage = match self.portfolios.as_ref() {
+            Some(_) => tokens.iter().map(|token_id| set_entry(PER_TOKEN_PREFIX_LEN, token_len(token_id) + 8)).sum(),
+            None => 0,
+        };
+        let enumeration: StorageUsage = created + tracked;
+
+        AccountStorageUsage {
+            balances: balances.into(),
+            approvals: approvals.into(),
+            enumeration: enumeration.into(),
+            total: (balances + approvals + enumeration).into(),
+        }
+    }
+}
+
+impl MultiTokenStorageUsage for MultiToken {
+    fn mt_storage_usage_of(&self, account_id: AccountId) -> AccountStorageUsage {
+        self.internal_storage_usage_of(&account_id)
+    }
+}
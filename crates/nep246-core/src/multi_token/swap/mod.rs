@@ -0,0 +1,63 @@
+/*! Atomic exchange of tokens between two accounts
+
+Maker escrows tokens with `mt_swap`, taker accepts with `mt_swap_accept`,
+which takes taker's side and settles both sides in the same call.
+If swap is not accepted before expiry, escrow is returned with `mt_swap_cancel`.
+ */
+
+mod swap_impl;
+
+pub use swap_impl::*;
+
+use crate::multi_token::json_types::Amount;
+use crate::multi_token::token::TokenId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+pub type SwapId = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SwapProposal {
+    /// The only account that can accept the swap
+    pub taker_id: AccountId,
+    /// Tokens maker gives, escrowed on proposal
+    pub give: Vec<(TokenId, Amount)>,
+    /// Tokens maker wants from taker
+    pub take: Vec<(TokenId, Amount)>,
+    /// Block timestamp in nanoseconds after which swap can't be accepted
+    pub expires_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Swap {
+    pub maker_id: AccountId,
+    pub proposal: SwapProposal,
+}
+
+pub trait MultiTokenSwap {
+    /// Propose a swap and escrow tokens of caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit to cover storage of the swap, unused part is refunded
+    /// * Contract MUST panic if caller does not have enough balance of `give` tokens
+    ///
+    /// returns: ID of the swap
+    fn mt_swap(&mut self, proposal: SwapProposal) -> SwapId;
+
+    /// Accept swap, transferring `take` tokens from caller to maker and escrowed tokens to caller
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if caller is not taker of the swap or swap is expired
+    fn mt_swap_accept(&mut self, swap_id: SwapId);
+
+    /// Cancel swap and return escrowed tokens to maker.
+    /// Maker can cancel at any time, anyone else only after swap expired
+    fn mt_swap_cancel(&mut self, swap_id: SwapId);
+
+    /// Get swap by ID, `None` if it was settled, cancelled or never existed
+    fn mt_swap_get(&self, swap_id: SwapId) -> Option<Swap>;
+}
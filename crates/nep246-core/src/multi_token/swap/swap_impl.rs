@@ -0,0 +1,102 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, require, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    utils::{expect_extension, Entity, StorageTracker},
+};
+
+use super::{MultiTokenSwap, Swap, SwapId, SwapProposal};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Swaps {
+    pub swaps_by_id: LookupMap<SwapId, Swap>,
+    pub next_swap_id: SwapId,
+}
+
+impl MultiToken {
+    /// Allow atomic swaps between accounts
+    pub fn enable_swaps<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.swaps = Some(Swaps { swaps_by_id: LookupMap::new(prefix), next_swap_id: 0 });
+    }
+}
+
+impl MultiTokenSwap for MultiToken {
+    fn mt_swap(&mut self, proposal: SwapProposal) -> SwapId {
+        self.assert_storage_call_deposit();
+        expect_extension(self.swaps.as_ref(), "Swaps", Entity::Contract);
+
+        self.assert_no_compliance();
+
+        let maker_id = env::predecessor_account_id();
+        let escrow_id = env::current_account_id();
+
+        require!(maker_id != proposal.taker_id, "Maker and taker must differ");
+        require!(!proposal.give.is_empty() && !proposal.take.is_empty(), "Both sides of swap must be present");
+        require!(proposal.expires_at > env::block_timestamp(), "Swap is already expired");
+        require!(
+            proposal.give.iter().chain(proposal.take.iter()).all(|(_, amount)| amount.0 > 0),
+            "Amounts must be positive"
+        );
+
+        let mut tracker = StorageTracker::default();
+        tracker.start();
+        for (token_id, amount) in proposal.give.iter() {
+            self.assert_can_move(token_id, &maker_id, &proposal.taker_id, amount.0);
+            self.internal_move(token_id, &maker_id, &escrow_id, amount.0, "swap");
+        }
+
+        let swaps = self.swaps.as_mut().unwrap();
+        let swap_id = swaps.next_swap_id;
+        swaps.next_swap_id += 1;
+        swaps.swaps_by_id.insert(&swap_id, &Swap { maker_id: maker_id.clone(), proposal });
+        tracker.stop();
+        tracker.refund(maker_id);
+
+        swap_id
+    }
+
+    fn mt_swap_accept(&mut self, swap_id: SwapId) {
+        self.assert_call_deposit();
+
+        let swaps = expect_extension(self.swaps.as_mut(), "Swaps", Entity::Contract);
+        let Swap { maker_id, proposal } = swaps.swaps_by_id.remove(&swap_id).expect("Swap not found");
+
+        let taker_id = env::predecessor_account_id();
+        let escrow_id = env::current_account_id();
+
+        require!(taker_id == proposal.taker_id, "Only taker can accept the swap");
+        require!(proposal.expires_at > env::block_timestamp(), "Swap is expired");
+
+        for (token_id, amount) in proposal.take.iter() {
+            self.assert_can_move(token_id, &taker_id, &maker_id, amount.0);
+            self.internal_move(token_id, &taker_id, &maker_id, amount.0, "swap");
+        }
+        for (token_id, amount) in proposal.give.iter() {
+            self.internal_move(token_id, &escrow_id, &taker_id, amount.0, "swap");
+        }
+    }
+
+    fn mt_swap_cancel(&mut self, swap_id: SwapId) {
+        self.assert_call_deposit();
+        let swaps = expect_extension(self.swaps.as_mut(), "Swaps", Entity::Contract);
+        let Swap { maker_id, proposal } = swaps.swaps_by_id.get(&swap_id).expect("Swap not found");
+
+        require!(
+            env::predecessor_account_id() == maker_id || proposal.expires_at <= env::block_timestamp(),
+            "Only maker can cancel swap before expiry"
+        );
+
+        swaps.swaps_by_id.remove(&swap_id);
+
+        let escrow_id = env::current_account_id();
+        for (token_id, amount) in proposal.give.iter() {
+            self.internal_move(token_id, &escrow_id, &maker_id, amount.0, "swap");
+        }
+    }
+
+    fn mt_swap_get(&self, swap_id: SwapId) -> Option<Swap> {
+        expect_extension(self.swaps.as_ref(), "Swaps", Entity::Contract).swaps_by_id.get(&swap_id)
+    }
+}
@@ -0,0 +1,149 @@
+//! Helpers for tests of contracts using [MultiToken], enabled by `test-utils` feature.
+//!
+//! [StateSnapshot] captures balances, supplies and approvals of given tokens and accounts.
+//! Difference of two snapshots is a [StateDiff] listing only what changed, so a test declaring
+//! the expected diff with [assert_state_diff] also fails on side effects it didn't expect.
+//!
+//! ```ignore
+//! assert_state_diff!(contract.tokens, [token_id], [alice, bob],
+//!     contract.tokens.mt_transfer(bob.clone(), token_id.clone(), Amount(10), None),
+//!     {
+//!         balance(token_id, alice) => -10,
+//!         balance(token_id, bob) => 10,
+//!     }
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+
+use near_sdk::{AccountId, Balance};
+
+use crate::multi_token::{core::MultiToken, token::TokenId};
+
+/// Balances, supplies and approvals of some tokens and accounts at one moment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateSnapshot {
+    pub balances: BTreeMap<(TokenId, AccountId), Balance>,
+    pub supplies: BTreeMap<TokenId, Balance>,
+    /// Approved amounts by token and grantee
+    pub approvals: BTreeMap<(TokenId, AccountId), Balance>,
+}
+
+/// Changes between two snapshots. Balances and supplies are deltas, approvals are
+/// new amounts with `None` for removed ones
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    pub balances: BTreeMap<(TokenId, AccountId), i128>,
+    pub supplies: BTreeMap<TokenId, i128>,
+    pub approvals: BTreeMap<(TokenId, AccountId), Option<Balance>>,
+}
+
+fn delta(before: Balance, after: Balance) -> i128 {
+    if after >= before {
+        (after - before) as i128
+    } else {
+        -((before - after) as i128)
+    }
+}
+
+impl StateSnapshot {
+    /// What changed from this snapshot to `after`
+    pub fn diff(&self, after: &StateSnapshot) -> StateDiff {
+        let mut diff = StateDiff::default();
+        for (key, &amount) in &after.balances {
+            let change = delta(self.balances.get(key).copied().unwrap_or(0), amount);
+            if change != 0 {
+                diff.balances.insert(key.clone(), change);
+            }
+        }
+        for (key, &supply) in &after.supplies {
+            let change = delta(self.supplies.get(key).copied().unwrap_or(0), supply);
+            if change != 0 {
+                diff.supplies.insert(key.clone(), change);
+            }
+        }
+        for key in self.approvals.keys().chain(after.approvals.keys()) {
+            let amount = after.approvals.get(key).copied();
+            if self.approvals.get(key).copied() != amount {
+                diff.approvals.insert(key.clone(), amount);
+            }
+        }
+        diff
+    }
+}
+
+impl StateDiff {
+    /// Expect balance of account in token to change by `delta`
+    pub fn balance(mut self, token_id: impl Into<TokenId>, account_id: AccountId, delta: i128) -> Self {
+        if delta != 0 {
+            self.balances.insert((token_id.into(), account_id), delta);
+        }
+        self
+    }
+
+    /// Expect total supply of token to change by `delta`
+    pub fn supply(mut self, token_id: impl Into<TokenId>, delta: i128) -> Self {
+        if delta != 0 {
+            self.supplies.insert(token_id.into(), delta);
+        }
+        self
+    }
+
+    /// Expect approval of grantee in token to become `amount`, `None` if it's removed
+    pub fn approval(mut self, token_id: impl Into<TokenId>, grantee_id: AccountId, amount: Option<Balance>) -> Self {
+        self.approvals.insert((token_id.into(), grantee_id), amount);
+        self
+    }
+}
+
+impl MultiToken {
+    /// Capture state of `token_ids` for `account_ids`. Balances of unregistered accounts are 0,
+    /// approvals are captured for all grantees
+    pub fn snapshot(&self, token_ids: &[TokenId], account_ids: &[AccountId]) -> StateSnapshot {
+        let mut snapshot = StateSnapshot::default();
+        for token_id in token_ids {
+            let balances = self.balances_per_token.get(token_id);
+            for account_id in account_ids {
+                let balance = balances.as_ref().and_then(|balances| balances.get(account_id)).unwrap_or(0);
+                snapshot.balances.insert((token_id.clone(), account_id.clone()), balance);
+            }
+            snapshot.supplies.insert(token_id.clone(), self.total_supply.get(token_id).unwrap_or(0));
+
+            let approvals = self.approvals_by_id.as_ref().and_then(|by_id| by_id.get(token_id)).unwrap_or_default();
+            for (grantee_id, approval) in approvals {
+                snapshot.approvals.insert((token_id.clone(), grantee_id), approval.amount.0);
+            }
+        }
+        snapshot
+    }
+}
+
+/// Run `$op` and assert that state of listed tokens and accounts changed exactly as declared.
+/// Balances and supplies are declared as deltas, approvals as new amounts (`None` if removed).
+/// Anything not declared must stay the same. Result of `$op` is returned
+///
+/// ```ignore
+/// assert_state_diff!(contract.tokens, ["1".to_string()], [alice, bob], contract.burn(...), {
+///     balance("1", alice) => -10,
+///     supply("1") => -10,
+///     approval("1", bob) => None,
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_state_diff {
+    (
+        $mt: expr, [$($token_id: expr),* $(,)?], [$($account_id: expr),* $(,)?], $op: expr,
+        { $($kind: ident ($($key: expr),+) => $change: expr),* $(,)? }
+    ) => {{
+        let token_ids: Vec<$crate::multi_token::token::TokenId> = vec![$($token_id.clone().into()),*];
+        let account_ids: Vec<near_sdk::AccountId> = vec![$($account_id.clone()),*];
+        let before = $mt.snapshot(&token_ids, &account_ids);
+        let result = $op;
+        let after = $mt.snapshot(&token_ids, &account_ids);
+
+        let expected = $crate::multi_token::testing::StateDiff::default()
+            $(.$kind($($key.clone()),+, $change))*;
+        assert_eq!(before.diff(&after), expected, "unexpected state diff");
+        result
+    }};
+}
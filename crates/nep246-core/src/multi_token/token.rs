@@ -0,0 +1,211 @@
+use crate::multi_token::metadata::TokenMetadata;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Deserializer, Serialize};
+pub use near_sdk::{AccountId, Balance};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{Error, ErrorKind, Write};
+use std::ops::Deref;
+use crate::multi_token::core::ApprovalId;
+
+/// Maximum length of [TokenId] in bytes
+pub const MAX_TOKEN_ID_LEN: usize = 64;
+
+/// ID of token. Non-empty string of at most [MAX_TOKEN_ID_LEN] ASCII alphanumeric characters
+/// or any of `-_.:/`. Serialized as a plain string both in JSON and Borsh.
+///
+/// Migration notes: previously `TokenId` was an alias for `String`. Storage layout is the same,
+/// use `TokenId::from(string)` (panics on invalid id) or `TokenId::new(string)` where `String`
+/// was passed before, and `token_id.as_str()` where `&str` is needed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(transparent)]
+pub struct TokenId(String);
+
+impl TokenId {
+    pub fn new(id: String) -> Result<Self, String> {
+        if id.is_empty() {
+            return Err("Token id cannot be empty".to_string());
+        }
+        if id.len() > MAX_TOKEN_ID_LEN {
+            return Err(format!("Token id cannot be longer than {} bytes", MAX_TOKEN_ID_LEN));
+        }
+        if !id.chars().all(|c| c.is_ascii_alphanumeric() || "-_.:/".contains(c)) {
+            return Err(format!("Token id {} contains invalid characters", id));
+        }
+
+        Ok(Self(id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for TokenId {
+    /// Panics if id is invalid
+    fn from(id: String) -> Self {
+        Self::new(id).unwrap_or_else(|err| env::panic_str(&err))
+    }
+}
+
+impl From<&str> for TokenId {
+    /// Panics if id is invalid
+    fn from(id: &str) -> Self {
+        Self::from(id.to_string())
+    }
+}
+
+impl From<TokenId> for String {
+    fn from(id: TokenId) -> Self {
+        id.0
+    }
+}
+
+impl Deref for TokenId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for TokenId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for TokenId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = <String as Deserialize>::deserialize(deserializer)?;
+        Self::new(id).map_err(near_sdk::serde::de::Error::custom)
+    }
+}
+
+/// Approval of account in token.
+///
+/// Borsh layout is compact, because approvals are stored per grantee and paid by owners:
+/// flag byte, then either share as `u16` or amount as LEB128, then approval ID as LEB128.
+/// Typical approval takes 3-8 bytes instead of 27 with plain layout.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Approval {
+    pub amount: U128,
+    pub approval_id: ApprovalId,
+    /// If set, approval covers this share of owner's balance at the moment of transfer
+    /// instead of fixed `amount`. Expressed in basis points, 10000 is 100%
+    pub share: Option<u16>,
+}
+
+/// Share that equals to whole balance, in basis points
+pub const MAX_SHARE_BPS: u16 = 10_000;
+
+const APPROVAL_AMOUNT_FLAG: u8 = 0;
+const APPROVAL_SHARE_FLAG: u8 = 1;
+
+fn write_leb128<W: Write>(writer: &mut W, mut value: u128) -> std::io::Result<()> {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            return writer.write_all(&[byte]);
+        }
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+fn read_leb128(buf: &mut &[u8]) -> std::io::Result<u128> {
+    let mut value: u128 = 0;
+    for shift in (0..128).step_by(7) {
+        let byte: u8 = BorshDeserialize::deserialize(buf)?;
+        let bits = u128::from(byte & 0x7f);
+        // Last byte of u128 can hold only 2 bits
+        if shift == 126 && bits > 0b11 {
+            break;
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::new(ErrorKind::InvalidData, "LEB128 value overflows u128"))
+}
+
+impl BorshSerialize for Approval {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self.share {
+            // Amount is not used by share approvals
+            Some(share) => {
+                writer.write_all(&[APPROVAL_SHARE_FLAG])?;
+                BorshSerialize::serialize(&share, writer)?;
+            }
+            None => {
+                writer.write_all(&[APPROVAL_AMOUNT_FLAG])?;
+                write_leb128(writer, self.amount.0)?;
+            }
+        }
+        write_leb128(writer, u128::from(self.approval_id))
+    }
+}
+
+impl BorshDeserialize for Approval {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let (amount, share) = match <u8 as BorshDeserialize>::deserialize(buf)? {
+            APPROVAL_AMOUNT_FLAG => (read_leb128(buf)?, None),
+            APPROVAL_SHARE_FLAG => (0, Some(<u16 as BorshDeserialize>::deserialize(buf)?)),
+            flag => return Err(Error::new(ErrorKind::InvalidData, format!("Unknown approval flag {}", flag))),
+        };
+        let approval_id = ApprovalId::try_from(read_leb128(buf)?)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Approval ID overflows u64"))?;
+
+        Ok(Approval { amount: U128(amount), approval_id, share })
+    }
+}
+
+impl Approval {
+    /// How much approved account can transfer when owner has given `balance`
+    pub fn allowance(&self, balance: Balance) -> Balance {
+        match self.share {
+            Some(share) => balance / Balance::from(MAX_SHARE_BPS) * Balance::from(share)
+                + balance % Balance::from(MAX_SHARE_BPS) * Balance::from(share) / Balance::from(MAX_SHARE_BPS),
+            None => self.amount.0,
+        }
+    }
+}
+
+/// How token behaves and how wallets should render it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TokenKind {
+    /// Interchangeable units without supply limits, like a fungible token
+    Fungible,
+    /// Single unique unit, like NFT. Supply is exactly 1 and it moves only as a whole
+    NonFungible,
+    /// Interchangeable units of a limited edition, e.g. tickets of an event
+    #[default]
+    SemiFungible,
+}
+
+/// Info on individual token
+#[derive(Debug, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct Token {
+    pub token_id: TokenId,
+    pub owner_id: AccountId,
+    /// Total amount generated
+    pub supply: U128,
+    /// Set if contract distinguishes kinds of tokens
+    pub kind: Option<TokenKind>,
+    pub balances: HashMap<AccountId, U128>,
+    pub metadata: Option<TokenMetadata>,
+    pub approvals: Option<HashMap<AccountId, Approval>>,
+    pub next_approval_id: Option<u64>,
+}
@@ -0,0 +1,18 @@
+/*! Fungible, non-fungible and semi-fungible tokens in one contract
+
+Kind is chosen at mint through `kind` of [TokenMetadata](crate::multi_token::metadata::TokenMetadata),
+semi-fungible if it's not set, and can't be changed afterwards. Non-fungible tokens are minted with
+supply of exactly 1 which can't grow, and move only as a whole unit. Kind is returned in `mt_token`
+and filled into stored metadata, so wallets can render tokens accordingly.
+ */
+
+mod token_kind_impl;
+
+pub use token_kind_impl::*;
+
+use crate::multi_token::token::{TokenId, TokenKind};
+
+pub trait MultiTokenKind {
+    /// Get kind of token, `None` if token doesn't exist
+    fn mt_token_kind(&self, token_id: TokenId) -> Option<TokenKind>;
+}
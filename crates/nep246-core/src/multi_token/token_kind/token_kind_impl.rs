@@ -0,0 +1,89 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{require, Balance, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    metadata::TokenMetadata,
+    token::{TokenId, TokenKind},
+    utils::{expect_extension, Entity},
+};
+
+use super::MultiTokenKind;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TokenKinds {
+    pub kind_by_id: LookupMap<TokenId, TokenKind>,
+}
+
+impl MultiToken {
+    /// Record kind of tokens minted from now on. Tokens minted before are treated as semi-fungible
+    pub fn enable_token_kinds<P: IntoStorageKey>(&mut self, prefix: P) {
+        self.token_kinds = Some(TokenKinds { kind_by_id: LookupMap::new(prefix) });
+    }
+
+    /// Record kind of freshly minted token requested in its metadata and fill it into metadata
+    pub(crate) fn internal_set_minted_kind(
+        &mut self,
+        token_id: &TokenId,
+        metadata: Option<&mut TokenMetadata>,
+        owner_amount: Option<Balance>,
+    ) {
+        let kinds = match self.token_kinds.as_mut() {
+            Some(kinds) => kinds,
+            None => {
+                require!(metadata.map_or(true, |metadata| metadata.kind.is_none()), "Token kinds are not enabled");
+                return;
+            }
+        };
+
+        let kind = metadata.as_ref().and_then(|metadata| metadata.kind).unwrap_or_default();
+        if kind == TokenKind::NonFungible {
+            require!(owner_amount == Some(1), "Non-fungible token must be minted with supply of 1");
+        }
+        if let Some(metadata) = metadata {
+            metadata.kind = Some(kind);
+        }
+        kinds.kind_by_id.insert(token_id, &kind);
+    }
+
+    /// Kind of token, `None` if contract doesn't record kinds
+    pub fn internal_token_kind(&self, token_id: &TokenId) -> Option<TokenKind> {
+        self.token_kinds.as_ref().map(|kinds| kinds.kind_by_id.get(token_id).unwrap_or_default())
+    }
+
+    fn internal_is_non_fungible(&self, token_id: &TokenId) -> bool {
+        self.internal_token_kind(token_id) == Some(TokenKind::NonFungible)
+    }
+
+    /// Panics if supply of non-fungible token would exceed 1
+    pub fn assert_kind_supply(&self, token_id: &TokenId, supply: Balance) {
+        if supply > 1 && self.internal_is_non_fungible(token_id) {
+            MtError::NonFungibleSupply.panic();
+        }
+    }
+
+    /// Check that `amount` can be moved in one transfer of token
+    pub fn internal_check_kind_amount(&self, token_id: &TokenId, amount: Balance) -> Result<(), MtError> {
+        if amount != 1 && self.internal_is_non_fungible(token_id) {
+            return Err(MtError::NonFungibleAmount { amount });
+        }
+        Ok(())
+    }
+
+    /// Panics if `amount` of non-fungible token is not 1
+    pub fn assert_kind_amount(&self, token_id: &TokenId, amount: Balance) {
+        if let Err(err) = self.internal_check_kind_amount(token_id, amount) {
+            err.panic();
+        }
+    }
+}
+
+impl MultiTokenKind for MultiToken {
+    fn mt_token_kind(&self, token_id: TokenId) -> Option<TokenKind> {
+        let kinds = expect_extension(self.token_kinds.as_ref(), "Token kinds", Entity::Contract);
+        self.owner_by_id.get(&token_id)?;
+        Some(kinds.kind_by_id.get(&token_id).unwrap_or_default())
+    }
+}
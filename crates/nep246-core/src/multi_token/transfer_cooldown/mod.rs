@@ -0,0 +1,30 @@
+/*! Cooldowns between transfers of a token, to damp wash trading of reward tokens
+
+Owner sets cooldown for a token in seconds. Every transfer of that token stamps the sender,
+and it can send the token again only after the cooldown since its last stamp is over.
+Receivers aren't stamped, otherwise anyone could keep an account from sending by sending it dust.
+Tokens held by the contract itself, e.g. in escrow, are never stamped.
+ */
+
+mod transfer_cooldown_impl;
+
+pub use transfer_cooldown_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U64;
+use near_sdk::AccountId;
+
+pub trait MultiTokenTransferCooldown {
+    /// Set cooldown of token in seconds, `None` removes it
+    ///
+    /// # Requirements:
+    /// * Caller of the method must attach a deposit of 1 yoctoⓃ for security purposes
+    /// * Contract MUST panic if called by someone other than contract owner
+    fn mt_set_transfer_cooldown(&mut self, token_id: TokenId, cooldown: Option<U64>);
+
+    /// Get cooldown of token in seconds
+    fn mt_transfer_cooldown(&self, token_id: TokenId) -> Option<U64>;
+
+    /// Get seconds left until `account_id` can transfer token, 0 if it can transfer right away
+    fn mt_cooldown_remaining(&self, token_id: TokenId, account_id: AccountId) -> U64;
+}
@@ -0,0 +1,91 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U64;
+use near_sdk::{env, AccountId, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+    utils::{expect_extension, unauthorized_assert, Entity},
+};
+
+use super::MultiTokenTransferCooldown;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TransferCooldowns {
+    /// Cooldown of token in nanoseconds
+    pub cooldown_by_id: LookupMap<TokenId, u64>,
+    /// Block timestamp of last transfer of token sent by account
+    pub last_transfer: LookupMap<(TokenId, AccountId), u64>,
+}
+
+impl MultiToken {
+    /// Allow owner to set cooldowns between transfers of tokens
+    pub fn enable_transfer_cooldowns<P: IntoStorageKey>(&mut self, prefix: P) {
+        let prefix = prefix.into_storage_key();
+        self.transfer_cooldowns = Some(TransferCooldowns {
+            cooldown_by_id: LookupMap::new([prefix.clone(), "c".into()].concat()),
+            last_transfer: LookupMap::new([prefix, "l".into()].concat()),
+        });
+    }
+
+    /// Timestamp until which `account_id` can't send token, `None` if it can send it now
+    pub fn internal_active_cooldown(&self, token_id: &TokenId, account_id: &AccountId) -> Option<u64> {
+        let cooldowns = self.transfer_cooldowns.as_ref()?;
+        let cooldown = cooldowns.cooldown_by_id.get(token_id)?;
+        cooldowns
+            .last_transfer
+            .get(&(token_id.clone(), account_id.clone()))
+            .map(|last| last.saturating_add(cooldown))
+            .filter(|until| env::block_timestamp() < *until)
+    }
+
+    /// Panics if `account_id` sent token less than cooldown ago
+    pub fn assert_not_cooling_down(&self, token_id: &TokenId, account_id: &AccountId) {
+        if let Some(until) = self.internal_active_cooldown(token_id, account_id) {
+            MtError::TransferCooldown { until }.panic();
+        }
+    }
+
+    /// Stamp sender of token that has a cooldown
+    pub(crate) fn internal_stamp_transfer(&mut self, token_id: &TokenId, sender_id: &AccountId) {
+        let cooldowns = match self.transfer_cooldowns.as_mut() {
+            Some(cooldowns) if cooldowns.cooldown_by_id.contains_key(token_id) => cooldowns,
+            _ => return,
+        };
+        if sender_id != &env::current_account_id() {
+            cooldowns.last_transfer.insert(&(token_id.clone(), sender_id.clone()), &env::block_timestamp());
+        }
+    }
+}
+
+impl MultiTokenTransferCooldown for MultiToken {
+    fn mt_set_transfer_cooldown(&mut self, token_id: TokenId, cooldown: Option<U64>) {
+        self.assert_call_deposit();
+        unauthorized_assert(&self.owner_id);
+        self.owner_by_id.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+
+        let cooldowns = expect_extension(self.transfer_cooldowns.as_mut(), "Transfer cooldown", Entity::Contract);
+        match cooldown {
+            Some(cooldown) => cooldowns.cooldown_by_id.insert(&token_id, &cooldown.0.saturating_mul(NANOS_PER_SECOND)),
+            None => cooldowns.cooldown_by_id.remove(&token_id),
+        };
+    }
+
+    fn mt_transfer_cooldown(&self, token_id: TokenId) -> Option<U64> {
+        let cooldowns = expect_extension(self.transfer_cooldowns.as_ref(), "Transfer cooldown", Entity::Contract);
+        cooldowns.cooldown_by_id.get(&token_id).map(|cooldown| U64(cooldown / NANOS_PER_SECOND))
+    }
+
+    fn mt_cooldown_remaining(&self, token_id: TokenId, account_id: AccountId) -> U64 {
+        expect_extension(self.transfer_cooldowns.as_ref(), "Transfer cooldown", Entity::Contract);
+        let remaining = self
+            .internal_active_cooldown(&token_id, &account_id)
+            .map_or(0, |until| until - env::block_timestamp());
+        // Round up, so account that waits returned number of seconds can transfer
+        U64((remaining + NANOS_PER_SECOND - 1) / NANOS_PER_SECOND)
+    }
+}
@@ -0,0 +1,20 @@
+/*! Lockup of freshly minted tokens
+
+Tokens minted while the extension is enabled can't be transferred until `transfer_lock_until`,
+e.g. for 30 days after a claim, which stops flipping right after a drop. Lock applies to every
+transfer made by holders or on their behalf, contract-initiated moves (staking, compliance
+settlement) and moves forced by compliance officer or council are not limited.
+ */
+
+mod transfer_lock_impl;
+
+pub use transfer_lock_impl::*;
+
+use crate::multi_token::token::TokenId;
+use near_sdk::json_types::U64;
+
+pub trait MultiTokenTransferLock {
+    /// Get block timestamp in nanoseconds from which token can be transferred,
+    /// `None` if token was minted without lock
+    fn mt_transfer_lock_until(&self, token_id: TokenId) -> Option<U64>;
+}
@@ -0,0 +1,65 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U64;
+use near_sdk::{env, IntoStorageKey};
+
+use crate::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::TokenId,
+};
+
+use super::MultiTokenTransferLock;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TransferLocks {
+    /// Lock given to every minted token, in nanoseconds. 0 means tokens are minted unlocked
+    pub lockup: u64,
+    /// Block timestamp in nanoseconds until which token can't be transferred
+    pub lock_until_by_id: LookupMap<TokenId, u64>,
+}
+
+impl MultiToken {
+    /// Lock transfers of tokens minted from now on for `lockup` nanoseconds after mint
+    pub fn enable_transfer_locks<P: IntoStorageKey>(&mut self, prefix: P, lockup: u64) {
+        self.transfer_locks = Some(TransferLocks { lockup, lock_until_by_id: LookupMap::new(prefix) });
+    }
+
+    /// Lock freshly minted token for default lockup
+    pub(crate) fn internal_lock_minted(&mut self, token_id: &TokenId) {
+        if let Some(locks) = self.transfer_locks.as_mut().filter(|locks| locks.lockup > 0) {
+            locks.lock_until_by_id.insert(token_id, &env::block_timestamp().saturating_add(locks.lockup));
+        }
+    }
+
+    /// Override lock of token, e.g. right after mint for tokens of a drop with custom lockup.
+    /// `None` removes the lock
+    pub fn internal_set_transfer_lock(&mut self, token_id: &TokenId, lock_until: Option<u64>) {
+        let locks = self.transfer_locks.as_mut().unwrap_or_else(|| env::panic_str("Transfer locks are not enabled"));
+        match lock_until {
+            Some(lock_until) => locks.lock_until_by_id.insert(token_id, &lock_until),
+            None => locks.lock_until_by_id.remove(token_id),
+        };
+    }
+
+    /// Timestamp until which token is locked, `None` if it's not locked anymore
+    pub fn internal_active_transfer_lock(&self, token_id: &TokenId) -> Option<u64> {
+        self.transfer_locks
+            .as_ref()
+            .and_then(|locks| locks.lock_until_by_id.get(token_id))
+            .filter(|lock_until| env::block_timestamp() < *lock_until)
+    }
+
+    /// Panics if token is still locked after mint
+    pub fn assert_not_transfer_locked(&self, token_id: &TokenId) {
+        if let Some(until) = self.internal_active_transfer_lock(token_id) {
+            MtError::TransferLocked { until }.panic();
+        }
+    }
+}
+
+impl MultiTokenTransferLock for MultiToken {
+    fn mt_transfer_lock_until(&self, token_id: TokenId) -> Option<U64> {
+        self.transfer_locks.as_ref().and_then(|locks| locks.lock_until_by_id.get(&token_id)).map(U64)
+    }
+}
@@ -0,0 +1,26 @@
+//! Contract upgrades, enabled by `upgrade` feature.
+//!
+//! New code is deployed and its `migrate` method is called in a single batch promise,
+//! so if migration fails, the deploy is reverted as well and the contract keeps working on old code.
+
+use near_sdk::{env, Balance, Promise};
+
+use crate::multi_token::{core::MultiToken, utils::unauthorized_assert};
+
+pub use crate::multi_token::gas::GAS_FOR_UPGRADE_DEPLOY;
+
+const NO_DEPOSIT: Balance = 0;
+
+impl MultiToken {
+    /// Deploy `code` to the current account and call `migrate` on it with all remaining gas.
+    /// Can only be called by contract owner
+    pub fn internal_upgrade(&self, code: Vec<u8>) -> Promise {
+        unauthorized_assert(&self.owner_id);
+
+        let migrate_gas = env::prepaid_gas() - env::used_gas() - GAS_FOR_UPGRADE_DEPLOY;
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call("migrate".to_string(), vec![], NO_DEPOSIT, migrate_gas)
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::{env, require, AccountId, Balance, CryptoHash, Promise, StorageUsage};
+
+use crate::multi_token::errors::MtError;
+use crate::multi_token::token::Approval;
+
+pub fn hash_account_id(account_id: &AccountId) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+    hash.copy_from_slice(&env::sha256(account_id.as_bytes()));
+    hash
+}
+
+pub fn refund_deposit_to_account(storage_used: u64, account_id: AccountId) {
+    let required_cost = env::storage_byte_cost() * Balance::from(storage_used);
+    let attached_deposit = env::attached_deposit();
+
+    require!(
+        required_cost <= attached_deposit,
+        format!("Must attach {} yoctoNEAR to cover storage", required_cost)
+    );
+
+    let refund = attached_deposit - required_cost;
+    if refund > 1 {
+        Promise::new(account_id).transfer(refund);
+    }
+}
+
+/// Assumes that the precedecessor will be refunded
+pub fn refund_deposit(storage_used: u64) {
+    refund_deposit_to_account(storage_used, env::predecessor_account_id())
+}
+
+/// Measures how storage usage changed between `start` and `stop` calls.
+/// Can be started & stopped several times, changes are accumulated.
+#[derive(Default)]
+pub struct StorageTracker {
+    pub bytes_added: StorageUsage,
+    pub bytes_released: StorageUsage,
+    initial_storage_usage: Option<StorageUsage>,
+}
+
+impl StorageTracker {
+    pub fn start(&mut self) {
+        require!(self.initial_storage_usage.is_none(), "Storage tracker is already started");
+        self.initial_storage_usage = Some(env::storage_usage());
+    }
+
+    pub fn stop(&mut self) {
+        let initial = self.initial_storage_usage.take().expect("Storage tracker is not started");
+        let current = env::storage_usage();
+
+        if current >= initial {
+            self.bytes_added += current - initial;
+        } else {
+            self.bytes_released += initial - current;
+        }
+    }
+
+    /// Charge attached deposit for added storage and refund the rest to `account_id`
+    pub fn refund(self, account_id: AccountId) {
+        require!(self.initial_storage_usage.is_none(), "Storage tracker is not stopped");
+        refund_deposit_to_account(self.bytes_added.saturating_sub(self.bytes_released), account_id)
+    }
+}
+
+// TODO: need a way for end users to determine how much an approval will cost.
+pub fn bytes_for_approved_account_id(account_id: &AccountId, approval: &Approval) -> u64 {
+    // The extra 4 bytes are coming from Borsh serialization to store the length of the string.
+    account_id.as_str().len() as u64 + 4 + bytes_for_approval(approval)
+}
+
+/// Size of approval in storage, see [Approval] for layout
+pub fn bytes_for_approval(approval: &Approval) -> u64 {
+    approval.try_to_vec().map_or(0, |bytes| bytes.len() as u64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entity {
+    Contract,
+    Token,
+}
+
+impl Display for Entity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let as_string = match self {
+            Entity::Contract => "contract",
+            Entity::Token => "token",
+        };
+        write!(f, "{}", as_string)
+    }
+}
+
+/// Unwrap state of `extension`, panicking with [MtError::ExtensionDisabled] if `entity` doesn't use it
+pub fn expect_extension<T>(o: Option<T>, extension: &'static str, entity: Entity) -> T {
+    o.unwrap_or_else(|| MtError::ExtensionDisabled { extension, entity }.panic())
+}
+
+pub fn unauthorized_assert(account_id: &AccountId) {
+    require!(account_id == &env::predecessor_account_id())
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use near_sdk::{json_types::U128, require, AccountId};
+use nep246_core::multi_token::{
+    core::MultiToken,
+    errors::MtError,
+    token::{Token, TokenId},
+};
+
+use crate::MultiTokenEnumeration;
+
+impl MultiTokenEnumeration for MultiToken {
+    fn mt_tokens(&self, from_index: Option<u64>, limit: u64) -> Vec<Token> {
+        let from_index = from_index.unwrap_or(0);
+
+        if let Some(order) = &self.token_order {
+            require!(order.index.len() > from_index, "Out of bounds");
+            require!(limit != 0, "Limit cannot be 0");
+
+            return order
+                .index
+                .iter()
+                .skip(from_index as usize)
+                .take(limit as usize)
+                .map(|((_, token_id), _)| {
+                    let owner_id = self.owner_by_id.get(&token_id).unwrap();
+                    self.enum_get_token(owner_id, token_id)
+                })
+                .collect();
+        }
+
+        require!(self.owner_by_id.len() > from_index, "Out of bounds");
+
+        require!(limit !=0, "Limit cannot be 0");
+
+        self.owner_by_id
+        .iter()
+        .skip(from_index as usize)
+        .take(limit as usize)
+        .map(|(token_id, owner_id)| self.enum_get_token(owner_id, token_id))
+        .collect()
+    }
+
+    fn mt_tokens_for_owner(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<Token> {
+        let tokens_per_owner = self.tokens_per_owner.as_ref().expect("Could not find field");
+
+        let token_set = if let Some(set) = tokens_per_owner.get(&account_id) {
+            set
+        } else {
+            return vec![];
+        };
+
+        require!(limit != 0, "Limit cannot be 0");
+        let from_index = from_index.unwrap_or(0);
+
+        require!(token_set.len() > from_index, "Out of bounds");
+
+        token_set
+        .iter()
+        .skip(from_index as usize)
+        .take(limit as usize)
+        .map(|(token_id, _)| self.enum_get_token(account_id.clone(), token_id))
+        .collect()
+    }
+
+    fn mt_balances_of_token(&self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> Vec<(AccountId, U128)> {
+        require!(limit != 0, "Limit cannot be 0");
+        let balances = self.balances_per_token.get(&token_id).unwrap_or_else(|| MtError::TokenNotFound.panic());
+        let holders = match self.holders_per_token.get(&token_id) {
+            Some(holders) => holders,
+            None => return vec![],
+        };
+        let from_index = from_index.unwrap_or(0);
+        let to_index = holders.len().min(from_index.saturating_add(limit));
+
+        // Index reads are O(1), unlike skipping over iterator
+        let holders = holders.as_vector();
+        (from_index..to_index)
+            .filter_map(|index| holders.get(index))
+            .map(|account_id| {
+                let balance = balances.get(&account_id).unwrap_or(0);
+                (account_id, U128(balance))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::unnecessary_map_or)]
+#![allow(clippy::manual_div_ceil)]
+#![allow(clippy::manual_is_multiple_of)]
+#![allow(clippy::doc_overindented_list_items)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(unused_variables)]
+
+//! Enumeration extension of NEP-246: paginated views of tokens and their holders.
+//!
+//! Indexes read by the views, e.g. tokens per owner and [TokenOrder], are maintained by
+//! [MultiToken](nep246_core::multi_token::core::MultiToken) of `nep246-core` on every mint and transfer.
+
+pub use nep246_core::multi_token::enumeration::*;
+
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use nep246_core::multi_token::{
+    metadata::MtContractMetadata,
+    token::{Token, TokenId},
+};
+
+mod enumeration_impl;
+
+/// Enumeration extension for NEP-246
+/// See specs here -> <https://github.com/shipsgold/NEPs/blob/master/specs/Standards/MultiToken/Enumeration.md>
+pub trait MultiTokenEnumeration {
+    /// Get a list of all tokens (with pagination)
+    ///
+    /// Tokens are ordered by token ID (lexicographically), so consecutive pages
+    /// are stable as long as no tokens are minted in between. If contract enabled
+    /// custom order, see [TokenOrder], tokens are returned in that order.
+    /// Enumeration doesn't depend on other extensions: without metadata or approvals
+    /// corresponding fields of tokens are `None`.
+    /// # Arguments:
+    /// * `from_index` - Index to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of tokens to return
+    ///
+    /// returns: List of [Token]s.
+    ///
+    fn mt_tokens(&self, from_index: Option<u64>, limit: u64) -> Vec<Token>;
+
+    /// Get list of all tokens by a given account
+    ///
+    /// Tokens are ordered by token ID (lexicographically), same as in `mt_tokens`.
+    /// # Arguments:
+    /// * `account_id`: a valid NEAR account
+    /// * `from_index` - Index to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of tokens to return
+    ///
+    /// returns: List of [Token]s owner by user
+    ///
+    fn mt_tokens_for_owner(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<Token>;
+
+    /// Get holders of token with their balances (with pagination), e.g. for snapshots and airdrops
+    ///
+    /// Holders are in order of the holder index, which changes when holders leave, so export
+    /// all pages at the same block height. Holders whose balance dropped to 0 may be included.
+    /// # Arguments:
+    /// * `token_id` - Token to export
+    /// * `from_index` - Index to start from, defaults to 0 if not provided
+    /// * `limit` - The maximum number of holders to return
+    ///
+    /// returns: List of holders and their balances
+    ///
+    fn mt_balances_of_token(&self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> Vec<(AccountId, U128)>;
+}
+
+/// The contract must implement the following view methods if using metadata extension
+pub trait MultiTokenEnumerationMetadata {
+    /// Get list of all base metadata for the contract
+    ///
+    /// Arguments:
+    /// * `from_index`: a string representing an unsigned 128-bit integer,
+    ///    representing the starting index of tokens to return
+    /// * `limit`: the maximum number of tokens to return
+    ///
+    /// Returns an array of `MTBaseTokenMetadata` objects, as described in the Metadata standard, and an empty array if there are no tokens
+    fn mt_tokens_base_metadata_all(&self, from_index: Option<u64>, limit: u64) -> Vec<MtContractMetadata>;
+}
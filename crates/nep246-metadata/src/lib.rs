@@ -0,0 +1,60 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::unnecessary_map_or)]
+#![allow(clippy::manual_div_ceil)]
+#![allow(clippy::manual_is_multiple_of)]
+#![allow(clippy::doc_overindented_list_items)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(unused_variables)]
+
+//! Metadata extension of NEP-246: views of contract and token metadata.
+//!
+//! Metadata itself is stored by [MultiToken](nep246_core::multi_token::core::MultiToken) of
+//! `nep246-core`, types are re-exported from there, this crate adds views of the extension.
+
+pub use nep246_core::multi_token::metadata::*;
+
+use nep246_core::multi_token::token::TokenId;
+
+mod metadata_impl;
+
+/// Offers details on the contract-level metadata.
+pub trait MultiTokenMetadataProvider {
+    fn mt_metadata(&self) -> MtContractMetadata;
+}
+
+/// Lets clients check that media of token was not swapped on the hosting server
+pub trait MultiTokenMediaVerifier {
+    /// Check media of token against hash stored on-chain
+    ///
+    /// # Arguments:
+    /// * `token_id` - ID of token
+    /// * `media_hash` - Base64-encoded sha256 hash of downloaded media
+    ///
+    /// returns: `true` if token has media and hashes match
+    fn mt_verify_media(&self, token_id: TokenId, media_hash: String) -> bool;
+}
+
+/// Single trait of token for rarity tools
+pub trait MultiTokenAttributes {
+    /// Get attribute of token with given `trait_type`, `None` if token doesn't have it
+    fn mt_token_attribute(&self, token_id: TokenId, trait_type: String) -> Option<TokenAttribute>;
+}
+
+/// Metadata of many tokens in one view call
+pub trait MultiTokenMetadataBatch {
+    /// Get metadata of tokens in order of `token_ids`, `None` for tokens without metadata.
+    ///
+    /// Each token is a separate storage read, so gas grows linearly with number of tokens
+    /// and size of metadata. With typical metadata 100 tokens fit well into view call gas limit.
+    ///
+    /// # Requirements:
+    /// * Contract MUST panic if more than [MAX_METADATA_BATCH] tokens are requested
+    fn mt_metadata_token_by_ids(&self, token_ids: Vec<TokenId>) -> Vec<Option<TokenMetadata>>;
+}
+
+/// Canonical URI of token, composed from contract-level `base_uri` and token metadata
+pub trait MultiTokenUri {
+    /// Get URI of token. See [token_uri] for composition rules
+    fn mt_token_uri(&self, token_id: TokenId) -> Option<String>;
+}
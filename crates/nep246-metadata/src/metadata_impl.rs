@@ -0,0 +1,37 @@
+use near_sdk::require;
+use nep246_core::multi_token::{core::MultiToken, token::TokenId};
+
+use crate::{
+    MultiTokenAttributes, MultiTokenMediaVerifier, MultiTokenMetadataBatch, TokenAttribute, TokenMetadata,
+    MAX_METADATA_BATCH,
+};
+
+impl MultiTokenMediaVerifier for MultiToken {
+    fn mt_verify_media(&self, token_id: TokenId, media_hash: String) -> bool {
+        self.token_metadata_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(&token_id))
+            .and_then(|metadata| metadata.media_hash)
+            .map(|stored| stored == media_hash)
+            .unwrap_or(false)
+    }
+}
+
+impl MultiTokenAttributes for MultiToken {
+    fn mt_token_attribute(&self, token_id: TokenId, trait_type: String) -> Option<TokenAttribute> {
+        self.token_metadata_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(&token_id))
+            .and_then(|metadata| metadata.attributes)
+            .and_then(|attributes| attributes.into_iter().find(|attribute| attribute.trait_type == trait_type))
+    }
+}
+
+impl MultiTokenMetadataBatch for MultiToken {
+    fn mt_metadata_token_by_ids(&self, token_ids: Vec<TokenId>) -> Vec<Option<TokenMetadata>> {
+        require!(token_ids.len() <= MAX_METADATA_BATCH, format!("Cannot fetch more than {} tokens", MAX_METADATA_BATCH));
+
+        let by_id = self.token_metadata_by_id.as_ref();
+        token_ids.iter().map(|token_id| by_id.and_then(|by_id| by_id.get(token_id))).collect()
+    }
+}
@@ -1,11 +1,16 @@
-use near_sdk::{near_bindgen, borsh::{self, BorshDeserialize, BorshSerialize}, PanicOnDefault, AccountId, env, PromiseOrValue};
-use nep_246::multi_token::{approval::MultiTokenApprovalReceiver, token::TokenId};
+use near_sdk::{near_bindgen, borsh::{self, BorshDeserialize, BorshSerialize}, PanicOnDefault, AccountId, env, PromiseOrValue, require};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use nep_246::multi_token::{approval::MultiTokenApprovalReceiver, core::MultiTokenReceiver, token::TokenId};
 
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
-    
+    /// How much of each token contract is willing to hold
+    capacity: u128,
+    /// Amount received so far by (MT contract, token)
+    held: LookupMap<(AccountId, TokenId), u128>,
 }
 
 #[near_bindgen]
@@ -16,11 +21,16 @@ impl Contract {
         that's passed in
     */
     #[init]
-    pub fn new() -> Self {
+    pub fn new(capacity: U128) -> Self {
         Self {
-            
+            capacity: capacity.0,
+            held: LookupMap::new(b"h"),
         }
     }
+
+    pub fn held(&self, contract_id: AccountId, token_id: TokenId) -> U128 {
+        U128(self.held.get(&(contract_id, token_id)).unwrap_or(0))
+    }
 }
 
 #[near_bindgen]
@@ -38,4 +48,40 @@ impl MultiTokenApprovalReceiver for Contract {
 
         PromiseOrValue::Value("yeeeeeeeeeeeeeeee".to_string())
     }
-}
\ No newline at end of file
+}
+
+/// Keeps only what fits within capacity, the rest is returned as unused
+/// and MT contract refunds it to the sender in `mt_resolve_transfer`
+#[near_bindgen]
+impl MultiTokenReceiver for Contract {
+    fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_id: Vec<AccountId>,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        require!(token_ids.len() == amounts.len(), "Length of token_ids and amounts must be equal");
+        require!(previous_owner_id.len() == token_ids.len(), "Length of token_ids and previous_owner_id must be equal");
+
+        env::log_str(format!("Sender: {}, msg: {}", sender_id, msg).as_str());
+
+        let contract_id = env::predecessor_account_id();
+        let unused = token_ids
+            .into_iter()
+            .zip(amounts.into_iter())
+            .map(|(token_id, amount)| {
+                let key = (contract_id.clone(), token_id);
+                let held = self.held.get(&key).unwrap_or(0);
+                let accepted = std::cmp::min(amount.0, self.capacity.saturating_sub(held));
+                if accepted > 0 {
+                    self.held.insert(&key, &(held + accepted));
+                }
+                U128(amount.0 - accepted)
+            })
+            .collect();
+
+        PromiseOrValue::Value(unused)
+    }
+}
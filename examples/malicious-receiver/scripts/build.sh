@@ -0,0 +1,12 @@
+#!/bin/bash
+set -e
+
+OUT_DIR=out
+
+if [ ! -d $OUT_DIR ]; then
+    echo "Creating '${OUT_DIR}' directory"
+    mkdir $OUT_DIR;
+fi
+
+cargo build --release
+cp target/wasm32-unknown-unknown/release/nep_246_malicious_receiver.wasm $OUT_DIR
\ No newline at end of file
@@ -0,0 +1,95 @@
+/*!
+Receiver that misbehaves in `mt_on_transfer` on purpose, to check that MT contracts
+survive hostile counterparties. Behaviour is chosen by `msg` of `mt_transfer_call`:
+
+* `panic` - fails, so whole amount must be refunded
+* `refund_more` - asks to refund more than was sent
+* `invalid` - returns something that is not a list of amounts
+* `short` - returns fewer amounts than tokens
+* `reenter` - transfers received tokens back to sender before returning
+* `burn_gas` - spins until all gas is used
+* anything else - keeps everything
+ */
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, near_bindgen, AccountId, Gas, PanicOnDefault, PromiseOrValue};
+use nep_246::multi_token::{core::MultiTokenReceiver, token::TokenId};
+
+const GAS_FOR_REENTER: Gas = Gas(10_000_000_000_000);
+
+#[ext_contract(ext_mt)]
+pub trait MultiTokenCore {
+    fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Vec<Option<u64>>,
+        batch_id: Option<String>,
+    );
+}
+
+#[ext_contract(ext_self)]
+pub trait SelfCallback {
+    fn not_amounts(&self) -> String;
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Raw result of `mt_on_transfer` for `invalid` mode
+    pub fn not_amounts(&self) -> String {
+        "definitely not amounts".to_string()
+    }
+}
+
+#[near_bindgen]
+impl MultiTokenReceiver for Contract {
+    fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_id: Vec<AccountId>,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        env::log_str(format!("Hostile mode: {}, previous owners: {:?}", msg, previous_owner_id).as_str());
+
+        match msg.as_str() {
+            "panic" => env::panic_str("Receiver panicked on purpose"),
+            "refund_more" => PromiseOrValue::Value(amounts.iter().map(|amount| U128(amount.0.saturating_mul(2).max(1))).collect()),
+            "invalid" => PromiseOrValue::Promise(ext_self::not_amounts(env::current_account_id(), 0, GAS_FOR_REENTER)),
+            "short" => PromiseOrValue::Value(amounts.iter().skip(1).copied().collect()),
+            "reenter" => {
+                // Tokens are sent back right away, then refund of everything is requested as well
+                ext_mt::mt_batch_transfer(
+                    sender_id,
+                    token_ids.clone(),
+                    amounts.clone(),
+                    vec![None; token_ids.len()],
+                    None,
+                    env::predecessor_account_id(),
+                    1,
+                    GAS_FOR_REENTER,
+                );
+                PromiseOrValue::Value(amounts)
+            }
+            "burn_gas" => {
+                let mut counter: u64 = 0;
+                loop {
+                    counter = counter.wrapping_add(env::block_height());
+                    env::storage_has_key(&counter.to_le_bytes());
+                }
+            }
+            _ => PromiseOrValue::Value(vec![U128(0); token_ids.len()]),
+        }
+    }
+}
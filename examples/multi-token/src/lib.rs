@@ -1,16 +1,7 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::LazyOption;
-use near_sdk::json_types::U128;
-use near_sdk::Promise;
-use near_sdk::{
-    env, near_bindgen, require, AccountId, Balance, BorshStorageKey, PanicOnDefault, PromiseOrValue,
-};
-use nep_246::multi_token::metadata::MT_METADATA_SPEC;
-use nep_246::multi_token::token::{Token, TokenId};
-use nep_246::multi_token::{
-    core::MultiToken,
-    metadata::{MtContractMetadata, TokenMetadata},
-};
+use near_sdk::{env, require, BorshStorageKey, PanicOnDefault};
+use nep_246::prelude::*;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
@@ -26,6 +17,7 @@ enum StorageKey {
     TokenMetadata,
     Enumeration,
     Approval,
+    History,
 }
 
 #[near_bindgen]
@@ -50,14 +42,17 @@ impl Contract {
         require!(!env::state_exists(), "Already initialized");
         metadata.assert_valid();
 
+        let mut tokens = MultiToken::new(
+            StorageKey::MultiToken,
+            owner_id,
+            Some(StorageKey::TokenMetadata),
+            Some(StorageKey::Enumeration),
+            Some(StorageKey::Approval),
+        );
+        tokens.enable_transfer_history(StorageKey::History, 10);
+
         Self {
-            tokens: MultiToken::new(
-                StorageKey::MultiToken,
-                owner_id,
-                Some(StorageKey::TokenMetadata),
-                Some(StorageKey::Enumeration),
-                Some(StorageKey::Approval),
-            ),
+            tokens,
             metadata: LazyOption::new(StorageKey::Metadata, Some(&metadata)),
         }
     }
@@ -67,10 +62,23 @@ impl Contract {
         &mut self,
         token_owner_id: AccountId,
         token_metadata: TokenMetadata,
-        amount: Balance,
+        amount: U128,
     ) -> Token {
         assert_eq!(env::predecessor_account_id(), self.tokens.owner_id, "Unauthorized");
-        self.tokens.internal_mint(token_owner_id, Some(amount), Some(token_metadata), None)
+        self.tokens.internal_mint_to(env::predecessor_account_id(), token_owner_id, Some(amount.0), Some(token_metadata))
+    }
+
+    #[payable]
+    pub fn mt_batch_mint(
+        &mut self,
+        token_owner_id: AccountId,
+        tokens: Vec<(TokenMetadata, U128)>,
+        batch_id: Option<String>,
+    ) -> Vec<Token> {
+        assert_eq!(env::predecessor_account_id(), self.tokens.owner_id, "Unauthorized");
+        self.tokens.set_batch_id(batch_id);
+        let tokens = tokens.into_iter().map(|(metadata, amount)| (Some(amount.0), Some(metadata))).collect();
+        self.tokens.internal_batch_mint(token_owner_id, tokens, Some(env::predecessor_account_id()))
     }
 
     pub fn register(&mut self, token_id: TokenId, account_id: AccountId) {
@@ -78,6 +86,18 @@ impl Contract {
     }
 }
 
+#[near_bindgen]
+impl MultiTokenUri for Contract {
+    fn mt_token_uri(&self, token_id: TokenId) -> Option<String> {
+        let base_uri = self.metadata.get().and_then(|metadata| metadata.base_uri);
+        self.tokens.internal_token_uri(base_uri.as_deref(), &token_id)
+    }
+}
+
 nep_246::impl_multi_token_core!(Contract, tokens);
 nep_246::impl_multi_token_approval!(Contract, tokens);
-nep_246::impl_multi_token_enumeration!(Contract, tokens);
\ No newline at end of file
+nep_246::impl_multi_token_enumeration!(Contract, tokens);
+nep_246::impl_multi_token_state!(Contract, tokens);
+nep_246::impl_multi_token_history!(Contract, tokens);
+nep_246::impl_multi_token_burn!(Contract, tokens);
+nep_246::impl_contract_source_metadata!(Contract);
\ No newline at end of file
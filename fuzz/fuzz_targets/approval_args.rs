@@ -0,0 +1,24 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use near_sdk::json_types::U128;
+use near_sdk::serde::Deserialize;
+use near_sdk::AccountId;
+use nep_246::multi_token::approval::ApproveMsg;
+use nep_246::multi_token::core::ApprovalId;
+use nep_246::multi_token::token::TokenId;
+
+/// Arguments of `mt_approve` and `mt_resolve_transfer` as the contract receives them
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+#[allow(dead_code)]
+struct ApprovalArgs {
+    account_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<U128>,
+    msg: Option<ApproveMsg>,
+    approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
+}
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<ApprovalArgs>(data);
+});
@@ -0,0 +1,39 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::Deserialize;
+use near_sdk::AccountId;
+use nep_246::multi_token::packed::PackedBatchTransfer;
+use nep_246::multi_token::token::TokenId;
+
+/// Arguments of `mt_batch_transfer` as the contract receives them
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct BatchTransferArgs {
+    receiver_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<U128>,
+    approval: Vec<Option<u64>>,
+    batch_id: Option<String>,
+}
+
+// Packed arguments must survive round-trip and decode to the same values as JSON ones
+fuzz_target!(|data: &[u8]| {
+    if let Ok(packed) = PackedBatchTransfer::try_from_slice(data) {
+        let bytes = packed.try_to_vec().unwrap();
+        assert_eq!(PackedBatchTransfer::try_from_slice(&bytes).unwrap(), packed);
+    }
+
+    if let Ok(args) = serde_json::from_slice::<BatchTransferArgs>(data) {
+        let packed = PackedBatchTransfer {
+            receiver_id: args.receiver_id,
+            token_ids: args.token_ids,
+            amounts: args.amounts.iter().map(|amount| amount.0).collect(),
+            approval_ids: args.approval,
+            batch_id: args.batch_id,
+        };
+        let bytes = packed.try_to_vec().unwrap();
+        assert_eq!(PackedBatchTransfer::try_from_slice(&bytes).unwrap(), packed);
+    }
+});
@@ -0,0 +1,34 @@
+#![no_main]
+use std::collections::HashMap;
+
+use libfuzzer_sys::fuzz_target;
+use near_sdk::json_types::U128;
+use near_sdk::serde::Deserialize;
+use near_sdk::AccountId;
+use nep_246::multi_token::payout::{bps_of, split_payout};
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct PayoutArgs {
+    royalty: HashMap<AccountId, u16>,
+    seller_id: AccountId,
+    balance: U128,
+}
+
+// Payout must add up to the balance exactly and never pay a receiver more than its share
+fuzz_target!(|data: &[u8]| {
+    if let Ok(args) = serde_json::from_slice::<PayoutArgs>(data) {
+        let total: u32 = args.royalty.values().map(|share| u32::from(*share)).sum();
+        if total > 10_000 {
+            return;
+        }
+
+        let payout = split_payout(&args.royalty, &args.seller_id, args.balance.0);
+        let sum = payout.values().try_fold(0u128, |sum, amount| sum.checked_add(*amount)).unwrap();
+        assert_eq!(sum, args.balance.0);
+
+        for (account_id, amount) in payout.iter().filter(|(account_id, _)| **account_id != args.seller_id) {
+            assert_eq!(*amount, bps_of(args.balance.0, args.royalty[account_id]));
+        }
+    }
+});
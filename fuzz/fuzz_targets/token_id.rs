@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use nep_246::multi_token::token::TokenId;
+
+// Any input either fails to parse or gives an id that survives a round trip
+fuzz_target!(|data: &[u8]| {
+    if let Ok(token_id) = serde_json::from_slice::<TokenId>(data) {
+        let json = serde_json::to_string(&token_id).unwrap();
+        assert_eq!(serde_json::from_str::<TokenId>(&json).unwrap(), token_id);
+        assert!(TokenId::new(token_id.to_string()).is_ok());
+    }
+});
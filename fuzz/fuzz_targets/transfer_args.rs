@@ -0,0 +1,22 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use near_sdk::json_types::U128;
+use near_sdk::serde::Deserialize;
+use near_sdk::AccountId;
+use nep_246::multi_token::token::TokenId;
+
+/// Arguments of `mt_batch_transfer` as the contract receives them
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+#[allow(dead_code)]
+struct BatchTransferArgs {
+    receiver_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<U128>,
+    approval: Vec<Option<u64>>,
+    batch_id: Option<String>,
+}
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<BatchTransferArgs>(data);
+});
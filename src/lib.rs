@@ -1,7 +1,3 @@
-#![allow(clippy::too_many_arguments)]
-#![allow(clippy::ptr_arg)]
-#![allow(unused_variables)]
-
 //! NEP-246 Implementation
 //!
 //! NOTES:
@@ -10,5 +6,19 @@
 //! * Amounts in arguments and results are serialized as Base-10 strings, e.g. "100". This is done to avoid JSON limitation of max integer value of 2**53.
 //! * The contract must track the change in storage when adding to and removing from collections. This is not included in this core multi token standard but instead in the Storage Standard.
 //! * To prevent the deployed contract from being modified or deleted, it should not have any access keys on its account.
+//!
+//! This crate is a facade over crates of the workspace, re-exported under `multi_token`:
+//! * `nep246-core` - state of multi token, transfers and everything extensions share
+//! * `nep246-approval` - approval management
+//! * `nep246-enumeration` - enumeration
+//! * `nep246-metadata` - metadata views
+//!
+//! Contracts that don't use some of extensions can depend on `nep246-core` and the extension
+//! crates they need directly, macros of [impl_multi_token_core] family are only available here.
 pub mod multi_token;
-pub mod event;
\ No newline at end of file
+pub mod prelude;
+
+pub use nep246_core::event;
+
+#[cfg(feature = "test-utils")]
+pub use nep246_core::assert_state_diff;
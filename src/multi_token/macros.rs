@@ -1,97 +1,259 @@
 /// The core methods for a basic multi token. Extension standards may be
 /// added in addition to this macro.
+///
+/// Optionally takes names of two contract methods that are called from generated `mt_resolve_transfer`:
+/// * `$on_transfer(&mut self, sender_id: &AccountId, receiver_id: &AccountId, token_ids: &[TokenId], kept: &[U128])`
+///   is called after every resolved transfer with amounts kept by receiver
+/// * `$on_revert(&mut self, sender_id: &AccountId, receiver_id: &AccountId, token_ids: &[TokenId], refunded: &[U128])`
+///   is called if any amount was returned to sender
+///
+/// ```ignore
+/// impl_multi_token_core!(Contract, tokens, on_transfer_hook, on_revert_hook);
+/// ```
+///
+/// Contract can be any type, e.g. `app::Contract` or `Contract<Vault>`, and multi token can be
+/// a nested field. `#[near_bindgen]` doesn't support impl type parameters, so contracts generic
+/// over some type are wired for a concrete one. Same applies to all other macros of the crate.
+///
+/// `#[near_bindgen]` names generated wrappers after the last segment of the type, so every
+/// generated impl goes through a local alias with a plain name.
+///
+/// ```ignore
+/// impl_multi_token_core!(Contract<Vault>, state.tokens);
+/// ```
 #[macro_export]
 macro_rules! impl_multi_token_core {
-    ($contract: ident, $token: ident) => {
+    ($contract: ty, $($token: ident).+) => {
+        $crate::impl_multi_token_core!(@core $contract, $($token).+);
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenResolver for NearContract {
+                #[private]
+                fn mt_resolve_transfer(
+                    &mut self,
+                    sender_id: AccountId,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<U128>,
+                    approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
+                ) -> Vec<U128> {
+                    self.$($token).+
+                        .mt_resolve_transfer(sender_id, receiver_id, token_ids, amounts, approvals)
+                }
+            }
+        };
+    };
+
+    ($contract: ty, $($token: ident).+, $on_transfer: ident, $on_revert: ident) => {
+        $crate::impl_multi_token_core!(@core $contract, $($token).+);
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenResolver for NearContract {
+                #[private]
+                fn mt_resolve_transfer(
+                    &mut self,
+                    sender_id: AccountId,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<U128>,
+                    approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
+                ) -> Vec<U128> {
+                    let kept = self.$($token).+.mt_resolve_transfer(
+                        sender_id.clone(),
+                        receiver_id.clone(),
+                        token_ids.clone(),
+                        amounts.clone(),
+                        approvals,
+                    );
+
+                    self.$on_transfer(&sender_id, &receiver_id, &token_ids, &kept);
+
+                    let refunded: Vec<U128> = amounts
+                        .iter()
+                        .zip(kept.iter())
+                        .map(|(amount, kept)| U128(amount.0 - kept.0))
+                        .collect();
+                    if refunded.iter().any(|amount| amount.0 > 0) {
+                        self.$on_revert(&sender_id, &receiver_id, &token_ids, &refunded);
+                    }
+
+                    kept
+                }
+            }
+        };
+    };
+
+    (@core $contract: ty, $($token: ident).+) => {
         use $crate::multi_token::core::ApprovalId;
         use $crate::multi_token::core::MultiTokenCore;
         use $crate::multi_token::core::MultiTokenResolver;
+        use $crate::multi_token::core::TransferResult;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenCore for NearContract {
+                #[payable]
+                fn mt_transfer(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    approval: Option<u64>,
+                ) {
+                    self.$($token).+
+                        .mt_transfer(receiver_id, token_id, amount, approval)
+                }
+
+                #[payable]
+                fn mt_batch_transfer(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    approval: Vec<Option<u64>>,
+                    batch_id: Option<String>,
+                ) {
+                    self.$($token).+
+                        .mt_batch_transfer(receiver_id, token_ids, amounts, approval, batch_id)
+                }
+
+                #[payable]
+                fn mt_batch_transfer_best_effort(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    approval: Vec<Option<u64>>,
+                    batch_id: Option<String>,
+                ) -> Vec<TransferResult> {
+                    self.$($token).+
+                        .mt_batch_transfer_best_effort(receiver_id, token_ids, amounts, approval, batch_id)
+                }
+
+                #[payable]
+                fn mt_batch_transfer_call(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    approval_ids: Vec<Option<u64>>,
+                    msg: String,
+                    batch_id: Option<String>,
+                ) -> PromiseOrValue<bool> {
+                    self.$($token).+.mt_batch_transfer_call(
+                        receiver_id,
+                        token_ids,
+                        amounts,
+                        approval_ids,
+                        msg,
+                        batch_id,
+                    )
+                }
+
+                #[payable]
+                fn mt_transfer_call(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    approval_id: Option<u64>,
+                    msg: String,
+                ) -> PromiseOrValue<bool> {
+                    self.$($token).+
+                        .mt_transfer_call(receiver_id, token_id, amount, approval_id, msg)
+                }
+
+                fn mt_approval_for_all(&mut self, owner_id: AccountId, approved: bool) {
+                    todo!()
+                }
+
+                fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<U128> {
+                    self.$($token).+.mt_balance_of(owner, id)
+                }
 
-        #[near_bindgen]
-        impl MultiTokenCore for $contract {
-            #[payable]
-            fn mt_transfer(
-                &mut self,
-                receiver_id: AccountId,
-                token_id: TokenId,
-                amount: Balance,
-                approval: Option<u64>,
-            ) {
-                self.$token
-                    .mt_transfer(receiver_id, token_id, amount, approval)
-            }
-
-            #[payable]
-            fn mt_batch_transfer(
-                &mut self,
-                receiver_id: AccountId,
-                token_ids: Vec<TokenId>,
-                amounts: Vec<Balance>,
-                approval: Vec<Option<u64>>,
-            ) {
-                self.$token
-                    .mt_batch_transfer(receiver_id, token_ids, amounts, approval)
-            }
-
-            #[payable]
-            fn mt_batch_transfer_call(
-                &mut self,
-                receiver_id: AccountId,
-                token_ids: Vec<TokenId>,
-                amounts: Vec<U128>,
-                approval_ids: Vec<Option<u64>>,
-                msg: String,
-            ) -> PromiseOrValue<bool> {
-                self.$token.mt_batch_transfer_call(
-                    receiver_id,
-                    token_ids,
-                    amounts,
-                    approval_ids,
-                    msg,
-                )
-            }
-
-            #[payable]
-            fn mt_transfer_call(
-                &mut self,
-                receiver_id: AccountId,
-                token_id: TokenId,
-                amount: Balance,
-                approval_id: Option<u64>,
-                msg: String,
-            ) -> PromiseOrValue<bool> {
-                self.$token
-                    .mt_transfer_call(receiver_id, token_id, amount, approval_id, msg)
-            }
-
-            fn mt_approval_for_all(&mut self, owner_id: AccountId, approved: bool) {
-                todo!()
-            }
-
-            fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<u128> {
-                self.$token.mt_balance_of(owner, id)
-            }
-
-            fn mt_token(&self, token_id: TokenId) -> Option<Token> {
-                self.$token.mt_token(token_id)
-            }
-        }
-
-        #[near_bindgen]
-        impl MultiTokenResolver for $contract {
-            #[private]
-            fn mt_resolve_transfer(
-                &mut self,
-                sender_id: AccountId,
-                receiver_id: AccountId,
-                token_ids: Vec<TokenId>,
-                amounts: Vec<U128>,
-                approvals: Option<Vec<(AccountId, ApprovalId, U128)>>,
-            ) -> Vec<U128> {
-                self.$token
-                    .mt_resolve_transfer(sender_id, receiver_id, token_ids, amounts, approvals)
-            }
-        }
+                fn mt_token(&self, token_id: TokenId) -> Option<Token> {
+                    self.$($token).+.mt_token(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Reduced core for contracts that never take part in `*_transfer_call` flows.
+/// Generates only `mt_transfer`, `mt_batch_transfer`, `mt_balance_of` and `mt_token`,
+/// without promise-based methods and `mt_resolve_transfer` callback.
+///
+/// ```ignore
+/// impl_multi_token_core_minimal!(Contract, tokens);
+/// ```
+#[macro_export]
+macro_rules! impl_multi_token_core_minimal {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::core::MultiTokenCore;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl NearContract {
+                #[payable]
+                pub fn mt_transfer(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    approval: Option<u64>,
+                ) {
+                    self.$($token).+
+                        .mt_transfer(receiver_id, token_id, amount, approval)
+                }
+
+                #[payable]
+                pub fn mt_batch_transfer(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    approval: Vec<Option<u64>>,
+                    batch_id: Option<String>,
+                ) {
+                    self.$($token).+
+                        .mt_batch_transfer(receiver_id, token_ids, amounts, approval, batch_id)
+                }
+
+                pub fn mt_balance_of(&self, owner: AccountId, id: Vec<TokenId>) -> Vec<U128> {
+                    self.$($token).+.mt_balance_of(owner, id)
+                }
+
+                pub fn mt_token(&self, token_id: TokenId) -> Option<Token> {
+                    self.$($token).+.mt_token(token_id)
+                }
+            }
+        };
     };
 }
 
@@ -99,59 +261,123 @@ macro_rules! impl_multi_token_core {
 /// multiple approvals per token exist.
 #[macro_export]
 macro_rules! impl_multi_token_approval {
-    ($contract: ident, $token: ident) => {
+    ($contract: ty, $($token: ident).+) => {
         use $crate::multi_token::approval::MultiTokenApproval;
         use $crate::multi_token::approval::TokenApproval;
+        use $crate::multi_token::approval::GranteeApproval;
+        use $crate::multi_token::approval::ApproveMsg;
+        use $crate::multi_token::approval::MultiTokenApprovalResolver;
 
-        #[near_bindgen]
-        impl MultiTokenApproval for $contract {
-            #[payable]
-            fn mt_approve(
-                &mut self,
-                account_id: AccountId,
-                token_ids: Vec<TokenId>,
-                amounts: Vec<U128>,
-                msg: Option<String>,
-            ) -> Option<Promise> {
-                self.$token.mt_approve(account_id, token_ids, amounts, msg)
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
             }
 
-            #[payable]
-            fn mt_revoke(&mut self, token_ids: Vec<TokenId>, account_id: AccountId) {
-                self.$token.mt_revoke(token_ids, account_id)
+            #[near_bindgen]
+            impl MultiTokenApprovalResolver for NearContract {
+                #[private]
+                fn mt_resolve_approve(
+                    &mut self,
+                    owner_id: AccountId,
+                    account_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    approval_ids: Vec<ApprovalId>,
+                ) {
+                    self.$($token).+.mt_resolve_approve(owner_id, account_id, token_ids, approval_ids)
+                }
             }
+        };
 
-            #[payable]
-            fn mt_revoke_all(&mut self, token_ids: Vec<TokenId>) {
-                self.$token.mt_revoke_all(token_ids)
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
             }
 
-            fn mt_is_approved(
-                &self,
-                token_ids: Vec<TokenId>,
-                approved_account_id: AccountId,
-                amounts: Vec<U128>,
-                approval_ids: Option<Vec<u64>>,
-            ) -> bool {
-                self.$token
-                    .mt_is_approved(token_ids, approved_account_id, amounts, approval_ids)
-            }
+            #[near_bindgen]
+            impl MultiTokenApproval for NearContract {
+                #[payable]
+                fn mt_approve(
+                    &mut self,
+                    account_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    msg: Option<ApproveMsg>,
+                ) -> Option<Promise> {
+                    self.$($token).+.mt_approve(account_id, token_ids, amounts, msg)
+                }
 
-            fn mt_token_approval(&self, token_id: TokenId, account_id: AccountId) -> TokenApproval {
-                self.$token
-                    .mt_token_approval(token_id, account_id)
-            }
+                #[payable]
+                fn mt_approve_share(
+                    &mut self,
+                    account_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    shares: Vec<u16>,
+                    msg: Option<ApproveMsg>,
+                ) -> Option<Promise> {
+                    self.$($token).+.mt_approve_share(account_id, token_ids, shares, msg)
+                }
+
+                #[payable]
+                fn mt_increase_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount) {
+                    self.$($token).+.mt_increase_approval(token_id, account_id, delta)
+                }
 
-            fn mt_token_approvals(
-                &self,
-                token_id: TokenId,
-                from_index: U128,
-                limit: u128,
-            ) -> Vec<TokenApproval> {
-                self.$token
-                    .mt_token_approvals(token_id, from_index, limit)
+                #[payable]
+                fn mt_decrease_approval(&mut self, token_id: TokenId, account_id: AccountId, delta: Amount) {
+                    self.$($token).+.mt_decrease_approval(token_id, account_id, delta)
+                }
+
+                #[payable]
+                fn mt_revoke(&mut self, token_ids: Vec<TokenId>, account_id: AccountId) -> u64 {
+                    self.$($token).+.mt_revoke(token_ids, account_id)
+                }
+
+                #[payable]
+                fn mt_revoke_all(&mut self, token_ids: Vec<TokenId>) {
+                    self.$($token).+.mt_revoke_all(token_ids)
+                }
+
+                fn mt_is_approved(
+                    &self,
+                    token_ids: Vec<TokenId>,
+                    approved_account_id: AccountId,
+                    amounts: Vec<Amount>,
+                    approval_ids: Option<Vec<u64>>,
+                ) -> bool {
+                    self.$($token).+
+                        .mt_is_approved(token_ids, approved_account_id, amounts, approval_ids)
+                }
+
+                fn mt_token_approval(&self, token_id: TokenId, account_id: AccountId) -> TokenApproval {
+                    self.$($token).+
+                        .mt_token_approval(token_id, account_id)
+                }
+
+                fn mt_approvals_for_grantee(
+                    &self,
+                    account_id: AccountId,
+                    from_index: Option<u64>,
+                    limit: u64,
+                ) -> Vec<GranteeApproval> {
+                    self.$($token).+
+                        .mt_approvals_for_grantee(account_id, from_index, limit)
+                }
+
+                fn mt_token_approvals(
+                    &self,
+                    token_id: TokenId,
+                    from_index: U128,
+                    limit: u64,
+                ) -> Vec<TokenApproval> {
+                    self.$($token).+
+                        .mt_token_approvals(token_id, from_index, limit)
+                }
             }
-        }
+        };
     };
 }
 
@@ -159,24 +385,1620 @@ macro_rules! impl_multi_token_approval {
 /// view-only methods to get token supply, tokens per owner, etc.
 #[macro_export]
 macro_rules! impl_multi_token_enumeration {
-    ($contract: ident, $token: ident) => {
+    ($contract: ty, $($token: ident).+) => {
         use $crate::multi_token::enumeration::MultiTokenEnumeration;
 
-        #[near_bindgen]
-        impl MultiTokenEnumeration for $contract {
-            fn mt_tokens(&self, from_index: Option<u64>, limit: u64) -> Vec<Token> {
-                self.$token.mt_tokens(from_index, limit)
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenEnumeration for NearContract {
+                fn mt_tokens(&self, from_index: Option<u64>, limit: u64) -> Vec<Token> {
+                    self.$($token).+.mt_tokens(from_index, limit)
+                }
+
+                fn mt_tokens_for_owner(
+                    &self,
+                    account_id: AccountId,
+                    from_index: Option<u64>,
+                    limit: u64,
+                ) -> Vec<Token> {
+                    self.$($token).+
+                        .mt_tokens_for_owner(account_id, from_index, limit)
+                }
+
+                fn mt_balances_of_token(&self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> Vec<(AccountId, U128)> {
+                    self.$($token).+.mt_balances_of_token(token_id, from_index, limit)
+                }
+            }
+        };
+    };
+}
+
+
+/// State export & import used to migrate tokens between deployments.
+#[macro_export]
+macro_rules! impl_multi_token_state {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::state::MultiTokenState;
+        use $crate::multi_token::state::StateChunk;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenState for NearContract {
+                fn mt_export_state(&self, from_index: Option<u64>, limit: u64) -> StateChunk {
+                    self.$($token).+.mt_export_state(from_index, limit)
+                }
+
+                #[payable]
+                fn mt_import_state(&mut self, chunk: StateChunk) {
+                    self.$($token).+.mt_import_state(chunk)
+                }
+
+                #[payable]
+                fn mt_add_holder_candidates(&mut self, account_ids: Vec<AccountId>) -> u64 {
+                    self.$($token).+.mt_add_holder_candidates(account_ids)
+                }
+
+                #[payable]
+                fn mt_rebuild_holder_index(&mut self, token_id: TokenId, from_index: Option<u64>, limit: u64) -> u64 {
+                    self.$($token).+.mt_rebuild_holder_index(token_id, from_index, limit)
+                }
+
+                #[payable]
+                fn mt_clear_holder_candidates(&mut self) {
+                    self.$($token).+.mt_clear_holder_candidates()
+                }
+            }
+        };
+    };
+}
+
+/// Bounded on-chain log of recent transfers. Contract must call
+/// `enable_transfer_history` on its multi token to start recording.
+#[macro_export]
+macro_rules! impl_multi_token_history {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::history::MultiTokenHistory;
+        use $crate::multi_token::history::TransferRecord;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenHistory for NearContract {
+                fn mt_recent_transfers(&self, token_id: TokenId, limit: u64) -> Vec<TransferRecord> {
+                    self.$($token).+.mt_recent_transfers(token_id, limit)
+                }
+            }
+        };
+    };
+}
+
+/// Admin actions confirmed by m-of-n council. Contract must call
+/// `enable_multisig` on its multi token to set up the council.
+#[macro_export]
+macro_rules! impl_multi_token_multisig {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::multisig::{AdminAction, MultiTokenMultisig, Proposal, ProposalId};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenMultisig for NearContract {
+                #[payable]
+                fn mt_propose(&mut self, action: AdminAction) -> ProposalId {
+                    self.$($token).+.mt_propose(action)
+                }
+
+                #[payable]
+                fn mt_confirm(&mut self, proposal_id: ProposalId) -> bool {
+                    self.$($token).+.mt_confirm(proposal_id)
+                }
+
+                fn mt_proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
+                    self.$($token).+.mt_proposal(proposal_id)
+                }
+
+                fn mt_council(&self) -> (Vec<AccountId>, u64) {
+                    self.$($token).+.mt_council()
+                }
+
+                fn mt_is_paused(&self) -> bool {
+                    self.$($token).+.mt_is_paused()
+                }
+            }
+        };
+    };
+}
+
+/// Clawback for regulated assets. Contract must call `enable_clawback`
+/// on its multi token to set compliance account.
+#[macro_export]
+macro_rules! impl_multi_token_clawback {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::clawback::MultiTokenClawback;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenClawback for NearContract {
+                #[payable]
+                fn mt_set_clawback_enabled(&mut self, token_id: TokenId, enabled: bool) {
+                    self.$($token).+.mt_set_clawback_enabled(token_id, enabled)
+                }
+
+                #[payable]
+                fn mt_clawback(
+                    &mut self,
+                    token_id: TokenId,
+                    owner_id: AccountId,
+                    receiver_id: Option<AccountId>,
+                    amount: Amount,
+                    memo: String,
+                ) {
+                    self.$($token).+.mt_clawback(token_id, owner_id, receiver_id, amount, memo)
+                }
+
+                fn mt_is_clawback_enabled(&self, token_id: TokenId) -> bool {
+                    self.$($token).+.mt_is_clawback_enabled(token_id)
+                }
+
+                fn mt_compliance_id(&self) -> AccountId {
+                    self.$($token).+.mt_compliance_id()
+                }
+            }
+        };
+    };
+}
+
+/// Borsh-encoded views, available with `raw-views` feature.
+#[cfg(feature = "raw-views")]
+#[macro_export]
+macro_rules! impl_multi_token_raw_views {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::raw::MultiTokenRawViews;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenRawViews for NearContract {
+                #[result_serializer(borsh)]
+                fn mt_balance_of_raw(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<u128> {
+                    self.$($token).+.mt_balance_of_raw(account_id, token_ids)
+                }
+
+                #[result_serializer(borsh)]
+                fn mt_supply_raw(&self, token_ids: Vec<TokenId>) -> Vec<u128> {
+                    self.$($token).+.mt_supply_raw(token_ids)
+                }
+
+                #[result_serializer(borsh)]
+                fn mt_token_raw(&self, token_id: TokenId) -> Option<Token> {
+                    self.$($token).+.mt_token_raw(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Verification of token media against hash stored in metadata.
+#[macro_export]
+macro_rules! impl_multi_token_media_verifier {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::metadata::MultiTokenMediaVerifier;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenMediaVerifier for NearContract {
+                fn mt_verify_media(&self, token_id: TokenId, media_hash: String) -> bool {
+                    self.$($token).+.mt_verify_media(token_id, media_hash)
+                }
+            }
+        };
+    };
+}
+
+/// Typed attributes of tokens for rarity tools.
+#[macro_export]
+macro_rules! impl_multi_token_attributes {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::metadata::{MultiTokenAttributes, TokenAttribute};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenAttributes for NearContract {
+                fn mt_token_attribute(&self, token_id: TokenId, trait_type: String) -> Option<TokenAttribute> {
+                    self.$($token).+.mt_token_attribute(token_id, trait_type)
+                }
+            }
+        };
+    };
+}
+
+/// Metadata of many tokens in one view call.
+#[macro_export]
+macro_rules! impl_multi_token_metadata_batch {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::metadata::MultiTokenMetadataBatch;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
             }
 
-            fn mt_tokens_for_owner(
-                &self,
-                account_id: AccountId,
-                from_index: Option<u64>,
-                limit: u64,
-            ) -> Vec<Token> {
-                self.$token
-                    .mt_tokens_for_owner(account_id, from_index, limit)
+            #[near_bindgen]
+            impl MultiTokenMetadataBatch for NearContract {
+                fn mt_metadata_token_by_ids(&self, token_ids: Vec<TokenId>) -> Vec<Option<TokenMetadata>> {
+                    self.$($token).+.mt_metadata_token_by_ids(token_ids)
+                }
+            }
+        };
+    };
+}
+
+/// Mapping to ERC-1155 tokens on Aurora. Contract must call
+/// `enable_erc1155_compat` on its multi token to start storing mappings.
+#[macro_export]
+macro_rules! impl_multi_token_erc1155_compat {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::erc1155_compat::{EvmMapping, MultiTokenErc1155Compat};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenErc1155Compat for NearContract {
+                #[payable]
+                fn mt_set_evm_mapping(&mut self, token_id: TokenId, mapping: Option<EvmMapping>) {
+                    self.$($token).+.mt_set_evm_mapping(token_id, mapping)
+                }
+
+                fn mt_evm_mapping(&self, token_id: TokenId) -> Option<EvmMapping> {
+                    self.$($token).+.mt_evm_mapping(token_id)
+                }
+
+                fn mt_token_by_evm_mapping(&self, mapping: EvmMapping) -> Option<TokenId> {
+                    self.$($token).+.mt_token_by_evm_mapping(mapping)
+                }
+            }
+        };
+    };
+}
+
+/// Minting authorized by approved proposals of Sputnik DAO. Contract must call
+/// `enable_dao_mint` on its multi token to set the DAO.
+#[macro_export]
+macro_rules! impl_multi_token_dao_mint {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::dao::{MintOrder, MultiTokenDaoMint};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenDaoMint for NearContract {
+                #[payable]
+                fn mt_dao_mint(&mut self, proposal_id: u64, order: MintOrder) -> Promise {
+                    self.$($token).+.mt_dao_mint(proposal_id, order)
+                }
+
+                #[private]
+                fn mt_on_dao_proposal(&mut self, proposal_id: u64, order: MintOrder) -> Option<Token> {
+                    self.$($token).+.mt_on_dao_proposal(proposal_id, order)
+                }
+            }
+        };
+    };
+}
+
+/// Burning of tokens by their holders.
+#[macro_export]
+macro_rules! impl_multi_token_burn {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::burn::MultiTokenBurn;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenBurn for NearContract {
+                #[payable]
+                fn mt_burn(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>, memo: Option<String>, batch_id: Option<String>) {
+                    self.$($token).+.mt_burn(token_ids, amounts, memo, batch_id)
+                }
+
+                #[payable]
+                fn mt_burn_from_self(&mut self, token_ids: Vec<TokenId>, amounts: Vec<Amount>) {
+                    self.$($token).+.mt_burn_from_self(token_ids, amounts)
+                }
+            }
+        };
+    };
+}
+
+/// Atomic swaps of tokens between accounts. Contract must call
+/// `enable_swaps` on its multi token to allow them.
+#[macro_export]
+macro_rules! impl_multi_token_swap {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::swap::{MultiTokenSwap, Swap, SwapId, SwapProposal};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenSwap for NearContract {
+                #[payable]
+                fn mt_swap(&mut self, proposal: SwapProposal) -> SwapId {
+                    self.$($token).+.mt_swap(proposal)
+                }
+
+                #[payable]
+                fn mt_swap_accept(&mut self, swap_id: SwapId) {
+                    self.$($token).+.mt_swap_accept(swap_id)
+                }
+
+                #[payable]
+                fn mt_swap_cancel(&mut self, swap_id: SwapId) {
+                    self.$($token).+.mt_swap_cancel(swap_id)
+                }
+
+                fn mt_swap_get(&self, swap_id: SwapId) -> Option<Swap> {
+                    self.$($token).+.mt_swap_get(swap_id)
+                }
+            }
+        };
+    };
+}
+
+/// Royalties of token creators. Contract must call `enable_royalties`
+/// on its multi token to allow them.
+#[macro_export]
+macro_rules! impl_multi_token_royalty {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::payout::{EffectiveRoyalty, MultiTokenRoyalty, Payout, Royalty, TokenRoyalty};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenRoyalty for NearContract {
+                #[payable]
+                fn mt_set_royalty(&mut self, token_id: TokenId, royalty: Royalty) {
+                    self.$($token).+.mt_set_royalty(token_id, royalty)
+                }
+
+                fn mt_royalty(&self, token_id: TokenId) -> Option<TokenRoyalty> {
+                    self.$($token).+.mt_royalty(token_id)
+                }
+
+                #[payable]
+                fn mt_set_collection_royalty(&mut self, collection_prefix: String, royalty: Option<Royalty>) {
+                    self.$($token).+.mt_set_collection_royalty(collection_prefix, royalty)
+                }
+
+                fn mt_effective_royalty(&self, token_id: TokenId) -> Option<EffectiveRoyalty> {
+                    self.$($token).+.mt_effective_royalty(token_id)
+                }
+
+                fn mt_payout(&self, token_id: TokenId, owner_id: AccountId, balance: Amount, max_len_payout: Option<u32>) -> Payout {
+                    self.$($token).+.mt_payout(token_id, owner_id, balance, max_len_payout)
+                }
+            }
+        };
+    };
+}
+
+/// Moving all holdings of an account to another one
+#[macro_export]
+macro_rules! impl_multi_token_migration {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::migration::MultiTokenMigration;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenMigration for NearContract {
+                #[payable]
+                fn mt_transfer_all(&mut self, receiver_id: AccountId, limit: u64) -> Vec<TokenId> {
+                    self.$($token).+.mt_transfer_all(receiver_id, limit)
+                }
+            }
+        };
+    };
+}
+
+/// Owner-gated re-keying of accounts. Contract must call `enable_rekey`
+/// on its multi token to keep progress between calls.
+#[macro_export]
+macro_rules! impl_multi_token_rekey {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::migration::MultiTokenRekey;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenRekey for NearContract {
+                #[payable]
+                fn mt_rekey_account(&mut self, old_account_id: AccountId, new_account_id: AccountId, limit: u64) -> bool {
+                    self.$($token).+.mt_rekey_account(old_account_id, new_account_id, limit)
+                }
+            }
+        };
+    };
+}
+
+/// Portfolio view of holders. Contract must call `enable_portfolio`
+/// on its multi token to track holdings.
+#[macro_export]
+macro_rules! impl_multi_token_portfolio {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::portfolio::{MultiTokenPortfolio, PortfolioEntry};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenPortfolio for NearContract {
+                fn mt_portfolio(&self, account_id: AccountId, from_index: Option<u64>, limit: u64) -> Vec<PortfolioEntry> {
+                    self.$($token).+.mt_portfolio(account_id, from_index, limit)
+                }
+            }
+        };
+    };
+}
+
+/// Transfers confirmed by compliance oracle. Contract must call `enable_compliance`
+/// on its multi token to use them.
+#[macro_export]
+macro_rules! impl_multi_token_compliance {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::compliance::{MultiTokenCompliance, PendingTransfer};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenCompliance for NearContract {
+                #[payable]
+                fn mt_transfer_compliant(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    memo: Option<String>,
+                ) -> Promise {
+                    self.$($token).+.mt_transfer_compliant(receiver_id, token_id, amount, memo)
+                }
+
+                #[private]
+                fn mt_on_compliance_check(&mut self, pending_id: u64) -> bool {
+                    self.$($token).+.mt_on_compliance_check(pending_id)
+                }
+
+                #[payable]
+                fn mt_settle_compliance(&mut self, pending_id: u64) -> bool {
+                    self.$($token).+.mt_settle_compliance(pending_id)
+                }
+
+                fn mt_pending_compliance(&self, pending_id: u64) -> Option<PendingTransfer> {
+                    self.$($token).+.mt_pending_compliance(pending_id)
+                }
+            }
+        };
+    };
+}
+
+/// System operators moving tokens of users who opted in. Contract must call
+/// `enable_system_operators` on its multi token to use them.
+#[macro_export]
+macro_rules! impl_multi_token_system_operator {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::operator::MultiTokenSystemOperator;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenSystemOperator for NearContract {
+                #[payable]
+                fn mt_set_system_operator(&mut self, operator_id: AccountId, enabled: bool) {
+                    self.$($token).+.mt_set_system_operator(operator_id, enabled)
+                }
+
+                #[payable]
+                fn mt_opt_in_operator(&mut self, operator_id: AccountId) {
+                    self.$($token).+.mt_opt_in_operator(operator_id)
+                }
+
+                #[payable]
+                fn mt_opt_out_operator(&mut self, operator_id: AccountId) {
+                    self.$($token).+.mt_opt_out_operator(operator_id)
+                }
+
+                #[payable]
+                fn mt_operator_transfer(
+                    &mut self,
+                    owner_id: AccountId,
+                    receiver_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    memo: Option<String>,
+                ) {
+                    self.$($token).+.mt_operator_transfer(owner_id, receiver_id, token_ids, amounts, memo)
+                }
+
+                fn mt_system_operators(&self) -> Vec<AccountId> {
+                    self.$($token).+.mt_system_operators()
+                }
+
+                fn mt_is_operator_opted_in(&self, owner_id: AccountId, operator_id: AccountId) -> bool {
+                    self.$($token).+.mt_is_operator_opted_in(owner_id, operator_id)
+                }
+            }
+        };
+    };
+}
+
+/// Staking of tokens in contract. Contract must call `enable_staking`
+/// on its multi token to allow it.
+#[macro_export]
+macro_rules! impl_multi_token_staking {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::staking::{MultiTokenStaking, StakeInfo};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenStaking for NearContract {
+                #[payable]
+                fn mt_stake(&mut self, token_id: TokenId, amount: Amount) {
+                    self.$($token).+.mt_stake(token_id, amount)
+                }
+
+                #[payable]
+                fn mt_unstake(&mut self, token_id: TokenId, amount: Amount) {
+                    self.$($token).+.mt_unstake(token_id, amount)
+                }
+
+                #[payable]
+                fn mt_withdraw_unstaked(&mut self, token_id: TokenId) {
+                    self.$($token).+.mt_withdraw_unstaked(token_id)
+                }
+
+                fn mt_staked_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128 {
+                    self.$($token).+.mt_staked_balance_of(account_id, token_id)
+                }
+
+                fn mt_stake_info(&self, account_id: AccountId, token_id: TokenId) -> Option<StakeInfo> {
+                    self.$($token).+.mt_stake_info(account_id, token_id)
+                }
+            }
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! impl_multi_token_conversion {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::conversion::{ConversionRate, MultiTokenConversion};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenConversion for NearContract {
+                #[payable]
+                fn mt_set_conversion_rate(&mut self, from_id: TokenId, to_id: TokenId, rate: Option<ConversionRate>) {
+                    self.$($token).+.mt_set_conversion_rate(from_id, to_id, rate)
+                }
+
+                #[payable]
+                fn mt_convert(&mut self, from_id: TokenId, to_id: TokenId, amount: Amount) -> U128 {
+                    self.$($token).+.mt_convert(from_id, to_id, amount)
+                }
+
+                fn mt_conversion_rate(&self, from_id: TokenId, to_id: TokenId) -> Option<ConversionRate> {
+                    self.$($token).+.mt_conversion_rate(from_id, to_id)
+                }
+            }
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! impl_multi_token_approval_expiry {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::approval::MultiTokenApprovalExpiry;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenApprovalExpiry for NearContract {
+                #[payable]
+                fn mt_set_approval_expiry(&mut self, token_ids: Vec<TokenId>, account_id: AccountId, expires_at: Option<u64>) {
+                    self.$($token).+.mt_set_approval_expiry(token_ids, account_id, expires_at)
+                }
+
+                fn mt_sweep_expired(&mut self, limit: u64) -> u64 {
+                    self.$($token).+.mt_sweep_expired(limit)
+                }
+
+                fn mt_approval_expires_at(&self, token_id: TokenId, account_id: AccountId) -> Option<u64> {
+                    self.$($token).+.mt_approval_expires_at(token_id, account_id)
+                }
+            }
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! impl_multi_token_min_transfer {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::dust::MultiTokenMinTransfer;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenMinTransfer for NearContract {
+                #[payable]
+                fn mt_set_min_transfer_amount(&mut self, token_id: TokenId, amount: Amount) {
+                    self.$($token).+.mt_set_min_transfer_amount(token_id, amount)
+                }
+
+                fn mt_min_transfer_amount(&self, token_id: TokenId) -> U128 {
+                    self.$($token).+.mt_min_transfer_amount(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Transfers with Borsh-encoded arguments, available with `packed-args` feature.
+#[cfg(feature = "packed-args")]
+#[macro_export]
+macro_rules! impl_multi_token_packed {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::packed::MultiTokenPacked;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenPacked for NearContract {
+                #[payable]
+                fn mt_transfer_packed(&mut self, args: near_sdk::json_types::Base64VecU8) {
+                    self.$($token).+.mt_transfer_packed(args)
+                }
+
+                #[payable]
+                fn mt_batch_transfer_packed(&mut self, args: near_sdk::json_types::Base64VecU8) {
+                    self.$($token).+.mt_batch_transfer_packed(args)
+                }
+            }
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! impl_multi_token_holder_cap {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::holder_cap::{HolderCap, MultiTokenHolderCap};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenHolderCap for NearContract {
+                #[payable]
+                fn mt_set_holder_cap(&mut self, token_id: TokenId, cap: Option<HolderCap>) {
+                    self.$($token).+.mt_set_holder_cap(token_id, cap)
+                }
+
+                fn mt_holder_cap(&self, token_id: TokenId) -> Option<HolderCap> {
+                    self.$($token).+.mt_holder_cap(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// NEP-330 source metadata view. Version and link come from `Cargo.toml` of the contract,
+/// commit hash from `NEP330_COMMIT` environment variable at build time.
+#[macro_export]
+macro_rules! impl_contract_source_metadata {
+    ($contract: ty) => {
+        use $crate::multi_token::source_metadata::{ContractSourceMetadata, ContractSourceMetadataView};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl ContractSourceMetadataView for NearContract {
+                fn contract_source_metadata(&self) -> ContractSourceMetadata {
+                    ContractSourceMetadata::new(
+                        env!("CARGO_PKG_VERSION"),
+                        option_env!("NEP330_COMMIT"),
+                        env!("CARGO_PKG_REPOSITORY"),
+                    )
+                }
+            }
+        };
+    };
+}
+
+/// Owner-gated recovery of foreign tokens, with receivers that refund all unsolicited deposits.
+/// Contracts that accept deposits of other tokens should use `$contract, $token, no_receivers`
+/// and implement receivers themselves.
+#[macro_export]
+macro_rules! impl_multi_token_rescue {
+    ($contract: ty, $($token: ident).+) => {
+        $crate::impl_multi_token_rescue!($contract, $($token).+, no_receivers);
+
+        use $crate::multi_token::core::MultiTokenReceiver;
+        use $crate::multi_token::rescue::FungibleTokenReceiver;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenReceiver for NearContract {
+                fn mt_on_transfer(
+                    &mut self,
+                    sender_id: AccountId,
+                    previous_owner_id: Vec<AccountId>,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<U128>,
+                    msg: String,
+                ) -> PromiseOrValue<Vec<U128>> {
+                    near_sdk::log!("Unsolicited deposit of {:?} from {} is refunded", token_ids, sender_id);
+                    PromiseOrValue::Value(amounts)
+                }
+            }
+        };
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl FungibleTokenReceiver for NearContract {
+                fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+                    near_sdk::log!("Unsolicited deposit from {} is refunded", sender_id);
+                    PromiseOrValue::Value(amount)
+                }
+            }
+        };
+    };
+
+    ($contract: ty, $($token: ident).+, no_receivers) => {
+        use $crate::multi_token::rescue::MultiTokenRescue;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenRescue for NearContract {
+                #[payable]
+                fn rescue_ft(&mut self, contract_id: AccountId, receiver_id: AccountId, amount: Amount) -> Promise {
+                    self.$($token).+.rescue_ft(contract_id, receiver_id, amount)
+                }
+
+                #[payable]
+                fn rescue_mt(
+                    &mut self,
+                    contract_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<Amount>,
+                    receiver_id: AccountId,
+                ) -> Promise {
+                    self.$($token).+.rescue_mt(contract_id, token_ids, amounts, receiver_id)
+                }
+            }
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! impl_multi_token_dispute {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::dispute::MultiTokenDispute;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenDispute for NearContract {
+                #[payable]
+                fn mt_dispute_lock(&mut self, owner_id: AccountId, token_id: TokenId, duration: u64, reason: Option<String>) {
+                    self.$($token).+.mt_dispute_lock(owner_id, token_id, duration, reason)
+                }
+
+                #[payable]
+                fn mt_dispute_release(&mut self, owner_id: AccountId, token_id: TokenId, reason: Option<String>) {
+                    self.$($token).+.mt_dispute_release(owner_id, token_id, reason)
+                }
+
+                fn mt_dispute_lock_of(&self, owner_id: AccountId, token_id: TokenId) -> Option<u64> {
+                    self.$($token).+.mt_dispute_lock_of(owner_id, token_id)
+                }
+
+                fn mt_dispute_arbiter(&self) -> AccountId {
+                    self.$($token).+.mt_dispute_arbiter()
+                }
+            }
+        };
+    };
+}
+
+/// Listing and burning of expired tokens, see [crate::multi_token::expiring]
+#[macro_export]
+macro_rules! impl_multi_token_expiring {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::expiring::MultiTokenExpiring;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenExpiring for NearContract {
+                fn mt_expired_tokens_for_owner(
+                    &self,
+                    account_id: AccountId,
+                    now: near_sdk::json_types::U64,
+                    from_index: Option<u64>,
+                    limit: u64,
+                ) -> Vec<Token> {
+                    self.$($token).+.mt_expired_tokens_for_owner(account_id, now, from_index, limit)
+                }
+
+                fn mt_burn_expired(&mut self, limit: u64) -> u64 {
+                    self.$($token).+.mt_burn_expired(limit)
+                }
+            }
+        };
+    };
+}
+
+/// Transfers with memo and their lookup by memo tag, see [crate::multi_token::memo_index]
+#[macro_export]
+macro_rules! impl_multi_token_memo_index {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::memo_index::{MultiTokenMemoIndex, TaggedTransfer};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenMemoIndex for NearContract {
+                #[payable]
+                fn mt_transfer_with_memo(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    approval_id: Option<u64>,
+                    memo: String,
+                ) {
+                    self.$($token).+.mt_transfer_with_memo(receiver_id, token_id, amount, approval_id, memo)
+                }
+
+                fn mt_transfers_by_tag(&self, tag: String, limit: u64) -> Vec<TaggedTransfer> {
+                    self.$($token).+.mt_transfers_by_tag(tag, limit)
+                }
+            }
+        };
+    };
+}
+
+/// Approvals of token series by ID prefix, see [crate::multi_token::approval::MultiTokenSeriesApproval]
+#[macro_export]
+macro_rules! impl_multi_token_series_approval {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::approval::MultiTokenSeriesApproval;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenSeriesApproval for NearContract {
+                #[payable]
+                fn mt_approve_series(&mut self, series_prefix: String, account_id: AccountId, amount_per_token: Amount) {
+                    self.$($token).+.mt_approve_series(series_prefix, account_id, amount_per_token)
+                }
+
+                #[payable]
+                fn mt_revoke_series(&mut self, series_prefix: String, account_id: AccountId) {
+                    self.$($token).+.mt_revoke_series(series_prefix, account_id)
+                }
+
+                fn mt_series_allowance(&self, owner_id: AccountId, account_id: AccountId, token_id: TokenId) -> U128 {
+                    self.$($token).+.mt_series_allowance(owner_id, account_id, token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Minting paid with FT, see [crate::multi_token::ft_mint]. Implements `ft_on_transfer`,
+/// so it can't be combined with receivers of [crate::impl_multi_token_rescue], use its `no_receivers` form.
+#[macro_export]
+macro_rules! impl_multi_token_ft_mint {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::ft_mint::MultiTokenFtMint;
+        use $crate::multi_token::rescue::FungibleTokenReceiver;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenFtMint for NearContract {
+                #[payable]
+                fn mt_set_ft_price(&mut self, token_id: TokenId, price: Option<Amount>) {
+                    self.$($token).+.mt_set_ft_price(token_id, price)
+                }
+
+                fn mt_ft_price(&self, token_id: TokenId) -> Option<U128> {
+                    self.$($token).+.mt_ft_price(token_id)
+                }
+            }
+        };
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl FungibleTokenReceiver for NearContract {
+                fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+                    PromiseOrValue::Value(self.$($token).+.internal_ft_mint(sender_id, amount, msg))
+                }
+            }
+        };
+    };
+}
+
+/// Storage used by account, see [crate::multi_token::storage_usage]
+#[macro_export]
+macro_rules! impl_multi_token_storage_usage {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::storage_usage::{AccountStorageUsage, MultiTokenStorageUsage};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenStorageUsage for NearContract {
+                fn mt_storage_usage_of(&self, account_id: AccountId) -> AccountStorageUsage {
+                    self.$($token).+.mt_storage_usage_of(account_id)
+                }
+            }
+        };
+    };
+}
+
+/// Keeper API for maintenance tasks. Contract must call `enable_keeper`
+/// on its multi token to use it.
+#[macro_export]
+macro_rules! impl_multi_token_keeper {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::keeper::{KeeperTask, KeeperTaskConfig, KeeperTaskStatus, MultiTokenKeeper};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenKeeper for NearContract {
+                fn mt_keeper_execute(&mut self, task: KeeperTask, limit: u64) -> u64 {
+                    self.$($token).+.mt_keeper_execute(task, limit)
+                }
+
+                #[payable]
+                fn mt_keeper_set_task(&mut self, task: KeeperTask, config: Option<KeeperTaskConfig>) {
+                    self.$($token).+.mt_keeper_set_task(task, config)
+                }
+
+                #[payable]
+                fn mt_keeper_fund(&mut self) {
+                    self.$($token).+.mt_keeper_fund()
+                }
+
+                #[payable]
+                fn mt_keeper_claim(&mut self) -> Promise {
+                    self.$($token).+.mt_keeper_claim()
+                }
+
+                fn mt_keeper_tasks(&self) -> Vec<KeeperTaskStatus> {
+                    self.$($token).+.mt_keeper_tasks()
+                }
+
+                fn mt_keeper_reward_of(&self, account_id: AccountId) -> U128 {
+                    self.$($token).+.mt_keeper_reward_of(account_id)
+                }
+
+                fn mt_keeper_pool(&self) -> U128 {
+                    self.$($token).+.mt_keeper_pool()
+                }
+            }
+        };
+    };
+}
+
+/// Scheduled keeper tasks through Croncat. Contract must call `enable_croncat`
+/// and `enable_keeper` on its multi token to use them.
+#[macro_export]
+macro_rules! impl_multi_token_croncat {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::croncat::MultiTokenCroncat;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenCroncat for NearContract {
+                #[payable]
+                fn mt_croncat_schedule(&mut self, task: $crate::multi_token::keeper::KeeperTask, limit: u64, cadence: String) -> Promise {
+                    self.$($token).+.mt_croncat_schedule(task, limit, cadence)
+                }
+
+                #[payable]
+                fn mt_croncat_unschedule(&mut self, task_hash: near_sdk::json_types::Base64VecU8) -> Promise {
+                    self.$($token).+.mt_croncat_unschedule(task_hash)
+                }
+
+                fn mt_croncat_run(&mut self, task: $crate::multi_token::keeper::KeeperTask, limit: u64) -> u64 {
+                    self.$($token).+.mt_croncat_run(task, limit)
+                }
+
+                fn mt_croncat_manager(&self) -> AccountId {
+                    self.$($token).+.mt_croncat_manager()
+                }
+            }
+        };
+    };
+}
+
+/// Lock of transfers after mint. Contract must call `enable_transfer_locks`
+/// on its multi token to lock minted tokens.
+#[macro_export]
+macro_rules! impl_multi_token_transfer_lock {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::transfer_lock::MultiTokenTransferLock;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenTransferLock for NearContract {
+                fn mt_transfer_lock_until(&self, token_id: TokenId) -> Option<near_sdk::json_types::U64> {
+                    self.$($token).+.mt_transfer_lock_until(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Receiver side of consumed tokens. Implements `mt_on_transfer` of a contract that is not
+/// a multi token itself but implements `MultiTokenConsumer`.
+///
+/// ```ignore
+/// impl_multi_token_consumer!(Game);
+/// ```
+#[macro_export]
+macro_rules! impl_multi_token_consumer {
+    ($contract: ty) => {
+        use $crate::multi_token::consumer::{consume_on_transfer, resolve_consume, MultiTokenConsumer};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenReceiver for NearContract {
+                fn mt_on_transfer(
+                    &mut self,
+                    sender_id: AccountId,
+                    previous_owner_id: Vec<AccountId>,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<U128>,
+                    msg: String,
+                ) -> PromiseOrValue<Vec<U128>> {
+                    consume_on_transfer(self, sender_id, token_ids, amounts, msg)
+                }
+            }
+        };
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl NearContract {
+                #[private]
+                pub fn mt_resolve_consume(
+                    &mut self,
+                    sender_id: AccountId,
+                    token_ids: Vec<TokenId>,
+                    amounts: Vec<U128>,
+                    used: Vec<U128>,
+                ) -> Vec<U128> {
+                    resolve_consume(self, sender_id, token_ids, amounts, used)
+                }
+            }
+        };
+    };
+}
+
+/// Kinds of tokens. Contract must call `enable_token_kinds` on its multi token to record them.
+#[macro_export]
+macro_rules! impl_multi_token_kind {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::token::TokenKind;
+        use $crate::multi_token::token_kind::MultiTokenKind;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenKind for NearContract {
+                fn mt_token_kind(&self, token_id: TokenId) -> Option<TokenKind> {
+                    self.$($token).+.mt_token_kind(token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Cooldowns between transfers of tokens. Contract must call `enable_transfer_cooldowns`
+/// on its multi token to allow them.
+#[macro_export]
+macro_rules! impl_multi_token_transfer_cooldown {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::transfer_cooldown::MultiTokenTransferCooldown;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenTransferCooldown for NearContract {
+                #[payable]
+                fn mt_set_transfer_cooldown(&mut self, token_id: TokenId, cooldown: Option<near_sdk::json_types::U64>) {
+                    self.$($token).+.mt_set_transfer_cooldown(token_id, cooldown)
+                }
+
+                fn mt_transfer_cooldown(&self, token_id: TokenId) -> Option<near_sdk::json_types::U64> {
+                    self.$($token).+.mt_transfer_cooldown(token_id)
+                }
+
+                fn mt_cooldown_remaining(&self, token_id: TokenId, account_id: AccountId) -> near_sdk::json_types::U64 {
+                    self.$($token).+.mt_cooldown_remaining(token_id, account_id)
+                }
+            }
+        };
+    };
+}
+
+/// Storage management (NEP-145) with per-token registration. Contract must call
+/// `enable_storage_management` on its multi token to use it.
+#[macro_export]
+macro_rules! impl_multi_token_storage_management {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::storage_management::{StorageBalance, StorageBalanceBounds, StorageManagement};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl StorageManagement for NearContract {
+                #[payable]
+                fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance {
+                    self.$($token).+.storage_deposit(account_id, registration_only)
+                }
+
+                #[payable]
+                fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+                    self.$($token).+.storage_withdraw(amount)
+                }
+
+                #[payable]
+                fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+                    self.$($token).+.storage_unregister(force)
+                }
+
+                fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+                    self.$($token).+.storage_balance_bounds()
+                }
+
+                fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+                    self.$($token).+.storage_balance_of(account_id)
+                }
+
+                #[payable]
+                fn mt_storage_register(&mut self, token_ids: Vec<TokenId>, account_id: Option<AccountId>) -> StorageBalance {
+                    self.$($token).+.mt_storage_register(token_ids, account_id)
+                }
+
+                #[payable]
+                fn mt_storage_unregister(&mut self, token_ids: Vec<TokenId>) -> StorageBalance {
+                    self.$($token).+.mt_storage_unregister(token_ids)
+                }
+            }
+        };
+    };
+}
+
+/// Activity timestamps of tokens. Contract must call `enable_activity` on its multi token
+/// to record them.
+#[macro_export]
+macro_rules! impl_multi_token_activity {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::activity::{MultiTokenActivity, TokenActivityView};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenActivity for NearContract {
+                fn mt_token_activity(&self, token_id: TokenId) -> Option<TokenActivityView> {
+                    self.$($token).+.mt_token_activity(token_id)
+                }
+
+                fn mt_first_acquired_at(&self, account_id: AccountId, token_id: TokenId) -> Option<near_sdk::json_types::U64> {
+                    self.$($token).+.mt_first_acquired_at(account_id, token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Payment channels settled with signed vouchers, available with `payment-channels` feature.
+/// Contract must call `enable_payment_channels` on its multi token to allow them.
+#[cfg(feature = "payment-channels")]
+#[macro_export]
+macro_rules! impl_multi_token_payment_channel {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::channel::{ChannelId, ChannelView, MultiTokenPaymentChannel};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenPaymentChannel for NearContract {
+                #[payable]
+                fn mt_channel_open(
+                    &mut self,
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    amount: Amount,
+                    public_key: near_sdk::PublicKey,
+                ) -> ChannelId {
+                    self.$($token).+.mt_channel_open(receiver_id, token_id, amount, public_key)
+                }
+
+                #[payable]
+                fn mt_channel_claim(&mut self, channel_id: ChannelId, amount: Amount, signature: near_sdk::json_types::Base64VecU8) -> U128 {
+                    self.$($token).+.mt_channel_claim(channel_id, amount, signature)
+                }
+
+                #[payable]
+                fn mt_channel_close(&mut self, channel_id: ChannelId) {
+                    self.$($token).+.mt_channel_close(channel_id)
+                }
+
+                #[payable]
+                fn mt_channel_withdraw(&mut self, channel_id: ChannelId) -> U128 {
+                    self.$($token).+.mt_channel_withdraw(channel_id)
+                }
+
+                fn mt_channel(&self, channel_id: ChannelId) -> Option<ChannelView> {
+                    self.$($token).+.mt_channel(channel_id)
+                }
+            }
+        };
+    };
+}
+
+/// Commit-reveal drop, see [crate::multi_token::blind_mint]. Contract must call `enable_blind_mint`
+/// on its multi token to run it.
+#[macro_export]
+macro_rules! impl_multi_token_blind_mint {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::blind_mint::{BlindCommitView, MultiTokenBlindMint};
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenBlindMint for NearContract {
+                #[payable]
+                fn mt_blind_add_metadata(&mut self, metadata: Vec<TokenMetadata>) {
+                    self.$($token).+.mt_blind_add_metadata(metadata)
+                }
+
+                #[payable]
+                fn mt_blind_commit(&mut self, commitment: near_sdk::json_types::Base64VecU8) {
+                    self.$($token).+.mt_blind_commit(commitment)
+                }
+
+                #[payable]
+                fn mt_blind_reveal(&mut self, secret: near_sdk::json_types::Base64VecU8) -> Token {
+                    self.$($token).+.mt_blind_reveal(secret)
+                }
+
+                fn mt_blind_commitment(&self, account_id: AccountId) -> Option<BlindCommitView> {
+                    self.$($token).+.mt_blind_commitment(account_id)
+                }
+
+                fn mt_blind_price(&self) -> U128 {
+                    self.$($token).+.mt_blind_price()
+                }
+
+                fn mt_blind_available(&self) -> u64 {
+                    self.$($token).+.mt_blind_available()
+                }
+            }
+        };
+    };
+}
+
+/// Operators with blanket rights over all or listed tokens, see [crate::multi_token::approval::MultiTokenScopedOperator]
+#[macro_export]
+macro_rules! impl_multi_token_scoped_operator {
+    ($contract: ty, $($token: ident).+) => {
+        use $crate::multi_token::approval::MultiTokenScopedOperator;
+
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl MultiTokenScopedOperator for NearContract {
+                #[payable]
+                fn mt_approve_operator(&mut self, operator_id: AccountId, token_ids: Option<Vec<TokenId>>, expires_at: Option<u64>) {
+                    self.$($token).+.mt_approve_operator(operator_id, token_ids, expires_at)
+                }
+
+                #[payable]
+                fn mt_revoke_operator(&mut self, operator_id: AccountId) {
+                    self.$($token).+.mt_revoke_operator(operator_id)
+                }
+
+                fn mt_is_operator_approved(&self, owner_id: AccountId, operator_id: AccountId, token_id: TokenId) -> bool {
+                    self.$($token).+.mt_is_operator_approved(owner_id, operator_id, token_id)
+                }
+            }
+        };
+    };
+}
+
+/// Owner-gated `upgrade` method, available with `upgrade` feature.
+/// Code is passed as raw input of the call, not as JSON, to avoid encoding overhead.
+/// Contract must implement `migrate` method that is called right after deploy.
+/// Tokens stored by previous release are migrated with owner-gated `mt_migrate_tokens`, see `legacy` module.
+#[cfg(feature = "upgrade")]
+#[macro_export]
+macro_rules! impl_multi_token_upgrade {
+    ($contract: ty, $($token: ident).+) => {
+        const _: () = {
+            type NearContract = $contract;
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct NearContractContract {
+                pub account_id: AccountId,
+            }
+
+            #[near_bindgen]
+            impl NearContract {
+                pub fn upgrade(&mut self) -> Promise {
+                    let code = near_sdk::env::input().expect("Code of the contract must be provided");
+                    self.$($token).+.internal_upgrade(code)
+                }
+
+                /// Rewrite tokens stored by previous release, see [internal_migrate_tokens]
+                pub fn mt_migrate_tokens(&mut self, from_index: u64, limit: u64) -> u64 {
+                    $crate::multi_token::utils::unauthorized_assert(&self.$($token).+.owner_id);
+                    self.$($token).+.internal_migrate_tokens(from_index, limit)
+                }
             }
-        }
+        };
     };
 }
@@ -1,17 +1,15 @@
-pub mod core;
+pub use nep246_core::multi_token::*;
 
-pub mod token;
+pub mod approval {
+    pub use nep246_approval::*;
+}
 
-pub mod approval;
+pub mod enumeration {
+    pub use nep246_enumeration::*;
+}
 
-pub mod metadata;
-
-pub mod enumeration;
-
-pub mod utils;
-
-pub mod events;
+pub mod metadata {
+    pub use nep246_metadata::*;
+}
 
 pub mod macros;
-
-pub use macros::*;
\ No newline at end of file
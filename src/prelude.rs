@@ -0,0 +1,21 @@
+//! Everything that is needed to embed multi token into a contract
+//!
+//! ```ignore
+//! use nep_246::prelude::*;
+//! ```
+
+pub use crate::multi_token::approval::{
+    ApproveMsg, GranteeApproval, MultiTokenApproval, MultiTokenApprovalReceiver, TokenApproval,
+};
+pub use crate::multi_token::core::{
+    ApprovalId, MultiToken, MultiTokenCore, MultiTokenReceiver, MultiTokenResolver,
+};
+pub use crate::multi_token::enumeration::MultiTokenEnumeration;
+pub use crate::multi_token::json_types::Amount;
+pub use crate::multi_token::metadata::{
+    MtContractMetadata, MultiTokenMetadataProvider, MultiTokenUri, TokenMetadata, MT_METADATA_SPEC,
+};
+pub use crate::multi_token::token::{Approval, Token, TokenId};
+
+pub use near_sdk::json_types::U128;
+pub use near_sdk::{near_bindgen, AccountId, Balance, Promise, PromiseOrValue};
@@ -0,0 +1,172 @@
+//! Approvals granted with `mt_approve` and checks of them
+
+mod common;
+
+use near_sdk::json_types::U128;
+use near_sdk::serde_json;
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, PromiseResult, ONE_NEAR};
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::{MultiTokenCore, MultiTokenResolver};
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+
+#[test]
+fn is_approved_requires_every_token() {
+    let mut tokens = common::new_tokens();
+    let approved = common::mint(&mut tokens, 100);
+    let other = common::mint(&mut tokens, 100);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![approved.clone()], vec![Amount(50)], None);
+
+    assert!(tokens.mt_is_approved(vec![approved.clone()], accounts(1), vec![Amount(50)], None));
+    assert!(!tokens.mt_is_approved(vec![approved.clone()], accounts(1), vec![Amount(51)], None));
+    assert!(!tokens.mt_is_approved(vec![approved, other], accounts(1), vec![Amount(10), Amount(10)], None));
+}
+
+#[test]
+fn is_approved_for_share_requires_every_token() {
+    let mut tokens = common::new_tokens();
+    let first = common::mint(&mut tokens, 100);
+    let second = common::mint(&mut tokens, 100);
+    let other = common::mint(&mut tokens, 100);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve_share(accounts(1), vec![first.clone(), second.clone()], vec![5_000, 5_000], None);
+
+    assert!(tokens.mt_is_approved(vec![first.clone(), second.clone()], accounts(1), vec![Amount(50); 2], None));
+    assert!(!tokens.mt_is_approved(vec![first.clone(), other.clone()], accounts(1), vec![Amount(50); 2], None));
+    assert!(!tokens.mt_is_approved(vec![other, first], accounts(1), vec![Amount(50); 2], None));
+}
+
+#[test]
+fn reapproving_same_account_does_not_reach_limit() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 1_000);
+
+    for amount in 1..=200 {
+        common::set_caller(accounts(0), ONE_NEAR);
+        tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(amount)], None);
+    }
+
+    let approvals = tokens.approvals_by_id.as_ref().unwrap().get(&token_id).unwrap();
+    assert_eq!(approvals.len(), 1);
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)).unwrap().amount.0, 200);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(2), vec![token_id.clone()], vec![Amount(1)], None);
+    assert!(common::approval_of(&tokens, &token_id, &accounts(2)).is_some());
+}
+
+#[test]
+fn approval_pays_for_grantee_index() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    let usage = env::storage_usage();
+    tokens.mt_approve(accounts(1), vec![token_id], vec![Amount(50)], None);
+    let cost = u128::from(env::storage_usage() - usage) * env::storage_byte_cost();
+
+    assert_eq!(tokens.mt_approvals_for_grantee(accounts(1), None, 10).len(), 1);
+    assert_eq!(common::transfers_to(&accounts(0)), vec![ONE_NEAR - cost]);
+}
+
+#[test]
+fn share_approval_covers_transfers_in_a_row() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve_share(accounts(1), vec![token_id.clone()], vec![5_000], None);
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(50), None);
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(25), None);
+
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 25);
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)).unwrap().share, Some(5_000));
+}
+
+#[test]
+fn transfer_spends_fixed_approval() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], None);
+    tokens.mt_approve(accounts(3), vec![token_id.clone()], vec![Amount(10)], None);
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(30), None);
+
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)).unwrap().amount.0, 20);
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(3)).unwrap().amount.0, 10);
+}
+
+#[test]
+fn refund_of_transfer_call_goes_back_to_approval() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], None);
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer_call(accounts(2), token_id.clone(), Amount(50), None, String::new());
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)).unwrap().amount.0, 0);
+
+    common::set_callback(PromiseResult::Successful(serde_json::to_vec(&[U128(20)]).unwrap()));
+    let approvals = Some(vec![(accounts(0), 0, U128(50))]);
+    tokens.mt_resolve_transfer(accounts(1), accounts(2), vec![token_id.clone()], vec![U128(50)], approvals);
+
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 70);
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)).unwrap().amount.0, 20);
+}
+
+panic_test!(used_up_approval_can_not_transfer, "Not enough allowance", {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], None);
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(50), None);
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id, Amount(1), None);
+});
+
+panic_test!(owner_cannot_approve_self, &MtError::ApproveSelf.to_string(), {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(0), vec![token_id], vec![Amount(50)], None);
+});
+
+panic_test!(owner_cannot_approve_contract, &MtError::ApproveContract.to_string(), {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(common::contract_id(), vec![token_id], vec![Amount(50)], None);
+});
+
+panic_test!(owner_cannot_approve_share_to_self, &MtError::ApproveSelf.to_string(), {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve_share(accounts(0), vec![token_id], vec![5_000], None);
+});
+
+panic_test!(owner_cannot_approve_share_to_contract, &MtError::ApproveContract.to_string(), {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve_share(common::contract_id(), vec![token_id], vec![5_000], None);
+});
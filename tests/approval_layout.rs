@@ -0,0 +1,97 @@
+//! Stored layout of approvals and migration from the legacy one
+
+mod common;
+
+use std::collections::HashMap;
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, AccountId};
+use nep_246::multi_token::legacy::LegacyApproval;
+use nep_246::multi_token::token::{Approval, TokenId};
+
+fn amount_approval(amount: u128, approval_id: u64) -> Approval {
+    Approval { amount: U128(amount), approval_id, share: None }
+}
+
+fn stored_len(approval: &Approval) -> usize {
+    approval.try_to_vec().unwrap().len()
+}
+
+#[test]
+fn small_amount_approval_takes_three_bytes() {
+    assert_eq!(stored_len(&amount_approval(100, 1)), 3);
+}
+
+#[test]
+fn share_approval_takes_four_bytes() {
+    let approval = Approval { amount: U128(0), approval_id: 5, share: Some(2_500) };
+    assert_eq!(stored_len(&approval), 4);
+}
+
+#[test]
+fn max_values_fit_into_leb128_bounds() {
+    // 1 flag byte, 19 bytes of LEB128 u128, 10 bytes of LEB128 u64
+    assert_eq!(stored_len(&amount_approval(u128::MAX, u64::MAX)), 30);
+}
+
+#[test]
+fn compact_layout_never_exceeds_legacy_one_for_common_values() {
+    let legacy_len = LegacyApproval { amount: 0, approval_id: 0 }.try_to_vec().unwrap().len();
+    assert_eq!(legacy_len, 24);
+    for amount in [0, 1, 127, 128, 10u128.pow(24), u64::MAX as u128] {
+        assert!(stored_len(&amount_approval(amount, 1_000_000)) < legacy_len);
+    }
+}
+
+#[test]
+fn approvals_round_trip() {
+    for approval in [
+        amount_approval(0, 0),
+        amount_approval(127, 128),
+        amount_approval(u128::MAX, u64::MAX),
+        Approval { amount: U128(0), approval_id: 9, share: Some(10_000) },
+    ] {
+        let bytes = approval.try_to_vec().unwrap();
+        assert_eq!(Approval::try_from_slice(&bytes).unwrap(), approval);
+    }
+}
+
+#[test]
+fn unknown_flag_is_rejected() {
+    assert!(Approval::try_from_slice(&[7, 1, 1]).is_err());
+}
+
+fn store_legacy(token_id: &TokenId, approvals: &HashMap<AccountId, LegacyApproval>) {
+    let key = [b"a".to_vec(), token_id.try_to_vec().unwrap()].concat();
+    env::storage_write(&key, &approvals.try_to_vec().unwrap());
+}
+
+#[test]
+fn legacy_approvals_are_migrated() {
+    let mut tokens = common::new_tokens();
+    let first = common::mint(&mut tokens, 100);
+    let second = common::mint(&mut tokens, 100);
+
+    store_legacy(&first, &HashMap::from([(accounts(1), LegacyApproval { amount: 40, approval_id: 3 })]));
+    store_legacy(&second, &HashMap::from([(accounts(2), LegacyApproval { amount: u128::MAX, approval_id: 7 })]));
+
+    assert_eq!(tokens.internal_migrate_tokens(0, 1), 1);
+    assert_eq!(tokens.internal_migrate_tokens(1, 10), 2);
+
+    assert_eq!(common::approval_of(&tokens, &first, &accounts(1)), Some(amount_approval(40, 3)));
+    assert_eq!(common::approval_of(&tokens, &second, &accounts(2)), Some(amount_approval(u128::MAX, 7)));
+}
+
+#[test]
+fn migrated_approvals_are_kept_on_rerun() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    store_legacy(&token_id, &HashMap::from([(accounts(1), LegacyApproval { amount: 40, approval_id: 3 })]));
+
+    tokens.internal_migrate_tokens(0, 10);
+    tokens.internal_migrate_tokens(0, 10);
+
+    assert_eq!(common::approval_of(&tokens, &token_id, &accounts(1)), Some(amount_approval(40, 3)));
+}
@@ -0,0 +1,62 @@
+//! Use of an approval by grantee from its `mt_on_approve`, before `mt_approve` chain is over
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::{ApproveMsg, MultiTokenApproval};
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+/// `accounts(0)` approves `accounts(1)` for 50, `msg` makes contract notify grantee with `mt_on_approve`
+fn approve(strict: bool, msg: Option<ApproveMsg>) -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    if strict {
+        tokens.enable_strict_approvals(b"s".to_vec());
+    }
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], msg);
+    (tokens, token_id)
+}
+
+/// Grantee spends approval, as its `mt_on_approve` would do when called
+fn transfer_by_grantee(tokens: &mut MultiToken, token_id: &TokenId) {
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(30), None);
+}
+
+#[test]
+fn default_approval_is_usable_from_on_approve() {
+    let (mut tokens, token_id) = approve(false, Some(ApproveMsg::Single("list".into())));
+
+    transfer_by_grantee(&mut tokens, &token_id);
+
+    assert_eq!(tokens.mt_balance_of(accounts(2), vec![token_id]), vec![30.into()]);
+}
+
+panic_test!(strict_approval_is_not_usable_from_on_approve, "Approval is not confirmed by grantee yet", {
+    let (mut tokens, token_id) = approve(true, Some(ApproveMsg::Single("list".into())));
+    transfer_by_grantee(&mut tokens, &token_id);
+});
+
+#[test]
+fn strict_approval_without_msg_is_active_immediately() {
+    let (mut tokens, token_id) = approve(true, None);
+
+    transfer_by_grantee(&mut tokens, &token_id);
+
+    assert_eq!(tokens.mt_balance_of(accounts(2), vec![token_id]), vec![30.into()]);
+}
+
+#[test]
+fn strict_approval_without_call_for_token_is_active_immediately() {
+    let (mut tokens, token_id) = approve(true, Some(ApproveMsg::PerToken(vec![None])));
+
+    transfer_by_grantee(&mut tokens, &token_id);
+
+    assert_eq!(tokens.mt_balance_of(accounts(2), vec![token_id]), vec![30.into()]);
+}
@@ -0,0 +1,110 @@
+//! Burning of tokens and refund of their mint deposit
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, ONE_NEAR};
+use nep_246::multi_token::burn::DepositRefundPolicy;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::enumeration::{MultiTokenEnumeration, TokenOrderKind};
+use nep_246::multi_token::token::TokenId;
+
+/// Token with `supply` owned by `accounts(0)`, minted with deposit paid by `accounts(1)`
+fn refundable(policy: DepositRefundPolicy, supply: u128) -> (MultiToken, TokenId, u128) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_mint_deposits(b"d".to_vec(), policy);
+    common::set_caller(accounts(1), ONE_NEAR);
+    let token_id = tokens.internal_mint_refundable(accounts(0), Some(supply), None, accounts(1)).token_id;
+    let (_, deposit) = tokens.mint_deposits.as_ref().unwrap().deposit_by_id.get(&token_id).unwrap();
+    (tokens, token_id, deposit)
+}
+
+#[test]
+fn burning_last_unit_deletes_token() {
+    let (mut tokens, token_id, _) = refundable(DepositRefundPolicy::Payer, 10);
+
+    common::set_caller(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 10, None);
+
+    assert!(tokens.owner_by_id.get(&token_id).is_none());
+    assert!(tokens.total_supply.get(&token_id).is_none());
+    assert!(tokens.balances_per_token.get(&token_id).is_none());
+    assert!(tokens.holders_per_token.get(&token_id).is_none());
+    assert!(tokens.approvals_by_id.as_ref().unwrap().get(&token_id).is_none());
+    assert!(tokens.tokens_per_owner.as_ref().unwrap().get(&accounts(0)).is_none());
+    assert!(tokens.mint_deposits.as_ref().unwrap().deposit_by_id.get(&token_id).is_none());
+}
+
+#[test]
+fn burning_last_unit_deletes_extension_records() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_mint_deposits(b"d".to_vec(), DepositRefundPolicy::Payer);
+    tokens.enable_token_order(b"o".to_vec(), TokenOrderKind::MintTime);
+    tokens.enable_token_kinds(b"k".to_vec());
+    tokens.enable_transfer_locks(b"l".to_vec(), 1);
+    tokens.enable_activity(b"y".to_vec());
+    common::set_caller(accounts(1), ONE_NEAR);
+    let token_id = tokens.internal_mint_refundable(accounts(0), Some(10), None, accounts(1)).token_id;
+    let kept = tokens.internal_mint_refundable(accounts(0), Some(10), None, accounts(1)).token_id;
+
+    common::set_caller(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 10, None);
+
+    let order = tokens.token_order.as_ref().unwrap();
+    assert!(order.key_by_id.get(&token_id).is_none());
+    assert_eq!(order.index.len(), 1);
+    assert!(tokens.token_kinds.as_ref().unwrap().kind_by_id.get(&token_id).is_none());
+    assert!(tokens.transfer_locks.as_ref().unwrap().lock_until_by_id.get(&token_id).is_none());
+    assert!(tokens.activity.as_ref().unwrap().activity_by_id.get(&token_id).is_none());
+    let listed: Vec<TokenId> = tokens.mt_tokens(None, 10).into_iter().map(|token| token.token_id).collect();
+    assert_eq!(listed, vec![kept]);
+}
+
+#[test]
+fn refund_covers_only_released_storage() {
+    let (mut tokens, token_id, deposit) = refundable(DepositRefundPolicy::Payer, 10);
+
+    common::set_caller(accounts(0), 1);
+    let usage = env::storage_usage();
+    tokens.internal_burn(&accounts(0), &token_id, 10, None);
+    let released = u128::from(usage - env::storage_usage()) * env::storage_byte_cost();
+
+    assert_eq!(common::transfers_to(&accounts(1)), vec![deposit.min(released)]);
+    assert!(deposit.min(released) > 0);
+}
+
+#[test]
+fn burner_gets_refund_by_policy() {
+    let (mut tokens, token_id, _) = refundable(DepositRefundPolicy::Burner, 10);
+
+    common::set_caller(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 10, None);
+
+    assert_eq!(common::transfers_to(&accounts(0)).len(), 1);
+    assert!(common::transfers_to(&accounts(1)).is_empty());
+}
+
+#[test]
+fn partial_burn_keeps_token_and_deposit() {
+    let (mut tokens, token_id, deposit) = refundable(DepositRefundPolicy::Payer, 10);
+
+    common::set_caller(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 4, None);
+
+    assert_eq!(tokens.total_supply.get(&token_id), Some(6));
+    assert_eq!(tokens.mint_deposits.as_ref().unwrap().deposit_by_id.get(&token_id).unwrap().1, deposit);
+    assert!(common::transfers_to(&accounts(1)).is_empty());
+}
+
+#[test]
+fn token_without_deposit_is_kept_at_zero_supply() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 10, None);
+
+    assert_eq!(tokens.total_supply.get(&token_id), Some(0));
+    assert!(tokens.owner_by_id.get(&token_id).is_some());
+    assert!(common::transfers_to(&accounts(0)).is_empty());
+}
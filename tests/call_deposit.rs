@@ -0,0 +1,148 @@
+//! Every state-changing method rejects calls without the minimum attached deposit
+
+mod common;
+
+use std::collections::HashMap;
+
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::burn::MultiTokenBurn;
+use nep_246::multi_token::clawback::MultiTokenClawback;
+use nep_246::multi_token::compliance::MultiTokenCompliance;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::dao::{MintOrder, MultiTokenDaoMint};
+use nep_246::multi_token::erc1155_compat::MultiTokenErc1155Compat;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::migration::{MultiTokenMigration, MultiTokenRekey};
+use nep_246::multi_token::multisig::{AdminAction, MultiTokenMultisig};
+use nep_246::multi_token::payout::MultiTokenRoyalty;
+use nep_246::multi_token::state::MultiTokenState;
+use nep_246::multi_token::swap::{MultiTokenSwap, SwapProposal};
+use nep_246::multi_token::token::TokenId;
+
+const EXPECTED: &str = "Requires attached deposit of";
+
+/// Token minted to `accounts(0)`, which then calls without deposit
+fn setup() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(1));
+    common::set_caller(accounts(0), 0);
+    (tokens, token_id)
+}
+
+macro_rules! zero_deposit_test {
+    ($name: ident, |$tokens: ident, $token_id: ident| $call: expr) => {
+        panic_test!($name, EXPECTED, {
+            #[allow(unused_mut, unused_variables)]
+            let (mut $tokens, $token_id) = setup();
+            $call;
+        });
+    };
+}
+
+zero_deposit_test!(mt_transfer, |tokens, token_id| tokens.mt_transfer(accounts(1), token_id, Amount(1), None));
+zero_deposit_test!(mt_batch_transfer, |tokens, token_id| tokens.mt_batch_transfer(
+    accounts(1),
+    vec![token_id],
+    vec![Amount(1)],
+    vec![None],
+    None
+));
+zero_deposit_test!(mt_batch_transfer_best_effort, |tokens, token_id| tokens.mt_batch_transfer_best_effort(
+    accounts(1),
+    vec![token_id],
+    vec![Amount(1)],
+    vec![None],
+    None
+));
+zero_deposit_test!(mt_transfer_call, |tokens, token_id| tokens.mt_transfer_call(
+    accounts(1),
+    token_id,
+    Amount(1),
+    None,
+    String::new()
+));
+zero_deposit_test!(mt_batch_transfer_call, |tokens, token_id| tokens.mt_batch_transfer_call(
+    accounts(1),
+    vec![token_id],
+    vec![Amount(1)],
+    vec![None],
+    String::new(),
+    None
+));
+
+zero_deposit_test!(mt_approve, |tokens, token_id| tokens.mt_approve(
+    accounts(1),
+    vec![token_id],
+    vec![Amount(1)],
+    None
+));
+zero_deposit_test!(mt_approve_share, |tokens, token_id| tokens.mt_approve_share(
+    accounts(1),
+    vec![token_id],
+    vec![100],
+    None
+));
+zero_deposit_test!(mt_increase_approval, |tokens, token_id| tokens.mt_increase_approval(
+    token_id,
+    accounts(1),
+    Amount(1)
+));
+zero_deposit_test!(mt_decrease_approval, |tokens, token_id| tokens.mt_decrease_approval(
+    token_id,
+    accounts(1),
+    Amount(1)
+));
+zero_deposit_test!(mt_revoke, |tokens, token_id| tokens.mt_revoke(vec![token_id], accounts(1)));
+
+zero_deposit_test!(mt_burn, |tokens, token_id| tokens.mt_burn(vec![token_id], vec![Amount(1)], None, None));
+zero_deposit_test!(mt_burn_from_self, |tokens, token_id| tokens.mt_burn_from_self(vec![token_id], vec![Amount(1)]));
+
+zero_deposit_test!(mt_set_clawback_enabled, |tokens, token_id| tokens.mt_set_clawback_enabled(token_id, true));
+zero_deposit_test!(mt_clawback, |tokens, token_id| tokens.mt_clawback(
+    token_id,
+    accounts(1),
+    None,
+    Amount(1),
+    "memo".into()
+));
+
+zero_deposit_test!(mt_transfer_compliant, |tokens, token_id| tokens.mt_transfer_compliant(
+    accounts(1),
+    token_id,
+    Amount(1),
+    None
+));
+zero_deposit_test!(mt_settle_compliance, |tokens, token_id| tokens.mt_settle_compliance(0));
+
+zero_deposit_test!(mt_dao_mint, |tokens, token_id| tokens
+    .mt_dao_mint(0, MintOrder { owner_id: accounts(1), amount: U128(1), metadata: None }));
+zero_deposit_test!(mt_set_evm_mapping, |tokens, token_id| tokens.mt_set_evm_mapping(token_id, None));
+
+zero_deposit_test!(mt_transfer_all, |tokens, token_id| tokens.mt_transfer_all(accounts(1), 10));
+zero_deposit_test!(mt_rekey_account, |tokens, token_id| tokens.mt_rekey_account(accounts(0), accounts(2), 10));
+
+zero_deposit_test!(mt_propose, |tokens, token_id| tokens.mt_propose(AdminAction::Pause));
+zero_deposit_test!(mt_confirm, |tokens, token_id| tokens.mt_confirm(0));
+
+zero_deposit_test!(mt_set_royalty, |tokens, token_id| tokens.mt_set_royalty(token_id, HashMap::new()));
+zero_deposit_test!(mt_set_collection_royalty, |tokens, token_id| tokens.mt_set_collection_royalty("0".into(), None));
+
+zero_deposit_test!(mt_import_state, |tokens, token_id| {
+    let chunk = tokens.mt_export_state(None, 1);
+    tokens.mt_import_state(chunk)
+});
+zero_deposit_test!(mt_add_holder_candidates, |tokens, token_id| tokens.mt_add_holder_candidates(vec![accounts(1)]));
+zero_deposit_test!(mt_rebuild_holder_index, |tokens, token_id| tokens.mt_rebuild_holder_index(token_id, None, 10));
+zero_deposit_test!(mt_clear_holder_candidates, |tokens, token_id| tokens.mt_clear_holder_candidates());
+
+zero_deposit_test!(mt_swap, |tokens, token_id| tokens.mt_swap(SwapProposal {
+    taker_id: accounts(1),
+    give: vec![(token_id.clone(), Amount(1))],
+    take: vec![(token_id, Amount(1))],
+    expires_at: u64::MAX,
+}));
+zero_deposit_test!(mt_swap_accept, |tokens, token_id| tokens.mt_swap_accept(0));
+zero_deposit_test!(mt_swap_cancel, |tokens, token_id| tokens.mt_swap_cancel(0));
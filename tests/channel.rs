@@ -0,0 +1,53 @@
+//! Payment channels funded with tokens
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{PublicKey, ONE_NEAR};
+use nep_246::multi_token::channel::MultiTokenPaymentChannel;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::dust::MultiTokenMinTransfer;
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+fn voucher_key() -> PublicKey {
+    "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap()
+}
+
+fn with_channels() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_payment_channels(b"c".to_vec(), 1_000);
+    let token_id = common::mint(&mut tokens, 10);
+    (tokens, token_id)
+}
+
+#[test]
+fn sender_pays_for_channel_storage() {
+    let (mut tokens, token_id) = with_channels();
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    let channel_id = tokens.mt_channel_open(accounts(1), token_id, Amount(5), voucher_key());
+
+    assert!(tokens.mt_channel(channel_id).is_some());
+    let refunds = common::transfers_to(&accounts(0));
+    assert_eq!(refunds.len(), 1);
+    assert!(refunds[0] < ONE_NEAR);
+}
+
+panic_test!(channel_without_storage_deposit_is_rejected, "to cover storage", {
+    let (mut tokens, token_id) = with_channels();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_channel_open(accounts(1), token_id, Amount(5), voucher_key());
+});
+
+panic_test!(channel_below_min_amount_is_rejected, &MtError::BelowMinAmount { min: 6, amount: 5 }.to_string(), {
+    let (mut tokens, token_id) = with_channels();
+    tokens.enable_min_transfer_amounts(b"d".to_vec());
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_set_min_transfer_amount(token_id.clone(), Amount(6));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_channel_open(accounts(1), token_id, Amount(5), voucher_key());
+});
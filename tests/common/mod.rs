@@ -0,0 +1,106 @@
+//! Shared setup of integration tests.
+//!
+//! Mocked blockchain of `near-sdk` reports contract panics through an `extern "C"` host function,
+//! which can't unwind, so a panicking contract aborts the whole test binary and `#[should_panic]`
+//! can't observe it. Tests declared with [panic_test] run their body in a child process instead.
+
+#![allow(dead_code)]
+
+use std::process::Command;
+
+use near_sdk::mock::VmAction;
+use near_sdk::test_utils::{accounts, get_created_receipts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, Balance, PromiseResult, RuntimeFeesConfig, VMConfig};
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::token::{Approval, TokenId};
+
+/// Name of test the child process must run the body of
+pub const PANIC_CASE_VAR: &str = "NEP246_PANIC_CASE";
+
+/// Whether this process is the child spawned for test `name`
+pub fn is_panic_case(name: &str) -> bool {
+    std::env::var(PANIC_CASE_VAR).as_deref() == Ok(name)
+}
+
+/// Run test `name` of current binary in a child process and assert it panicked with `expected`
+pub fn assert_child_panics(name: &str, expected: &str) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(PANIC_CASE_VAR, name)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{} didn't panic", name);
+    assert!(stderr.contains(expected), "{} panicked without {:?}:\n{}", name, expected, stderr);
+}
+
+/// Test expected to panic with a message containing `$expected`
+#[macro_export]
+macro_rules! panic_test {
+    ($name: ident, $expected: expr, $body: block) => {
+        #[test]
+        fn $name() {
+            if common::is_panic_case(stringify!($name)) {
+                $body
+            } else {
+                common::assert_child_panics(stringify!($name), $expected);
+            }
+        }
+    };
+}
+
+/// Account the contract is deployed to
+pub fn contract_id() -> AccountId {
+    "mt.near".parse().unwrap()
+}
+
+fn context(predecessor_id: AccountId, deposit: Balance) -> VMContextBuilder {
+    let mut context = VMContextBuilder::new();
+    context.current_account_id(contract_id()).predecessor_account_id(predecessor_id).attached_deposit(deposit);
+    context
+}
+
+/// Set predecessor and attached deposit of the next call
+pub fn set_caller(account_id: AccountId, deposit: Balance) {
+    testing_env!(context(account_id, deposit).build());
+}
+
+/// Call contract from itself as a callback of promise with `result`
+pub fn set_callback(result: PromiseResult) {
+    testing_env!(
+        context(contract_id(), 0).build(),
+        VMConfig::test(),
+        RuntimeFeesConfig::test(),
+        Default::default(),
+        vec![result]
+    );
+}
+
+/// Multi token with approvals and enumeration but without metadata, owned by `accounts(0)`
+pub fn new_tokens() -> MultiToken {
+    set_caller(accounts(0), 1);
+    MultiToken::new(b"t".to_vec(), accounts(0), None::<Vec<u8>>, Some(b"e".to_vec()), Some(b"a".to_vec()))
+}
+
+/// Mint token with `supply` to `accounts(0)`
+pub fn mint(tokens: &mut MultiToken, supply: Balance) -> TokenId {
+    tokens.internal_mint(accounts(0), Some(supply), None, None).token_id
+}
+
+/// Approval of `account_id` in token, read from state
+pub fn approval_of(tokens: &MultiToken, token_id: &TokenId, account_id: &AccountId) -> Option<Approval> {
+    tokens.approvals_by_id.as_ref()?.get(token_id)?.get(account_id).cloned()
+}
+
+/// Amounts of NEAR transferred to `account_id` by receipts created so far
+pub fn transfers_to(account_id: &AccountId) -> Vec<Balance> {
+    get_created_receipts()
+        .into_iter()
+        .filter(|receipt| &receipt.receiver_id == account_id)
+        .flat_map(|receipt| receipt.actions)
+        .filter_map(|action| match action {
+            VmAction::Transfer { deposit } => Some(deposit),
+            _ => None,
+        })
+        .collect()
+}
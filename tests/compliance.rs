@@ -0,0 +1,38 @@
+//! Transfers confirmed by compliance oracle
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::compliance::{MultiTokenCompliance, TimeoutPolicy};
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+fn with_oracle() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_compliance(b"c".to_vec(), accounts(4), 1_000, TimeoutPolicy::Reject);
+    let token_id = common::mint(&mut tokens, 10);
+    tokens.internal_register_account(&token_id, &accounts(1));
+    (tokens, token_id)
+}
+
+#[test]
+fn sender_pays_for_pending_transfer_storage() {
+    let (mut tokens, token_id) = with_oracle();
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_transfer_compliant(accounts(1), token_id, Amount(5), None);
+
+    assert!(tokens.mt_pending_compliance(0).is_some());
+    let refunds = common::transfers_to(&accounts(0));
+    assert_eq!(refunds.len(), 1);
+    assert!(refunds[0] < ONE_NEAR);
+}
+
+panic_test!(pending_transfer_without_storage_deposit_is_rejected, "to cover storage", {
+    let (mut tokens, token_id) = with_oracle();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer_compliant(accounts(1), token_id, Amount(5), None);
+});
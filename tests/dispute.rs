@@ -0,0 +1,28 @@
+//! Locking balances by arbiter
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::dispute::MultiTokenDispute;
+
+panic_test!(contract_balance_cannot_be_locked, "Cannot lock balance of the contract itself", {
+    let mut tokens = common::new_tokens();
+    tokens.enable_disputes(b"d".to_vec(), accounts(3), 1_000);
+    let token_id = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(3), ONE_NEAR);
+    tokens.mt_dispute_lock(common::contract_id(), token_id, 100, None);
+});
+
+#[test]
+fn holder_balance_is_locked() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_disputes(b"d".to_vec(), accounts(3), 1_000);
+    let token_id = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(3), ONE_NEAR);
+    tokens.mt_dispute_lock(accounts(0), token_id.clone(), 100, None);
+
+    assert!(tokens.mt_dispute_lock_of(accounts(0), token_id).is_some());
+}
@@ -0,0 +1,134 @@
+//! Golden files of `EVENT_JSON` logs, so format seen by indexers can't drift unnoticed.
+//!
+//! Expected logs are in `tests/golden/<test name>.log`, one event per line.
+//! After intended change of format run tests with `NEP246_BLESS=1` to rewrite them and review the diff.
+
+mod common;
+
+use std::path::PathBuf;
+
+use near_sdk::test_utils::{accounts, get_logs, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, Balance, RuntimeFeesConfig, VMConfig, ONE_NEAR};
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::MultiTokenCore;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+const MEMO: &str = "Привет, 世界! 🚀 \"quoted\" \\ tab\t";
+
+fn assert_golden(name: &str) {
+    let events: Vec<String> = get_logs().into_iter().filter(|log| log.starts_with("EVENT_JSON:")).collect();
+    let actual = events.join("\n") + "\n";
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{}.log", name));
+
+    if std::env::var_os("NEP246_BLESS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("No golden file {}", path.display()));
+    assert_eq!(actual, expected, "Events differ from {}", path.display());
+}
+
+/// Start a call of `predecessor_id` without gas limit, only events of the call are compared
+fn start_call(predecessor_id: AccountId, deposit: Balance) {
+    let mut context = VMContextBuilder::new();
+    context.current_account_id(common::contract_id()).predecessor_account_id(predecessor_id).attached_deposit(deposit);
+    testing_env!(context.build(), VMConfig::free(), RuntimeFeesConfig::free(), Default::default(), vec![]);
+}
+
+/// Longest valid account id, 64 characters
+fn longest_account() -> AccountId {
+    format!("{}.near", "x".repeat(59)).parse().unwrap()
+}
+
+#[test]
+fn mint() {
+    let mut tokens = common::new_tokens();
+    start_call(accounts(0), 0);
+    tokens.internal_mint(accounts(0), Some(100), None, None);
+    assert_golden("mint");
+}
+
+#[test]
+fn mint_max_length_ids() {
+    let mut tokens = common::new_tokens();
+    tokens.next_token_id = u64::MAX - 1;
+    start_call(accounts(0), 0);
+    tokens.internal_mint(longest_account(), Some(u128::MAX), None, None);
+    assert_golden("mint_max_length_ids");
+}
+
+#[test]
+fn mint_huge_batch() {
+    let mut tokens = common::new_tokens();
+    start_call(accounts(0), 10 * ONE_NEAR);
+    tokens.internal_batch_mint(accounts(0), (1..=100).map(|supply| (Some(supply), None)).collect(), None);
+    assert_golden("mint_huge_batch");
+}
+
+#[test]
+fn transfer_unicode_memo() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(1));
+    start_call(accounts(0), 1);
+    tokens.internal_transfer_with_memo(&accounts(0), &accounts(1), &token_id, None, 40, Some(MEMO.to_string()));
+    assert_golden("transfer_unicode_memo");
+}
+
+#[test]
+fn transfer_max_length_ids() {
+    let mut tokens = common::new_tokens();
+    tokens.next_token_id = u64::MAX - 1;
+    let token_id = tokens.internal_mint(accounts(0), Some(u128::MAX), None, None).token_id;
+    tokens.internal_register_account(&token_id, &longest_account());
+    start_call(accounts(0), 1);
+    tokens.internal_transfer(&accounts(0), &longest_account(), &token_id, None, u128::MAX);
+    assert_golden("transfer_max_length_ids");
+}
+
+#[test]
+fn transfer_huge_batch() {
+    let mut tokens = common::new_tokens();
+    start_call(accounts(0), 0);
+    let token_ids: Vec<TokenId> = (0..50).map(|_| common::mint(&mut tokens, 100)).collect();
+    for token_id in &token_ids {
+        tokens.internal_register_account(token_id, &accounts(1));
+    }
+    start_call(accounts(0), 1);
+    tokens.internal_batch_transfer(&accounts(0), &accounts(1), &token_ids, vec![None; 50], (1..=50).collect());
+    assert_golden("transfer_huge_batch");
+}
+
+#[test]
+fn burn_unicode_memo() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    start_call(accounts(0), 1);
+    tokens.internal_burn(&accounts(0), &token_id, 30, Some(MEMO.to_string()));
+    assert_golden("burn_unicode_memo");
+}
+
+#[test]
+fn burn_max_length_ids() {
+    let mut tokens = common::new_tokens();
+    tokens.next_token_id = u64::MAX - 1;
+    let token_id = tokens.internal_mint(longest_account(), Some(u128::MAX), None, None).token_id;
+    start_call(longest_account(), 1);
+    tokens.internal_burn(&longest_account(), &token_id, u128::MAX - 1, None);
+    assert_golden("burn_max_length_ids");
+}
+
+/// Approval itself emits no event, transfer by grantee names it in `authorized_id`
+#[test]
+fn approve_and_transfer_by_grantee() {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], None);
+    assert!(get_logs().iter().all(|log| !log.starts_with("EVENT_JSON:")));
+    start_call(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id, Amount(50), None);
+    assert_golden("approve_and_transfer_by_grantee");
+}
@@ -0,0 +1,152 @@
+//! Core and views work with any combination of approval, enumeration and metadata extensions
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::enumeration::MultiTokenEnumeration;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::metadata::TokenMetadata;
+use nep_246::multi_token::token::{Token, TokenId};
+
+use common::set_caller;
+
+#[derive(Clone, Copy)]
+struct Extensions {
+    approval: bool,
+    enumeration: bool,
+    metadata: bool,
+}
+
+const CORE: Extensions = Extensions { approval: false, enumeration: false, metadata: false };
+
+fn metadata() -> TokenMetadata {
+    TokenMetadata {
+        title: Some("Gold".to_string()),
+        description: None,
+        media: None,
+        media_hash: None,
+        mime_type: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        attributes: None,
+        kind: None,
+        reference: None,
+        reference_hash: None,
+    }
+}
+
+fn new_tokens(extensions: Extensions) -> MultiToken {
+    set_caller(accounts(0), 1);
+    MultiToken::new(
+        b"t".to_vec(),
+        accounts(0),
+        extensions.metadata.then(|| b"m".to_vec()),
+        extensions.enumeration.then(|| b"e".to_vec()),
+        extensions.approval.then(|| b"a".to_vec()),
+    )
+}
+
+/// Mint token of 100 to `accounts(0)` and transfer 40 of it to `accounts(1)`
+fn mint_and_transfer(tokens: &mut MultiToken, extensions: Extensions) -> TokenId {
+    let metadata = extensions.metadata.then(metadata);
+    let token_id = tokens.internal_mint(accounts(0), Some(100), metadata, None).token_id;
+    tokens.internal_register_account(&token_id, &accounts(1));
+
+    set_caller(accounts(0), 1);
+    tokens.mt_transfer(accounts(1), token_id.clone(), Amount(40), None);
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 60);
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(1)), 40);
+
+    token_id
+}
+
+/// Fields of token filled by the extensions the contract uses and `None` otherwise
+fn assert_token(token: &Token, token_id: &TokenId, extensions: Extensions) {
+    assert_eq!(&token.token_id, token_id);
+    assert_eq!(token.owner_id, accounts(0));
+    assert_eq!(token.supply.0, 100);
+    assert_eq!(
+        token.metadata.as_ref().and_then(|metadata| metadata.title.as_deref()),
+        extensions.metadata.then_some("Gold")
+    );
+    assert_eq!(token.approvals.is_some(), extensions.approval);
+    assert_eq!(token.next_approval_id.is_some(), extensions.approval);
+}
+
+/// Core calls and views of contract with `extensions`
+fn check(extensions: Extensions) {
+    let mut tokens = new_tokens(extensions);
+    let token_id = mint_and_transfer(&mut tokens, extensions);
+
+    assert_token(&tokens.mt_token(token_id.clone()).unwrap(), &token_id, extensions);
+    let listed = tokens.mt_tokens(None, 10);
+    assert_eq!(listed.len(), 1);
+    assert_token(&listed[0], &token_id, extensions);
+
+    if extensions.enumeration {
+        let owned = tokens.mt_tokens_for_owner(accounts(0), None, 10);
+        assert_eq!(owned.len(), 1);
+        assert_token(&owned[0], &token_id, extensions);
+    }
+}
+
+#[test]
+fn core_only() {
+    check(CORE);
+}
+
+#[test]
+fn core_with_approval() {
+    check(Extensions { approval: true, ..CORE });
+}
+
+#[test]
+fn core_with_enumeration() {
+    check(Extensions { enumeration: true, ..CORE });
+}
+
+#[test]
+fn core_with_metadata() {
+    check(Extensions { metadata: true, ..CORE });
+}
+
+#[test]
+fn core_with_all() {
+    check(Extensions { approval: true, enumeration: true, metadata: true });
+}
+
+#[test]
+fn approval_listed_by_enumeration() {
+    let extensions = Extensions { approval: true, enumeration: true, ..CORE };
+    let mut tokens = new_tokens(extensions);
+    let token_id = mint_and_transfer(&mut tokens, extensions);
+
+    set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(2), vec![token_id], vec![Amount(10)], None);
+
+    let token = &tokens.mt_tokens(None, 10)[0];
+    assert!(token.approvals.as_ref().unwrap().contains_key(&accounts(2)));
+    assert_eq!(token.next_approval_id, Some(1));
+}
+
+panic_test!(tokens_for_owner_without_enumeration, "Could not find field", {
+    let mut tokens = new_tokens(CORE);
+    mint_and_transfer(&mut tokens, CORE);
+
+    tokens.mt_tokens_for_owner(accounts(0), None, 10);
+});
+
+panic_test!(approve_without_approval, "Approval Management is not supported", {
+    let extensions = Extensions { enumeration: true, metadata: true, ..CORE };
+    let mut tokens = new_tokens(extensions);
+    let token_id = mint_and_transfer(&mut tokens, extensions);
+
+    set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(2), vec![token_id], vec![Amount(10)], None);
+});
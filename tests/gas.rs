@@ -0,0 +1,88 @@
+//! Gas regression tests of `mt_approve`.
+//!
+//! Budgets are measured in mocked blockchain with some headroom. Raise them only together with
+//! a reason why the call became more expensive.
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, AccountId, Gas, ONE_NEAR};
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+use nep_246::multi_token::utils::refund_deposit;
+
+/// Approval of a single token
+const APPROVE_ONE_BUDGET: Gas = Gas(1_600_000_000_000);
+/// Every next token of the batch. Grantee index is a tree to keep its pages in order,
+/// so indexing a token rebalances it
+const APPROVE_PER_TOKEN_BUDGET: Gas = Gas(2_600_000_000_000);
+
+fn minted(count: usize) -> (MultiToken, Vec<TokenId>) {
+    let mut tokens = common::new_tokens();
+    let token_ids = (0..count).map(|_| common::mint(&mut tokens, 100)).collect();
+    (tokens, token_ids)
+}
+
+/// Gas burnt by a single `mt_approve` call
+fn approve(tokens: &mut MultiToken, account_id: AccountId, token_ids: &[TokenId]) -> Gas {
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(account_id, token_ids.to_vec(), vec![Amount(1); token_ids.len()], None);
+    env::used_gas()
+}
+
+/// The same approvals the way `mt_approve` made them before: storage of every token is charged
+/// and the rest of deposit refunded on its own
+fn approve_charging_every_token(tokens: &mut MultiToken, account_id: AccountId, token_ids: &[TokenId]) -> Gas {
+    common::set_caller(accounts(0), ONE_NEAR);
+    for token_id in token_ids {
+        let (_, _, storage) = tokens.internal_approve(&account_id, token_id, 1, None);
+        refund_deposit(storage);
+    }
+    env::used_gas()
+}
+
+#[test]
+fn approve_one_token() {
+    let (mut tokens, token_ids) = minted(1);
+
+    let used = approve(&mut tokens, accounts(1), &token_ids);
+
+    assert!(used <= APPROVE_ONE_BUDGET, "mt_approve of one token used {:?}", used);
+}
+
+#[test]
+fn approve_grows_linearly_with_batch() {
+    let (mut tokens, token_ids) = minted(10);
+
+    let one = approve(&mut tokens, accounts(1), &token_ids[..1]);
+    let ten = approve(&mut tokens, accounts(2), &token_ids);
+
+    let per_token = Gas((ten.0 - one.0) / 9);
+    assert!(per_token <= APPROVE_PER_TOKEN_BUDGET, "every next token of mt_approve used {:?}", per_token);
+}
+
+/// Storage is charged and refunded once per batch, that saves at least an eighth of gas on 10 tokens
+#[test]
+fn batch_approve_is_cheaper_than_separate_calls() {
+    let (mut tokens, token_ids) = minted(10);
+
+    let batch = approve(&mut tokens, accounts(1), &token_ids).0;
+    let separate: u64 =
+        token_ids.iter().map(|token_id| approve(&mut tokens, accounts(2), std::slice::from_ref(token_id)).0).sum();
+
+    assert!(batch * 8 < separate * 7, "batch used {}, separate calls {}", batch, separate);
+}
+
+/// Charging storage once per batch saves at least an eighth of gas on 10 tokens compared to charging
+/// every token. Batch is measured second, so approvals map already holds the other grantee
+#[test]
+fn batch_charge_is_cheaper_than_charging_every_token() {
+    let (mut tokens, token_ids) = minted(10);
+
+    let before = approve_charging_every_token(&mut tokens, accounts(1), &token_ids).0;
+    let after = approve(&mut tokens, accounts(2), &token_ids).0;
+
+    assert!(after * 8 < before * 7, "charged once {}, every token {}", after, before);
+}
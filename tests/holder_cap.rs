@@ -0,0 +1,51 @@
+//! Limits of holders of a token
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::holder_cap::{HolderCap, MultiTokenHolderCap};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+/// Token of 100 owned by `accounts(0)`, which sent all of it to `accounts(1)`, with at most 2 holders
+fn emptied_owner() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_holder_caps(b"h".to_vec());
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(1));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_set_holder_cap(token_id.clone(), Some(HolderCap { max_per_wallet: None, max_holders: Some(2) }));
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer(accounts(1), token_id.clone(), Amount(100), None);
+    (tokens, token_id)
+}
+
+#[test]
+fn owner_sending_whole_balance_frees_holder_slot() {
+    let (mut tokens, token_id) = emptied_owner();
+
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    assert!(!tokens.internal_is_holder(&token_id, &accounts(0)));
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 0);
+    assert_eq!(tokens.holders_per_token.get(&token_id).unwrap().len(), 2);
+}
+
+#[test]
+fn emptied_owner_takes_slot_back_on_receive() {
+    let (mut tokens, token_id) = emptied_owner();
+
+    tokens.internal_deposit(&token_id, &accounts(0), 10);
+
+    assert!(tokens.internal_is_holder(&token_id, &accounts(0)));
+}
+
+panic_test!(emptied_owner_can_not_receive_into_full_token, "Token reached maximum number of holders", {
+    let (mut tokens, token_id) = emptied_owner();
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    tokens.internal_deposit(&token_id, &accounts(0), 10);
+});
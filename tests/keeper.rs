@@ -0,0 +1,35 @@
+//! Maintenance tasks run by keepers
+
+mod common;
+
+use near_sdk::json_types::{U128, U64};
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::keeper::{KeeperTask, KeeperTaskConfig, MultiTokenKeeper};
+use nep_246::multi_token::storage_management::StorageManagement;
+
+#[test]
+fn empty_record_storage_is_credited_to_account() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_storage_management(b"s".to_vec());
+    tokens.enable_keeper(b"k".to_vec());
+    let token_id = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(1), ONE_NEAR);
+    tokens.storage_deposit(None, None);
+    common::set_caller(accounts(1), 0);
+    let registered = tokens.mt_storage_register(vec![token_id.clone()], None);
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_keeper_set_task(
+        KeeperTask::EmptyRecords,
+        Some(KeeperTaskConfig { cooldown: U64(0), reward_per_item: U128(0) }),
+    );
+    common::set_caller(accounts(2), 0);
+    assert_eq!(tokens.mt_keeper_execute(KeeperTask::EmptyRecords, 10), 1);
+
+    let storage = tokens.storage_balance_of(accounts(1)).unwrap();
+    assert!(storage.available.0 > registered.available.0);
+    assert_eq!(storage.total, registered.total);
+    assert!(tokens.balances_per_token.get(&token_id).unwrap().get(&accounts(1)).is_none());
+}
@@ -0,0 +1,10 @@
+//! Expansion of `impl_multi_token_*` macros for different shapes of contracts
+
+#[test]
+fn macros_expand() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/plain_contract.rs");
+    t.pass("tests/ui/nested_field.rs");
+    t.pass("tests/ui/generic_contract.rs");
+    t.pass("tests/ui/path_contract.rs");
+}
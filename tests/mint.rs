@@ -0,0 +1,36 @@
+//! Minting of new tokens
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, ONE_NEAR};
+use nep_246::multi_token::token::TokenId;
+
+#[test]
+fn tokens_get_sequential_ids() {
+    let mut tokens = common::new_tokens();
+
+    let ids: Vec<TokenId> = (0..3).map(|_| common::mint(&mut tokens, 10)).collect();
+
+    assert_eq!(ids, vec![TokenId::from("0"), "1".into(), "2".into()]);
+    assert_eq!(tokens.next_token_id, 3);
+}
+
+#[test]
+fn batch_mint_refunds_all_but_byte_cost() {
+    let mut tokens = common::new_tokens();
+    common::set_caller(accounts(0), ONE_NEAR);
+
+    let before = env::storage_usage();
+    let minted = tokens.internal_batch_mint(accounts(0), vec![(Some(10), None), (Some(20), None)], Some(accounts(0)));
+    let cost = env::storage_byte_cost() * u128::from(env::storage_usage() - before);
+
+    assert_eq!(minted.len(), 2);
+    assert_eq!(common::transfers_to(&accounts(0)), vec![ONE_NEAR - cost]);
+}
+
+panic_test!(batch_mint_requires_byte_cost, "yoctoNEAR to cover storage", {
+    let mut tokens = common::new_tokens();
+    common::set_caller(accounts(0), 1);
+    tokens.internal_batch_mint(accounts(0), vec![(Some(10), None)], Some(accounts(0)));
+});
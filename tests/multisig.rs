@@ -0,0 +1,65 @@
+//! Admin actions confirmed by council
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::metadata::TokenMetadata;
+use nep_246::multi_token::multisig::{AdminAction, MultiTokenMultisig};
+use nep_246::multi_token::token::TokenId;
+
+fn metadata(title: &str) -> TokenMetadata {
+    TokenMetadata {
+        title: Some(title.to_string()),
+        description: None,
+        media: None,
+        media_hash: None,
+        mime_type: None,
+        issued_at: None,
+        expires_at: None,
+        starts_at: None,
+        updated_at: None,
+        extra: None,
+        attributes: None,
+        kind: None,
+        reference: None,
+        reference_hash: None,
+    }
+}
+
+/// Tokens with metadata and council of `accounts(1)` alone
+fn with_council() -> (MultiToken, TokenId) {
+    common::set_caller(accounts(0), 1);
+    let mut tokens =
+        MultiToken::new(b"t".to_vec(), accounts(0), Some(b"m".to_vec()), None::<Vec<u8>>, None::<Vec<u8>>);
+    tokens.enable_multisig(b"c".to_vec(), vec![accounts(1)], 1);
+    let token_id = tokens.internal_mint(accounts(0), Some(10), Some(metadata("Gold")), None).token_id;
+    (tokens, token_id)
+}
+
+fn title_of(tokens: &MultiToken, token_id: &TokenId) -> Option<String> {
+    tokens.token_metadata_by_id.as_ref().unwrap().get(token_id).unwrap().title
+}
+
+#[test]
+fn metadata_is_updated_until_frozen() {
+    let (mut tokens, token_id) = with_council();
+
+    tokens.internal_update_token_metadata(&token_id, metadata("Silver"));
+
+    assert_eq!(title_of(&tokens, &token_id).as_deref(), Some("Silver"));
+}
+
+panic_test!(frozen_metadata_is_not_updated, "Metadata is frozen", {
+    let (mut tokens, token_id) = with_council();
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_propose(AdminAction::FreezeMetadata { token_id: token_id.clone() });
+
+    tokens.internal_update_token_metadata(&token_id, metadata("Silver"));
+});
+
+panic_test!(repeated_members_are_counted_once, "Threshold is bigger than council", {
+    let mut tokens = common::new_tokens();
+    tokens.enable_multisig(b"c".to_vec(), vec![accounts(1), accounts(1), accounts(2)], 3);
+});
@@ -0,0 +1,47 @@
+//! System operators moving tokens of owners that opted in
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::dust::MultiTokenMinTransfer;
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::operator::MultiTokenSystemOperator;
+use nep_246::multi_token::token::TokenId;
+
+/// Token of 100 owned by `accounts(0)`, who opted in for operator `accounts(1)`
+fn opted_in() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_system_operators(b"o".to_vec());
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_set_system_operator(accounts(1), true);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_opt_in_operator(accounts(1));
+    (tokens, token_id)
+}
+
+#[test]
+fn operator_moves_tokens_of_owner() {
+    let (mut tokens, token_id) = opted_in();
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_operator_transfer(accounts(0), accounts(2), vec![token_id.clone()], vec![Amount(30)], None);
+
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 70);
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(2)), 30);
+}
+
+panic_test!(operator_transfer_below_min_amount, &MtError::BelowMinAmount { min: 10, amount: 5 }.to_string(), {
+    let (mut tokens, token_id) = opted_in();
+    tokens.enable_min_transfer_amounts(b"d".to_vec());
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_set_min_transfer_amount(token_id.clone(), Amount(10));
+
+    common::set_caller(accounts(1), 1);
+    tokens.mt_operator_transfer(accounts(0), accounts(2), vec![token_id], vec![Amount(5)], None);
+});
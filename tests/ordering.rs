@@ -0,0 +1,93 @@
+//! Pages of enumeration and approval views come in a stable order
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::enumeration::{MultiTokenEnumeration, TokenOrderKind};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::{Token, TokenId};
+
+fn minted(count: usize) -> (MultiToken, Vec<TokenId>) {
+    let mut tokens = common::new_tokens();
+    let token_ids = (0..count).map(|_| common::mint(&mut tokens, 100)).collect();
+    (tokens, token_ids)
+}
+
+fn ids(tokens: Vec<Token>) -> Vec<String> {
+    tokens.into_iter().map(|token| token.token_id.to_string()).collect()
+}
+
+fn sorted(token_ids: &[TokenId]) -> Vec<String> {
+    let mut token_ids: Vec<String> = token_ids.iter().map(|token_id| token_id.to_string()).collect();
+    token_ids.sort();
+    token_ids
+}
+
+#[test]
+fn tokens_are_ordered_by_id() {
+    let (tokens, token_ids) = minted(12);
+
+    let all = ids(tokens.mt_tokens(None, 100));
+
+    assert_eq!(all, sorted(&token_ids));
+    assert_eq!(&all[..4], ["0", "1", "10", "11"]);
+}
+
+#[test]
+fn pages_of_tokens_make_up_whole_list() {
+    let (tokens, _) = minted(12);
+
+    let pages: Vec<String> = (0..3).flat_map(|page| ids(tokens.mt_tokens(Some(page * 5), 5))).collect();
+
+    assert_eq!(pages, ids(tokens.mt_tokens(None, 100)));
+}
+
+#[test]
+fn tokens_for_owner_are_ordered_by_id() {
+    let (tokens, token_ids) = minted(12);
+
+    let pages: Vec<String> =
+        (0..4).flat_map(|page| ids(tokens.mt_tokens_for_owner(accounts(0), Some(page * 3), 3))).collect();
+
+    assert_eq!(pages, sorted(&token_ids));
+}
+
+#[test]
+fn tokens_minted_in_same_block_are_ordered_by_mint_sequence() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_token_order(b"o".to_vec(), TokenOrderKind::MintTime);
+    for _ in 0..12 {
+        common::mint(&mut tokens, 100);
+    }
+
+    let expected: Vec<String> = (0..12).map(|id| id.to_string()).collect();
+    assert_eq!(ids(tokens.mt_tokens(None, 100)), expected);
+}
+
+#[test]
+fn repeated_reads_are_equal() {
+    let (tokens, _) = minted(12);
+    assert_eq!(ids(tokens.mt_tokens(Some(3), 4)), ids(tokens.mt_tokens(Some(3), 4)));
+}
+
+#[test]
+fn grantee_approvals_keep_order_after_revoke() {
+    let (mut tokens, token_ids) = minted(5);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), token_ids.clone(), vec![Amount(1); 5], None);
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_revoke(vec![token_ids[2].clone()], accounts(1));
+
+    let page = |from_index: u64| -> Vec<String> {
+        tokens
+            .mt_approvals_for_grantee(accounts(1), Some(from_index), 2)
+            .into_iter()
+            .map(|approval| approval.token_id.to_string())
+            .collect()
+    };
+    assert_eq!([page(0), page(2)].concat(), ["0", "1", "3", "4"]);
+}
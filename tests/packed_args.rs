@@ -0,0 +1,174 @@
+//! Packed arguments decode to the same values as JSON ones and transfers behave the same
+
+mod common;
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::serde_json::{self, json};
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::packed::{MultiTokenPacked, PackedBatchTransfer, PackedTransfer};
+use nep_246::multi_token::token::TokenId;
+
+/// Packed arguments as they arrive in JSON of the call
+fn through_json<T: BorshSerialize>(args: &T) -> Base64VecU8 {
+    let encoded = serde_json::to_value(Base64VecU8(args.try_to_vec().unwrap())).unwrap();
+    serde_json::from_value(encoded).unwrap()
+}
+
+fn transfers() -> Vec<PackedTransfer> {
+    let mut transfers = vec![];
+    for amount in [1, 127, 128, u64::MAX as u128, u128::MAX] {
+        for approval_id in [None, Some(0), Some(u64::MAX)] {
+            transfers.push(PackedTransfer { receiver_id: accounts(1), token_id: "0".into(), amount, approval_id });
+        }
+    }
+    transfers.push(PackedTransfer {
+        receiver_id: format!("{}.near", "x".repeat(59)).parse().unwrap(),
+        token_id: "18446744073709551615".into(),
+        amount: 1,
+        approval_id: None,
+    });
+    transfers
+}
+
+fn batches() -> Vec<PackedBatchTransfer> {
+    vec![
+        PackedBatchTransfer {
+            receiver_id: accounts(1),
+            token_ids: vec!["0".into()],
+            amounts: vec![1],
+            approval_ids: vec![None],
+            batch_id: None,
+        },
+        PackedBatchTransfer {
+            receiver_id: accounts(2),
+            token_ids: (0..50).map(|id| TokenId::from(id.to_string())).collect(),
+            amounts: (0..50).map(|amount| u128::MAX - amount).collect(),
+            approval_ids: (0..50).map(|id| if id % 2 == 0 { Some(id) } else { None }).collect(),
+            batch_id: Some("Партия 🚀".into()),
+        },
+    ]
+}
+
+#[test]
+fn transfers_round_trip() {
+    for transfer in transfers() {
+        let args = through_json(&transfer);
+        assert_eq!(PackedTransfer::try_from_slice(&args.0).unwrap(), transfer);
+    }
+}
+
+#[test]
+fn batch_transfers_round_trip() {
+    for batch in batches() {
+        let args = through_json(&batch);
+        assert_eq!(PackedBatchTransfer::try_from_slice(&args.0).unwrap(), batch);
+    }
+}
+
+#[test]
+fn transfer_layout() {
+    let transfer = PackedTransfer { receiver_id: accounts(1), token_id: "7".into(), amount: 5, approval_id: Some(2) };
+
+    let mut expected = 3u32.to_le_bytes().to_vec();
+    expected.extend(b"bob");
+    expected.extend(1u32.to_le_bytes());
+    expected.extend(b"7");
+    expected.extend(5u128.to_le_bytes());
+    expected.push(1);
+    expected.extend(2u64.to_le_bytes());
+    assert_eq!(transfer.try_to_vec().unwrap(), expected);
+}
+
+/// Two tokens prepared the same way: `accounts(1)` approved for 50 and `accounts(2)` registered
+fn twins() -> (MultiToken, TokenId, TokenId) {
+    let mut tokens = common::new_tokens();
+    let json_token = common::mint(&mut tokens, 100);
+    let packed_token = common::mint(&mut tokens, 100);
+    common::set_caller(accounts(0), ONE_NEAR);
+    for token_id in [&json_token, &packed_token] {
+        tokens.internal_register_account(token_id, &accounts(2));
+        tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], None);
+    }
+    (tokens, json_token, packed_token)
+}
+
+fn events_of(token_id: &TokenId) -> Vec<String> {
+    get_logs()
+        .into_iter()
+        .filter(|log| log.starts_with("EVENT_JSON:"))
+        .map(|log| log.replace(&format!("[\"{}\"]", token_id), "[\"<token>\"]"))
+        .collect()
+}
+
+fn state_of(tokens: &MultiToken, token_id: &TokenId) -> serde_json::Value {
+    json!({
+        "balances": tokens.mt_balance_of(accounts(0), vec![token_id.clone()])
+            .into_iter()
+            .chain(tokens.mt_balance_of(accounts(2), vec![token_id.clone()]))
+            .collect::<Vec<_>>(),
+        "approved": tokens.mt_is_approved(vec![token_id.clone()], accounts(1), vec![Amount(1)], None),
+    })
+}
+
+fn assert_packed_transfer_matches_json_one(sender: usize, approval_id: Option<u64>) {
+    let (mut tokens, json_token, packed_token) = twins();
+
+    common::set_caller(accounts(sender), 1);
+    tokens.mt_transfer(accounts(2), json_token.clone(), Amount(40), approval_id);
+    let json_events = events_of(&json_token);
+    assert_eq!(json_events.len(), 1);
+
+    common::set_caller(accounts(sender), 1);
+    let args = PackedTransfer { receiver_id: accounts(2), token_id: packed_token.clone(), amount: 40, approval_id };
+    tokens.mt_transfer_packed(through_json(&args));
+
+    assert_eq!(events_of(&packed_token), json_events);
+    assert_eq!(state_of(&tokens, &packed_token), state_of(&tokens, &json_token));
+}
+
+#[test]
+fn packed_transfer_by_owner_matches_json_one() {
+    assert_packed_transfer_matches_json_one(0, None);
+}
+
+#[test]
+fn packed_transfer_by_grantee_matches_json_one() {
+    assert_packed_transfer_matches_json_one(1, Some(0));
+}
+
+#[test]
+fn packed_batch_transfer_matches_json_one() {
+    let (mut tokens, json_token, packed_token) = twins();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_batch_transfer(accounts(2), vec![json_token.clone()], vec![Amount(40)], vec![None], Some("b".into()));
+    let json_events = events_of(&json_token);
+    assert_eq!(json_events.len(), 1);
+
+    common::set_caller(accounts(0), 1);
+    let args = PackedBatchTransfer {
+        receiver_id: accounts(2),
+        token_ids: vec![packed_token.clone()],
+        amounts: vec![40],
+        approval_ids: vec![None],
+        batch_id: Some("b".into()),
+    };
+    tokens.mt_batch_transfer_packed(through_json(&args));
+
+    assert_eq!(events_of(&packed_token), json_events);
+    assert_eq!(state_of(&tokens, &packed_token), state_of(&tokens, &json_token));
+}
+
+panic_test!(truncated_args_are_rejected, "Invalid packed arguments", {
+    let (mut tokens, _, packed_token) = twins();
+    let args = PackedTransfer { receiver_id: accounts(2), token_id: packed_token, amount: 40, approval_id: None };
+    let mut bytes = args.try_to_vec().unwrap();
+    bytes.pop();
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer_packed(Base64VecU8(bytes));
+});
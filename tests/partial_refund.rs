@@ -0,0 +1,81 @@
+//! Receiver of `mt_batch_transfer_call` keeps part of tokens and returns the rest as unused,
+//! like `examples/approval-receiver` does when its capacity is reached
+
+mod common;
+
+use near_sdk::json_types::U128;
+use near_sdk::serde_json;
+use near_sdk::test_utils::accounts;
+use near_sdk::PromiseResult;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore, MultiTokenResolver};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+const SENT: [u128; 3] = [30, 60, 80];
+/// Unused amounts of receiver with capacity of 50 of each token
+const UNUSED: [u128; 3] = [0, 10, 30];
+
+/// Three tokens with 100 of each sent by `accounts(0)` to `accounts(2)` with `mt_batch_transfer_call`
+fn sent() -> (MultiToken, Vec<TokenId>) {
+    let mut tokens = common::new_tokens();
+    let token_ids: Vec<TokenId> = (0..3).map(|_| common::mint(&mut tokens, 100)).collect();
+    for token_id in &token_ids {
+        tokens.internal_register_account(token_id, &accounts(2));
+    }
+    common::set_caller(accounts(0), 1);
+    tokens.mt_batch_transfer_call(
+        accounts(2),
+        token_ids.clone(),
+        SENT.iter().map(|amount| Amount(*amount)).collect(),
+        vec![None; 3],
+        String::new(),
+        None,
+    );
+    (tokens, token_ids)
+}
+
+fn resolve(tokens: &mut MultiToken, token_ids: &[TokenId], unused: &[u128]) -> Vec<U128> {
+    let unused: Vec<U128> = unused.iter().map(|amount| U128(*amount)).collect();
+    common::set_callback(PromiseResult::Successful(serde_json::to_vec(&unused).unwrap()));
+    tokens.mt_resolve_transfer(accounts(0), accounts(2), token_ids.to_vec(), SENT.map(U128).to_vec(), None)
+}
+
+fn balances_of(tokens: &MultiToken, account: usize, token_ids: &[TokenId]) -> Vec<U128> {
+    tokens.mt_balance_of(accounts(account), token_ids.to_vec())
+}
+
+#[test]
+fn unused_part_of_each_token_is_credited_back() {
+    let (mut tokens, token_ids) = sent();
+
+    let used = resolve(&mut tokens, &token_ids, &UNUSED);
+
+    assert_eq!(used, vec![U128(30), U128(50), U128(50)]);
+    assert_eq!(balances_of(&tokens, 2, &token_ids), vec![U128(30), U128(50), U128(50)]);
+    assert_eq!(balances_of(&tokens, 0, &token_ids), vec![U128(70), U128(50), U128(50)]);
+}
+
+#[test]
+fn refund_is_limited_by_what_receiver_still_has() {
+    let (mut tokens, token_ids) = sent();
+    // Receiver burned part of the last token before the resolver ran
+    tokens.internal_burn(&accounts(2), &token_ids[2], 70, None);
+
+    let used = resolve(&mut tokens, &token_ids, &UNUSED);
+
+    assert_eq!(used[2], U128(70));
+    assert_eq!(balances_of(&tokens, 0, &token_ids)[2], U128(30));
+}
+
+#[test]
+fn refund_to_deleted_sender_is_burned() {
+    let (mut tokens, token_ids) = sent();
+    let mut balances = tokens.balances_per_token.get(&token_ids[1]).unwrap();
+    balances.remove(&accounts(0));
+
+    let used = resolve(&mut tokens, &token_ids, &UNUSED);
+
+    assert_eq!(used[1], U128(60));
+    assert_eq!(balances_of(&tokens, 2, &token_ids)[1], U128(50));
+    assert_eq!(tokens.total_supply.get(&token_ids[1]), Some(90));
+}
@@ -0,0 +1,130 @@
+//! Properties of payout split for arbitrary royalties and sale balances.
+
+mod common;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{AccountId, Balance};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::payout::{bps_of, split_payout, MultiTokenRoyalty, Royalty, MAX_ROYALTY_BPS};
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+
+use common::{mint, new_tokens, set_caller};
+
+fn receiver(index: u8) -> AccountId {
+    format!("r{}.near", index).parse().unwrap()
+}
+
+/// Royalty of up to `max_len` receivers out of 20 with shares summing to at most `max_total` bps
+fn royalty(max_total: u16, max_len: usize) -> impl Strategy<Value = Royalty> {
+    prop::collection::vec((0..20u8, 0..=max_total), 0..=max_len).prop_map(move |entries| {
+        let mut royalty = Royalty::new();
+        let mut total = 0;
+        for (index, share) in entries {
+            let share = share.min(max_total - total);
+            total += share;
+            royalty.insert(receiver(index), share);
+        }
+        royalty
+    })
+}
+
+/// Sale balances, edges of rounding and of `u128` included
+fn balance() -> impl Strategy<Value = Balance> {
+    prop_oneof![Just(0), Just(1), Just(9_999), Just(10_000), Just(Balance::MAX), 0..10_u128.pow(24), any::<Balance>()]
+}
+
+fn total_of(payout: &HashMap<AccountId, Balance>) -> Balance {
+    payout.values().try_fold(0, |total: Balance, amount| total.checked_add(*amount)).expect("Payout overflows")
+}
+
+/// Payout is exactly what [split_payout] gives for `royalty`: receivers get their rounded down shares,
+/// seller gets the rest
+fn assert_split(royalty: &Royalty, seller_id: &AccountId, balance: Balance, payout: &HashMap<AccountId, Balance>) {
+    assert_eq!(total_of(payout), balance);
+
+    for (account_id, share) in royalty.iter().filter(|(account_id, _)| *account_id != seller_id) {
+        let expected = bps_of(balance, *share);
+        assert_eq!(payout.get(account_id).copied(), Some(expected).filter(|amount| *amount > 0));
+    }
+    assert!(payout.keys().all(|account_id| account_id == seller_id || royalty.contains_key(account_id)));
+
+    let seller_share = royalty.get(seller_id).copied().unwrap_or_default();
+    assert!(payout[seller_id] >= bps_of(balance, seller_share));
+}
+
+proptest! {
+    #[test]
+    fn bps_of_rounds_down(balance in 0..(1_u128 << 100), share in 0..=10_000u16) {
+        prop_assert_eq!(bps_of(balance, share), balance * Balance::from(share) / 10_000);
+    }
+
+    #[test]
+    fn bps_of_never_exceeds_balance(balance in balance(), share in 0..=10_000u16) {
+        prop_assert!(bps_of(balance, share) <= balance);
+        prop_assert_eq!(bps_of(balance, 10_000), balance);
+    }
+
+    #[test]
+    fn split_payout_sums_to_balance(royalty in royalty(10_000, 12), seller in 0..20u8, balance in balance()) {
+        let seller_id = receiver(seller);
+        let payout = split_payout(&royalty, &seller_id, balance);
+
+        assert_split(&royalty, &seller_id, balance, &payout);
+    }
+}
+
+#[test]
+fn mt_payout_sums_to_balance() {
+    let tokens = RefCell::new(new_tokens());
+    let token_id = {
+        let mut tokens = tokens.borrow_mut();
+        tokens.enable_royalties(b"r".to_vec());
+        mint(&mut tokens, 1)
+    };
+
+    let strategy = (royalty(MAX_ROYALTY_BPS, 9), 0..20u8, balance());
+    TestRunner::default()
+        .run(&strategy, |(royalty, seller, balance)| {
+            let seller_id = receiver(seller);
+            let mut tokens = tokens.borrow_mut();
+            set_caller(accounts(0), 0);
+            tokens.set_default_royalty(Some(royalty.clone()));
+
+            let payout = tokens.mt_payout(token_id.clone(), seller_id.clone(), Amount(balance), None).payout;
+            let payout = payout.into_iter().map(|(account_id, amount)| (account_id, amount.0)).collect();
+
+            assert_split(&royalty, &seller_id, balance, &payout);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn dust_goes_to_seller() {
+    let royalty = Royalty::from([(receiver(1), 3_333), (receiver(2), 3_333), (receiver(3), 3_333)]);
+
+    let payout = split_payout(&royalty, &receiver(0), 2);
+    assert_eq!(payout, HashMap::from([(receiver(0), 2)]));
+
+    let payout = split_payout(&royalty, &receiver(0), 10_000);
+    assert_eq!(payout[&receiver(0)], 1);
+    assert_eq!(total_of(&payout), 10_000);
+}
+
+panic_test!(royalty_over_100_percent, "Royalty cannot exceed 100%", {
+    let royalty = Royalty::from([(receiver(1), 6_000), (receiver(2), 4_001)]);
+    split_payout(&royalty, &receiver(0), 1);
+});
+
+panic_test!(payout_longer_than_max_len, "Payout cannot have more than 2 receivers", {
+    let mut tokens = new_tokens();
+    tokens.enable_royalties(b"r".to_vec());
+    let token_id = mint(&mut tokens, 1);
+    tokens.set_default_royalty(Some(Royalty::from([(receiver(1), 100), (receiver(2), 100)])));
+
+    tokens.mt_payout(token_id, receiver(0), Amount(10_000), Some(2));
+});
@@ -0,0 +1,81 @@
+//! Borsh-encoded views decode to the same values as their JSON counterparts
+
+mod common;
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde_json;
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::raw::MultiTokenRawViews;
+use nep_246::multi_token::token::{Token, TokenId};
+
+/// Two tokens of `accounts(0)`, 30 of the first one sent to `accounts(1)`
+fn setup() -> (MultiToken, Vec<TokenId>) {
+    let mut tokens = common::new_tokens();
+    let token_ids = vec![common::mint(&mut tokens, 100), common::mint(&mut tokens, u128::MAX)];
+    tokens.internal_register_account(&token_ids[0], &accounts(1));
+    tokens.internal_transfer(&accounts(0), &accounts(1), &token_ids[0], None, 30);
+    (tokens, token_ids)
+}
+
+#[test]
+fn balances_round_trip() {
+    let (tokens, token_ids) = setup();
+
+    for (account_id, token_ids) in [(accounts(0), token_ids.clone()), (accounts(1), token_ids[..1].to_vec())] {
+        let bytes = tokens.mt_balance_of_raw(account_id.clone(), token_ids.clone()).try_to_vec().unwrap();
+        let json: Vec<u128> =
+            tokens.mt_balance_of(account_id, token_ids).into_iter().map(|balance| balance.0).collect();
+        assert_eq!(Vec::<u128>::try_from_slice(&bytes).unwrap(), json);
+    }
+}
+
+/// JSON view panics for these, raw one returns 0
+#[test]
+fn balances_of_unregistered_accounts_are_zero() {
+    let (tokens, token_ids) = setup();
+
+    let bytes =
+        tokens.mt_balance_of_raw(accounts(1), vec![token_ids[1].clone(), "missing".into()]).try_to_vec().unwrap();
+
+    assert_eq!(Vec::<u128>::try_from_slice(&bytes).unwrap(), vec![0, 0]);
+}
+
+#[test]
+fn balances_layout() {
+    let (tokens, token_ids) = setup();
+
+    let bytes = tokens.mt_balance_of_raw(accounts(0), token_ids).try_to_vec().unwrap();
+
+    let mut expected = 2u32.to_le_bytes().to_vec();
+    expected.extend(70u128.to_le_bytes());
+    expected.extend(u128::MAX.to_le_bytes());
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn supply_round_trip() {
+    let (tokens, mut token_ids) = setup();
+    token_ids.push("missing".into());
+
+    let bytes = tokens.mt_supply_raw(token_ids).try_to_vec().unwrap();
+
+    assert_eq!(Vec::<u128>::try_from_slice(&bytes).unwrap(), vec![100, u128::MAX, 0]);
+}
+
+#[test]
+fn token_round_trip() {
+    let (tokens, token_ids) = setup();
+
+    for token_id in [token_ids[0].clone(), "missing".into()] {
+        let bytes = tokens.mt_token_raw(token_id.clone()).try_to_vec().unwrap();
+        let decoded = Option::<Token>::try_from_slice(&bytes).unwrap();
+        assert_eq!(serde_json::to_value(decoded).unwrap(), serde_json::to_value(tokens.mt_token(token_id)).unwrap());
+    }
+}
+
+#[test]
+fn missing_token_layout() {
+    let (tokens, _) = setup();
+    assert_eq!(tokens.mt_token_raw("missing".into()).try_to_vec().unwrap(), vec![0]);
+}
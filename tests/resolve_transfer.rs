@@ -0,0 +1,60 @@
+//! Resolution of `mt_transfer_call` by `mt_resolve_transfer`
+
+mod common;
+
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::accounts;
+use near_sdk::PromiseResult;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore, MultiTokenResolver};
+use nep_246::multi_token::token::TokenId;
+
+/// Token with 100 minted to `accounts(0)`, 40 of which were sent to `accounts(2)` by `mt_transfer_call`
+fn transferred() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+    tokens.internal_transfer(&accounts(0), &accounts(2), &token_id, None, 40);
+    (tokens, token_id)
+}
+
+fn resolve(tokens: &mut MultiToken, token_id: &TokenId, result: PromiseResult) -> Vec<U128> {
+    common::set_callback(result);
+    tokens.mt_resolve_transfer(accounts(0), accounts(2), vec![token_id.clone()], vec![U128(40)], None)
+}
+
+fn balances(tokens: &MultiToken, token_id: &TokenId) -> Vec<U128> {
+    tokens
+        .mt_balance_of(accounts(0), vec![token_id.clone()])
+        .into_iter()
+        .chain(tokens.mt_balance_of(accounts(2), vec![token_id.clone()]))
+        .collect()
+}
+
+#[test]
+fn failed_receiver_gets_whole_amount_reverted() {
+    let (mut tokens, token_id) = transferred();
+
+    assert_eq!(resolve(&mut tokens, &token_id, PromiseResult::Failed), vec![U128(0)]);
+    assert_eq!(balances(&tokens, &token_id), vec![U128(100), U128(0)]);
+}
+
+#[test]
+fn unused_amount_is_returned() {
+    let (mut tokens, token_id) = transferred();
+    let unused = near_sdk::serde_json::to_vec(&vec![U128(15)]).unwrap();
+
+    assert_eq!(resolve(&mut tokens, &token_id, PromiseResult::Successful(unused)), vec![U128(25)]);
+    assert_eq!(balances(&tokens, &token_id), vec![U128(75), U128(25)]);
+}
+
+panic_test!(resolver_rejects_other_callers, "Method mt_resolve_transfer is private", {
+    let (mut tokens, token_id) = transferred();
+    common::set_caller(accounts(2), 0);
+    tokens.mt_resolve_transfer(accounts(0), accounts(2), vec![token_id], vec![U128(40)], None);
+});
+
+panic_test!(resolver_rejects_sender, "Method mt_resolve_transfer is private", {
+    let (mut tokens, token_id) = transferred();
+    common::set_caller(accounts(0), 0);
+    tokens.mt_resolve_transfer(accounts(0), accounts(2), vec![token_id], vec![U128(40)], None);
+});
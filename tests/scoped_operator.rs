@@ -0,0 +1,33 @@
+//! Operators approved for all or listed tokens of owner
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::MultiTokenScopedOperator;
+
+#[test]
+fn operator_covers_listed_tokens_of_owner() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_scoped_operators(b"o".to_vec());
+    let listed = common::mint(&mut tokens, 10);
+    let other = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve_operator(accounts(2), Some(vec![listed.clone()]), None);
+
+    assert!(tokens.mt_is_operator_approved(accounts(0), accounts(2), listed));
+    assert!(!tokens.mt_is_operator_approved(accounts(0), accounts(2), other));
+}
+
+panic_test!(tokens_of_other_owner_cannot_be_listed, "Caller doesn't own token 0", {
+    let mut tokens = common::new_tokens();
+    tokens.enable_scoped_operators(b"o".to_vec());
+    let token_id = common::mint(&mut tokens, 10);
+    // accounts(1) holds balance of the token, but accounts(0) owns it
+    tokens.internal_register_account(&token_id, &accounts(1));
+    tokens.internal_transfer(&accounts(0), &accounts(1), &token_id, None, 5);
+
+    common::set_caller(accounts(1), ONE_NEAR);
+    tokens.mt_approve_operator(accounts(2), Some(vec![token_id]), None);
+});
@@ -0,0 +1,37 @@
+//! Transfers to the account tokens are taken from are rejected with `SenderIsReceiver`
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+fn setup() -> (MultiToken, Vec<TokenId>) {
+    let mut tokens = common::new_tokens();
+    let token_ids = (0..2).map(|_| common::mint(&mut tokens, 100)).collect();
+    (tokens, token_ids)
+}
+
+panic_test!(single_transfer_to_self, "Sender and receiver must differ", {
+    let (mut tokens, token_ids) = setup();
+    tokens.mt_transfer(accounts(0), token_ids[0].clone(), Amount(10), None);
+});
+
+panic_test!(batch_transfer_to_self, "Sender and receiver must differ", {
+    let (mut tokens, token_ids) = setup();
+    tokens.mt_batch_transfer(accounts(0), token_ids, vec![Amount(10), Amount(20)], vec![None, None], None);
+});
+
+panic_test!(best_effort_batch_to_self, "Sender and receiver must differ", {
+    let (mut tokens, token_ids) = setup();
+    tokens.mt_batch_transfer_best_effort(accounts(0), token_ids, vec![Amount(10), Amount(20)], vec![None, None], None);
+});
+
+#[test]
+fn check_of_transfer_to_self() {
+    let (tokens, token_ids) = setup();
+    let result = tokens.internal_check_transfer(&accounts(0), &accounts(0), &token_ids[0], None, 10);
+    assert_eq!(result, Err(MtError::SenderIsReceiver.to_string()));
+}
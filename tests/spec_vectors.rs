@@ -0,0 +1,206 @@
+//! Example flows of the NEP-246 specification run against [MultiToken].
+//!
+//! Arguments are given as JSON exactly as in the examples and parsed into arguments of the methods,
+//! results are compared with the values the examples expect. Cross-contract calls are replaced
+//! by the results the examples give for them. Approvals are passed as approval IDs, the only
+//! form this implementation accepts.
+
+mod common;
+
+use near_sdk::json_types::U128;
+use near_sdk::serde::Deserialize;
+use near_sdk::serde_json::{self, json, Value};
+use near_sdk::test_utils::accounts;
+use near_sdk::{AccountId, PromiseResult, ONE_NEAR};
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore, MultiTokenResolver};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct TransferArgs {
+    receiver_id: AccountId,
+    token_id: TokenId,
+    amount: Amount,
+    #[serde(default)]
+    approval: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct BatchTransferArgs {
+    receiver_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<Amount>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct TransferCallArgs {
+    receiver_id: AccountId,
+    token_ids: Vec<TokenId>,
+    amounts: Vec<Amount>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct ApproveArgs {
+    token_ids: Vec<TokenId>,
+    amounts: Vec<Amount>,
+    account_id: AccountId,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct IsApprovedArgs {
+    token_ids: Vec<TokenId>,
+    approved_account_id: AccountId,
+    amounts: Vec<Amount>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct RevokeArgs {
+    token_ids: Vec<TokenId>,
+    account_id: AccountId,
+}
+
+fn args<T: near_sdk::serde::de::DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).unwrap()
+}
+
+fn account(name: &str) -> AccountId {
+    name.parse().unwrap()
+}
+
+/// Tokens of `alice`: "1" is `gold` with precision `10^8`, "2" is a unique `gemstone`, "3" is `silver`
+fn games() -> MultiToken {
+    let mut tokens = common::new_tokens();
+    tokens.next_token_id = 1;
+    for supply in [100 * 10u128.pow(8), 1, 100 * 10u128.pow(8)] {
+        let token_id = common::mint(&mut tokens, supply);
+        for receiver in ["bob", "games", "market"] {
+            tokens.internal_register_account(&token_id, &account(receiver));
+        }
+    }
+    tokens
+}
+
+fn balance(tokens: &MultiToken, owner: &str, token_id: &str) -> U128 {
+    tokens.mt_balance_of(account(owner), vec![token_id.into()])[0]
+}
+
+/// Alice sends 5 `gold` to Bob
+#[test]
+fn simple_transfer() {
+    let mut tokens = games();
+    let args: TransferArgs = args(json!({"receiver_id": "bob", "amount": "500000000", "token_id": "1"}));
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer(args.receiver_id, args.token_id, args.amount, args.approval);
+
+    assert_eq!(balance(&tokens, "bob", "1"), U128(500000000));
+    assert_eq!(balance(&tokens, "alice", "1"), U128(9500000000));
+}
+
+/// Alice sends 5 `gold`, the `gemstone` and 10 `silver` to Bob
+#[test]
+fn simple_batch_transfer() {
+    let mut tokens = games();
+    let args: BatchTransferArgs = args(json!({
+        "receiver_id": "bob",
+        "amounts": ["500000000", "1", "1000000000"],
+        "token_ids": ["1", "2", "3"]
+    }));
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_batch_transfer(args.receiver_id, args.token_ids, args.amounts, vec![None; 3], None);
+
+    assert_eq!(
+        ["1", "2", "3"].map(|token_id| balance(&tokens, "bob", token_id)),
+        [U128(500000000), U128(1), U128(1000000000)]
+    );
+}
+
+/// `mt_resolve_transfer` example: sender calls `mt_transfer_call({ "amounts": ["100"], token_ids: ["55"],
+/// receiver_id: "games" })`, receiver only uses 80, `mt_on_transfer` resolves with `["20"]` and
+/// `mt_resolve_transfer` returns `["80"]`
+#[test]
+fn token_deposit_to_contract() {
+    let mut tokens = common::new_tokens();
+    tokens.next_token_id = 55;
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &account("games"));
+    let args: TransferCallArgs = args(json!({"amounts": ["100"], "token_ids": ["55"], "receiver_id": "games"}));
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer_call(args.receiver_id.clone(), args.token_ids[0].clone(), args.amounts[0], None, String::new());
+
+    common::set_callback(PromiseResult::Successful(serde_json::to_vec(&json!(["20"])).unwrap()));
+    let used = tokens.mt_resolve_transfer(
+        accounts(0),
+        args.receiver_id,
+        args.token_ids,
+        args.amounts.into_iter().map(U128::from).collect(),
+        None,
+    );
+
+    assert_eq!(serde_json::to_value(used).unwrap(), json!(["80"]));
+    assert_eq!(balance(&tokens, "games", "55"), U128(80));
+    assert_eq!(balance(&tokens, "alice", "55"), U128(20));
+}
+
+/// `mt_on_transfer` example: `amounts` is `[10]` but only 9 are needed, it returns `[1]`
+#[test]
+fn receiver_returns_unused_amount() {
+    let mut tokens = games();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer_call(account("games"), "1".into(), Amount(10), None, String::new());
+
+    common::set_callback(PromiseResult::Successful(serde_json::to_vec(&json!([1])).unwrap()));
+    let used = tokens.mt_resolve_transfer(accounts(0), account("games"), vec!["1".into()], vec![U128(10)], None);
+
+    assert_eq!(used, vec![U128(9)]);
+    assert_eq!(balance(&tokens, "games", "1"), U128(9));
+}
+
+/// Alice approves Bob to transfer the `gemstone` and 100 `silver` on her behalf, then Bob uses the approval
+/// to send the `silver` to `games`
+#[test]
+fn simple_approval() {
+    let mut tokens = games();
+    let approve: ApproveArgs = args(json!({"token_ids": ["2", "3"], "amounts": ["1", "100"], "account_id": "bob"}));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(approve.account_id, approve.token_ids, approve.amounts, None);
+
+    let check: IsApprovedArgs =
+        args(json!({"token_ids": ["2", "3"], "amounts": ["1", "100"], "approved_account_id": "bob"}));
+    assert!(tokens.mt_is_approved(check.token_ids, check.approved_account_id, check.amounts, None));
+
+    let approval_id = common::approval_of(&tokens, &"3".into(), &account("bob")).unwrap().approval_id;
+    let transfer: TransferArgs =
+        args(json!({"receiver_id": "games", "token_id": "3", "amount": "100", "approval": approval_id}));
+    common::set_caller(account("bob"), 1);
+    tokens.mt_transfer(transfer.receiver_id, transfer.token_id, transfer.amount, transfer.approval);
+
+    assert_eq!(balance(&tokens, "games", "3"), U128(100));
+}
+
+/// Alice revokes approval of the market, other approvals are kept
+#[test]
+fn revoke_one() {
+    let mut tokens = games();
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(account("market"), vec!["1".into()], vec![Amount(1)], None);
+    tokens.mt_approve(account("bob"), vec!["1".into()], vec![Amount(1)], None);
+
+    let revoke: RevokeArgs = args(json!({"token_ids": ["1"], "account_id": "market"}));
+    common::set_caller(accounts(0), 1);
+    tokens.mt_revoke(revoke.token_ids, revoke.account_id);
+
+    assert!(!tokens.mt_is_approved(vec!["1".into()], account("market"), vec![Amount(1)], None));
+    assert!(tokens.mt_is_approved(vec!["1".into()], account("bob"), vec![Amount(1)], None));
+}
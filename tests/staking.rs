@@ -0,0 +1,46 @@
+//! Staking of tokens by holders
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::staking::MultiTokenStaking;
+use nep_246::multi_token::utils::Entity;
+
+#[test]
+fn staked_tokens_move_to_contract() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_staking(b"s".to_vec(), 0);
+    let token_id = common::mint(&mut tokens, 100);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_stake(token_id.clone(), Amount(40));
+
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &accounts(0)), 60);
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id, &common::contract_id()), 40);
+    assert_eq!(tokens.mt_staked_balance_of(accounts(0), token_id).0, 40);
+}
+
+panic_test!(locked_tokens_can_not_be_staked, &MtError::TransferLocked { until: 1_000 }.to_string(), {
+    let mut tokens = common::new_tokens();
+    tokens.enable_staking(b"s".to_vec(), 0);
+    tokens.enable_transfer_locks(b"l".to_vec(), 1_000);
+    let token_id = common::mint(&mut tokens, 100);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_stake(token_id, Amount(40));
+});
+
+panic_test!(
+    staking_without_extension_names_it,
+    &MtError::ExtensionDisabled { extension: "Staking", entity: Entity::Contract }.to_string(),
+    {
+        let mut tokens = common::new_tokens();
+        let token_id = common::mint(&mut tokens, 100);
+
+        common::set_caller(accounts(0), ONE_NEAR);
+        tokens.mt_stake(token_id, Amount(40));
+    }
+);
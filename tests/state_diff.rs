@@ -0,0 +1,45 @@
+//! State diffs of basic operations declared with `assert_state_diff!`
+
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+use nep_246::assert_state_diff;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+fn setup() -> (MultiToken, TokenId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(0)).attached_deposit(1);
+    testing_env!(context.build());
+
+    let mut tokens = MultiToken::new(b"t".to_vec(), accounts(0), None::<Vec<u8>>, None::<Vec<u8>>, None::<Vec<u8>>);
+    let token_id = tokens.internal_mint(accounts(0), Some(100), None, None).token_id;
+    tokens.internal_register_account(&token_id, &accounts(1));
+    (tokens, token_id)
+}
+
+#[test]
+fn transfer_moves_balance_only() {
+    let (mut tokens, token_id) = setup();
+
+    assert_state_diff!(tokens, [token_id], [accounts(0), accounts(1)],
+        tokens.mt_transfer(accounts(1), token_id.clone(), Amount(10), None),
+        {
+            balance(token_id, accounts(0)) => -10,
+            balance(token_id, accounts(1)) => 10,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "unexpected state diff")]
+fn undeclared_change_fails() {
+    let (mut tokens, token_id) = setup();
+
+    assert_state_diff!(tokens, [token_id], [accounts(0), accounts(1)],
+        tokens.mt_transfer(accounts(1), token_id.clone(), Amount(10), None),
+        {
+            balance(token_id, accounts(1)) => 10,
+        }
+    );
+}
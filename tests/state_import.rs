@@ -0,0 +1,53 @@
+//! Import of state exported by another deployment
+
+mod common;
+
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::env;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::state::{MultiTokenState, StateChunk, TokenState};
+
+/// Token `id` of `accounts(0)` with total supply of 100 split between `balances` of `accounts(0)` and `accounts(1)`
+fn token(id: &str, balances: [u128; 2]) -> TokenState {
+    TokenState {
+        token_id: id.into(),
+        owner_id: accounts(0),
+        supply: U128(100),
+        metadata: None,
+        balances: vec![(accounts(0), U128(balances[0])), (accounts(1), U128(balances[1]))],
+    }
+}
+
+fn import(tokens: &mut MultiToken, token: TokenState) {
+    let tokens_state = vec![token];
+    let hash = env::sha256(&tokens_state.try_to_vec().unwrap()).into();
+    common::set_caller(accounts(0), 1);
+    tokens.mt_import_state(StateChunk { from_index: 0, tokens: tokens_state, hash });
+}
+
+#[test]
+fn imported_balances_are_kept() {
+    let mut tokens = common::new_tokens();
+
+    import(&mut tokens, token("7", [60, 40]));
+
+    assert_eq!(tokens.internal_unwrap_balance_of(&"7".into(), &accounts(1)), 40);
+    assert_eq!(tokens.next_token_id, 8);
+}
+
+panic_test!(balances_must_add_up_to_supply, "Balances don't add up to total supply", {
+    let mut tokens = common::new_tokens();
+    import(&mut tokens, token("7", [60, 30]));
+});
+
+panic_test!(balances_must_not_overflow, "Balance overflow", {
+    let mut tokens = common::new_tokens();
+    import(&mut tokens, token("7", [u128::MAX, 1]));
+});
+
+panic_test!(largest_numeric_id_is_rejected, "Token id overflow", {
+    let mut tokens = common::new_tokens();
+    import(&mut tokens, token(&u64::MAX.to_string(), [60, 40]));
+});
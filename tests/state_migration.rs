@@ -0,0 +1,170 @@
+//! Upgrade of state stored by the previous release
+
+mod common;
+
+use std::collections::HashMap;
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap, UnorderedMap, UnorderedSet};
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::state::MultiTokenState;
+use nep_246::multi_token::legacy::{LegacyApproval, LegacyTokenMetadata, MultiTokenV0};
+use nep_246::multi_token::token::TokenId;
+
+fn token_id(id: &str) -> TokenId {
+    id.into()
+}
+
+/// State of the previous release with two tokens: "1" with an approval and "2"
+/// whose approvals were overwritten by approvals counter sharing the same key
+fn legacy_state() -> Vec<u8> {
+    common::set_caller(accounts(0), 0);
+    let mut old = MultiTokenV0 {
+        owner_id: accounts(0),
+        extra_storage_in_bytes_per_emission: 7,
+        owner_by_id: TreeMap::new(b"o".to_vec()),
+        total_supply: LookupMap::new(b"s".to_vec()),
+        token_metadata_by_id: Some(LookupMap::new(b"m".to_vec())),
+        tokens_per_owner: Some(LookupMap::new(b"e".to_vec())),
+        balances_per_token: UnorderedMap::new(b"b".to_vec()),
+        approvals_by_id: Some(LookupMap::new(b"a".to_vec())),
+        approvals_number_by_id: Some(LookupMap::new(b"a".to_vec())),
+        next_approval_id_by_id: Some(LookupMap::new(b"an".to_vec())),
+        next_token_id: 2,
+    };
+
+    for id in ["1", "2"] {
+        old.owner_by_id.insert(&token_id(id), &accounts(0));
+        old.total_supply.insert(&token_id(id), &100);
+        let mut balances = LookupMap::new([b"b".to_vec(), id.as_bytes().to_vec()].concat());
+        balances.insert(&accounts(0), &100u128);
+        balances.insert(&accounts(2), &0u128);
+        old.balances_per_token.insert(&token_id(id), &balances);
+    }
+    old.token_metadata_by_id.as_mut().unwrap().insert(
+        &token_id("1"),
+        &LegacyTokenMetadata { title: Some("Gold".into()), reference: Some("ipfs://gold".into()), ..Default::default() },
+    );
+    old.approvals_by_id.as_mut().unwrap().insert(
+        &token_id("1"),
+        &HashMap::from([(accounts(1), LegacyApproval { amount: 40, approval_id: 0 })]),
+    );
+    old.approvals_number_by_id.as_mut().unwrap().insert(&token_id("2"), &1);
+    let mut owned = UnorderedSet::new(b"eo".to_vec());
+    owned.insert(&token_id("2"));
+    owned.insert(&token_id("1"));
+    old.tokens_per_owner.as_mut().unwrap().insert(&accounts(0), &owned);
+    old.next_approval_id_by_id.as_mut().unwrap().insert(&token_id("1"), &1);
+
+    old.try_to_vec().unwrap()
+}
+
+fn upgraded() -> MultiToken {
+    let mut tokens: MultiToken = MultiTokenV0::try_from_slice(&legacy_state()).unwrap().into();
+    let mut from_index = 0;
+    while from_index < tokens.owner_by_id.len() {
+        from_index = tokens.internal_migrate_tokens(from_index, 1);
+    }
+    tokens
+}
+
+#[test]
+fn root_state_is_kept() {
+    let tokens = upgraded();
+
+    assert_eq!(tokens.owner_id, accounts(0));
+    assert_eq!(tokens.extra_storage_in_bytes_per_emission, 7);
+    assert_eq!(tokens.next_token_id, 2);
+    assert_eq!(tokens.total_supply.get(&token_id("1")), Some(100));
+    assert_eq!(tokens.internal_unwrap_balance_of(&token_id("2"), &accounts(0)), 100);
+    assert_eq!(tokens.next_approval_id_by_id.as_ref().unwrap().get(&token_id("1")), Some(1));
+}
+
+#[test]
+fn new_extensions_start_disabled() {
+    let tokens = upgraded();
+
+    assert!(tokens.transfer_history.is_none());
+    assert!(tokens.storage_accounts.is_none());
+    assert!(tokens.scoped_operators.is_none());
+    assert!(tokens.approvals_by_grantee.is_some());
+}
+
+#[test]
+fn upgraded_state_round_trips() {
+    let tokens = upgraded();
+    let restored = MultiToken::try_from_slice(&tokens.try_to_vec().unwrap()).unwrap();
+
+    assert_eq!(restored.next_token_id, tokens.next_token_id);
+    assert_eq!(restored.internal_unwrap_balance_of(&token_id("1"), &accounts(0)), 100);
+}
+
+#[test]
+fn approvals_and_metadata_are_rewritten() {
+    let tokens = upgraded();
+
+    let approval = common::approval_of(&tokens, &token_id("1"), &accounts(1)).unwrap();
+    assert_eq!((approval.amount.0, approval.approval_id, approval.share), (40, 0, None));
+
+    let metadata = tokens.token_metadata_by_id.as_ref().unwrap().get(&token_id("1")).unwrap();
+    assert_eq!(metadata.title.as_deref(), Some("Gold"));
+    assert_eq!(metadata.reference.as_deref(), Some("ipfs://gold"));
+    assert_eq!(metadata.mime_type, None);
+    assert_eq!(metadata.kind, None);
+}
+
+#[test]
+fn tokens_of_owner_are_moved_to_ordered_index() {
+    let tokens = upgraded();
+
+    let owned = tokens.tokens_per_owner.as_ref().unwrap().get(&accounts(0)).unwrap();
+    assert_eq!(owned.iter().map(|(token_id, _)| token_id).collect::<Vec<_>>(), [token_id("1"), token_id("2")]);
+    assert!(!near_sdk::env::storage_has_key(&[b"eoe".as_ref(), &0u64.to_le_bytes()].concat()));
+}
+
+#[test]
+fn stale_approvals_counter_is_dropped() {
+    let tokens = upgraded();
+
+    assert!(tokens.approvals_by_id.as_ref().unwrap().get(&token_id("2")).is_none());
+}
+
+fn holders(tokens: &MultiToken, id: &str) -> Vec<near_sdk::AccountId> {
+    tokens.holders_per_token.get(&token_id(id)).map(|holders| holders.to_vec()).unwrap_or_default()
+}
+
+#[test]
+fn holder_index_is_rebuilt_page_by_page() {
+    let mut tokens = upgraded();
+    assert!(holders(&tokens, "1").is_empty());
+
+    common::set_caller(accounts(0), 10u128.pow(24));
+    assert_eq!(tokens.mt_add_holder_candidates(vec![accounts(1), accounts(0)]), 2);
+    assert_eq!(tokens.mt_add_holder_candidates(vec![accounts(2), accounts(3)]), 4);
+
+    common::set_caller(accounts(0), 1);
+    assert_eq!(tokens.mt_rebuild_holder_index(token_id("1"), None, 2), 1);
+    assert_eq!(tokens.mt_rebuild_holder_index(token_id("1"), Some(2), 2), 1);
+    assert_eq!(tokens.mt_rebuild_holder_index(token_id("1"), Some(4), 2), 0);
+    assert_eq!(tokens.mt_rebuild_holder_index(token_id("1"), None, 4), 0);
+    assert_eq!(holders(&tokens, "1"), vec![accounts(0), accounts(2)]);
+    assert!(holders(&tokens, "2").is_empty());
+
+    let before = near_sdk::env::storage_usage();
+    tokens.mt_clear_holder_candidates();
+    assert!(near_sdk::env::storage_usage() < before);
+    assert_eq!(tokens.mt_rebuild_holder_index(token_id("2"), None, 4), 0);
+}
+
+panic_test!(holder_candidates_are_added_by_owner_only, "assertion failed", {
+    let mut tokens = upgraded();
+    common::set_caller(accounts(1), 10u128.pow(24));
+    tokens.mt_add_holder_candidates(vec![accounts(1)]);
+});
+
+panic_test!(rebuild_page_is_limited, "Too many accounts", {
+    let mut tokens = upgraded();
+    common::set_caller(accounts(0), 1);
+    tokens.mt_rebuild_holder_index(token_id("1"), None, 101);
+});
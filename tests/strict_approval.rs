@@ -0,0 +1,63 @@
+//! Strict approvals become usable only after `mt_on_approve` of grantee succeeded
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::{PromiseResult, ONE_NEAR};
+use nep_246::multi_token::approval::{ApproveMsg, MultiTokenApproval, MultiTokenApprovalResolver};
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+fn setup() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_strict_approvals(b"s".to_vec());
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(1), vec![token_id.clone()], vec![Amount(50)], Some(ApproveMsg::Single("list".into())));
+    (tokens, token_id)
+}
+
+fn resolve(tokens: &mut MultiToken, token_id: &TokenId, result: PromiseResult) {
+    let approval_id = common::approval_of(tokens, token_id, &accounts(1)).unwrap().approval_id;
+    common::set_callback(result);
+    tokens.mt_resolve_approve(accounts(0), accounts(1), vec![token_id.clone()], vec![approval_id]);
+}
+
+#[test]
+fn approval_is_pending_until_resolved() {
+    let (tokens, token_id) = setup();
+
+    assert!(tokens.internal_is_approval_pending(&accounts(1), &token_id));
+    assert!(!tokens.mt_is_approved(vec![token_id.clone()], accounts(1), vec![Amount(50)], None));
+    let check = tokens.internal_check_transfer(&accounts(1), &accounts(2), &token_id, None, 10);
+    assert_eq!(check, Err("Approval is not confirmed by grantee yet".to_string()));
+}
+
+#[test]
+fn successful_callback_confirms_approval() {
+    let (mut tokens, token_id) = setup();
+    resolve(&mut tokens, &token_id, PromiseResult::Successful(vec![]));
+
+    assert!(!tokens.internal_is_approval_pending(&accounts(1), &token_id));
+    common::set_caller(accounts(1), 1);
+    tokens.mt_transfer(accounts(2), token_id.clone(), Amount(10), None);
+    assert_eq!(tokens.mt_balance_of(accounts(2), vec![token_id]), vec![10.into()]);
+}
+
+#[test]
+fn failed_callback_removes_approval() {
+    let (mut tokens, token_id) = setup();
+    resolve(&mut tokens, &token_id, PromiseResult::Failed);
+
+    assert!(!tokens.internal_is_approval_pending(&accounts(1), &token_id));
+    assert!(common::approval_of(&tokens, &token_id, &accounts(1)).is_none());
+}
+
+panic_test!(resolver_rejects_other_callers, "Method mt_resolve_approve is private", {
+    let (mut tokens, token_id) = setup();
+    common::set_caller(accounts(1), 0);
+    tokens.mt_resolve_approve(accounts(0), accounts(1), vec![token_id], vec![0]);
+});
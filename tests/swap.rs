@@ -0,0 +1,58 @@
+//! Atomic swaps between accounts
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::dust::MultiTokenMinTransfer;
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::swap::{MultiTokenSwap, SwapProposal};
+
+fn proposal(tokens: &mut MultiToken) -> SwapProposal {
+    let give = common::mint(tokens, 10);
+    let take = common::mint(tokens, 10);
+    SwapProposal {
+        taker_id: accounts(1),
+        give: vec![(give, Amount(5))],
+        take: vec![(take, Amount(5))],
+        expires_at: u64::MAX,
+    }
+}
+
+#[test]
+fn maker_pays_for_swap_storage() {
+    let mut tokens = common::new_tokens();
+    tokens.enable_swaps(b"s".to_vec());
+    let proposal = proposal(&mut tokens);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    let swap_id = tokens.mt_swap(proposal);
+
+    assert!(tokens.mt_swap_get(swap_id).is_some());
+    let refunds = common::transfers_to(&accounts(0));
+    assert_eq!(refunds.len(), 1);
+    assert!(refunds[0] < ONE_NEAR);
+}
+
+panic_test!(swap_without_storage_deposit_is_rejected, "to cover storage", {
+    let mut tokens = common::new_tokens();
+    tokens.enable_swaps(b"s".to_vec());
+    let proposal = proposal(&mut tokens);
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_swap(proposal);
+});
+
+panic_test!(swap_below_min_transfer_amount_is_rejected, &MtError::BelowMinAmount { min: 6, amount: 5 }.to_string(), {
+    let mut tokens = common::new_tokens();
+    tokens.enable_swaps(b"s".to_vec());
+    tokens.enable_min_transfer_amounts(b"d".to_vec());
+    let proposal = proposal(&mut tokens);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_set_min_transfer_amount(proposal.give[0].0.clone(), Amount(6));
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_swap(proposal);
+});
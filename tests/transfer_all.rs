@@ -0,0 +1,112 @@
+//! Moving all holdings of an account with `mt_transfer_all`
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::core::MultiToken;
+use nep_246::multi_token::dust::MultiTokenMinTransfer;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::migration::MultiTokenMigration;
+use nep_246::multi_token::portfolio::MultiTokenPortfolio;
+use nep_246::multi_token::storage_management::StorageManagement;
+use nep_246::multi_token::token::TokenId;
+
+fn with_portfolio() -> MultiToken {
+    let mut tokens = common::new_tokens();
+    tokens.enable_portfolio(b"p".to_vec());
+    tokens
+}
+
+fn balance(tokens: &MultiToken, token_id: &TokenId, account_id: &near_sdk::AccountId) -> Option<u128> {
+    tokens.balances_per_token.get(token_id).unwrap().get(account_id)
+}
+
+#[test]
+fn walks_only_holdings_of_caller() {
+    let mut tokens = with_portfolio();
+    let held: Vec<TokenId> = (0..3).map(|_| common::mint(&mut tokens, 10)).collect();
+    // Created by accounts(0) but held by accounts(1) only
+    let foreign = common::mint(&mut tokens, 10);
+    tokens.internal_register_account(&foreign, &accounts(1));
+    tokens.internal_transfer(&accounts(0), &accounts(1), &foreign, None, 10);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    let first = tokens.mt_transfer_all(accounts(2), 2);
+    common::set_caller(accounts(0), ONE_NEAR);
+    let second = tokens.mt_transfer_all(accounts(2), 2);
+
+    // Registration of accounts(0) in the foreign token is dropped without moving anything
+    assert_eq!(first.len() + second.len(), 4);
+    for token_id in held.iter() {
+        assert_eq!(balance(&tokens, token_id, &accounts(2)), Some(10));
+        assert_eq!(balance(&tokens, token_id, &accounts(0)), None);
+    }
+    assert_eq!(balance(&tokens, &foreign, &accounts(1)), Some(10));
+    assert_eq!(balance(&tokens, &foreign, &accounts(2)), None);
+    assert_eq!(balance(&tokens, &foreign, &accounts(0)), None);
+    assert!(tokens.mt_portfolio(accounts(0), None, 10).is_empty());
+}
+
+#[test]
+fn repeated_calls_move_whole_portfolio() {
+    let mut tokens = with_portfolio();
+    let held: Vec<TokenId> = (0..5).map(|_| common::mint(&mut tokens, 10)).collect();
+
+    let mut pages = vec![];
+    loop {
+        common::set_caller(accounts(0), ONE_NEAR);
+        let page = tokens.mt_transfer_all(accounts(2), 2);
+        if page.is_empty() {
+            break;
+        }
+        pages.push(page.len());
+    }
+
+    assert_eq!(pages, vec![2, 2, 1]);
+    for token_id in held.iter() {
+        assert_eq!(balance(&tokens, token_id, &accounts(2)), Some(10));
+    }
+}
+
+#[test]
+fn released_storage_is_credited_not_refunded() {
+    let mut tokens = with_portfolio();
+    tokens.enable_storage_management(b"s".to_vec());
+    let token_id = common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(1), ONE_NEAR);
+    tokens.storage_deposit(None, None);
+    common::set_caller(accounts(1), 0);
+    let registered = tokens.mt_storage_register(vec![token_id.clone()], None);
+    tokens.internal_transfer(&accounts(0), &accounts(1), &token_id, None, 10);
+    tokens.internal_register_account(&token_id, &accounts(2));
+
+    common::set_caller(accounts(1), ONE_NEAR);
+    tokens.mt_transfer_all(accounts(2), 10);
+
+    let storage = tokens.storage_balance_of(accounts(1)).unwrap();
+    assert!(storage.available.0 > registered.available.0);
+    // Only attached deposit comes back
+    assert_eq!(common::transfers_to(&accounts(1)), vec![ONE_NEAR]);
+}
+
+panic_test!(transfer_locked_token_is_not_moved, "Token is locked for transfers until", {
+    let mut tokens = with_portfolio();
+    tokens.enable_transfer_locks(b"l".to_vec(), u64::MAX / 2);
+    common::mint(&mut tokens, 10);
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_transfer_all(accounts(2), 10);
+});
+
+panic_test!(balance_below_min_transfer_is_not_moved, "Amount 10 is below minimum transfer amount 11", {
+    let mut tokens = with_portfolio();
+    tokens.enable_min_transfer_amounts(b"m".to_vec());
+    let token_id = common::mint(&mut tokens, 10);
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_set_min_transfer_amount(token_id, Amount(11));
+
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_transfer_all(accounts(2), 10);
+});
@@ -0,0 +1,42 @@
+//! Cooldowns between transfers of a token by the same account
+
+mod common;
+
+use near_sdk::json_types::U64;
+use near_sdk::test_utils::accounts;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+use nep_246::multi_token::transfer_cooldown::MultiTokenTransferCooldown;
+
+/// Token of 100 owned by `accounts(0)` with cooldown of 60 seconds
+fn cooling() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    tokens.enable_transfer_cooldowns(b"c".to_vec());
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(1));
+    common::set_caller(accounts(0), 1);
+    tokens.mt_set_transfer_cooldown(token_id.clone(), Some(U64(60)));
+    (tokens, token_id)
+}
+
+#[test]
+fn only_sender_is_stamped() {
+    let (mut tokens, token_id) = cooling();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer(accounts(1), token_id.clone(), Amount(1), None);
+
+    assert_eq!(tokens.mt_cooldown_remaining(token_id.clone(), accounts(0)).0, 60);
+    assert_eq!(tokens.mt_cooldown_remaining(token_id, accounts(1)).0, 0);
+}
+
+panic_test!(sender_waits_for_cooldown, &MtError::TransferCooldown { until: 60_000_000_000 }.to_string(), {
+    let (mut tokens, token_id) = cooling();
+
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer(accounts(1), token_id.clone(), Amount(10), None);
+    common::set_caller(accounts(0), 1);
+    tokens.mt_transfer(accounts(1), token_id, Amount(10), None);
+});
@@ -0,0 +1,61 @@
+//! Transfers rejected on edge cases panic with precise [MtError]
+
+mod common;
+
+use near_sdk::test_utils::accounts;
+use near_sdk::ONE_NEAR;
+use nep_246::multi_token::approval::MultiTokenApproval;
+use nep_246::multi_token::core::{MultiToken, MultiTokenCore};
+use nep_246::multi_token::errors::MtError;
+use nep_246::multi_token::json_types::Amount;
+use nep_246::multi_token::token::TokenId;
+
+/// Token with 100 minted to `accounts(0)`, `accounts(1)` is registered in it
+fn setup() -> (MultiToken, TokenId) {
+    let mut tokens = common::new_tokens();
+    let token_id = common::mint(&mut tokens, 100);
+    tokens.internal_register_account(&token_id, &accounts(1));
+    common::set_caller(accounts(0), 1);
+    (tokens, token_id)
+}
+
+panic_test!(unregistered_receiver, &MtError::NotRegistered(accounts(3)).to_string(), {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_transfer(accounts(3), token_id, Amount(10), None);
+});
+
+panic_test!(zero_amount, &MtError::ZeroAmount.to_string(), {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_transfer(accounts(1), token_id, Amount(0), None);
+});
+
+panic_test!(balance_plus_one, &MtError::InsufficientBalance { balance: 100, amount: 101 }.to_string(), {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_transfer(accounts(1), token_id, Amount(101), None);
+});
+
+panic_test!(mismatched_lengths, &MtError::LengthMismatch.to_string(), {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_batch_transfer(accounts(1), vec![token_id.clone(), token_id], vec![Amount(10)], vec![None, None], None);
+});
+
+panic_test!(approval_id_mismatch, &MtError::ApprovalIdMismatch { expected: 7, actual: 0 }.to_string(), {
+    let (mut tokens, token_id) = setup();
+    common::set_caller(accounts(0), ONE_NEAR);
+    tokens.mt_approve(accounts(2), vec![token_id.clone()], vec![Amount(50)], None);
+
+    common::set_caller(accounts(2), 1);
+    tokens.mt_transfer(accounts(1), token_id, Amount(10), Some(7));
+});
+
+panic_test!(self_transfer, &MtError::SenderIsReceiver.to_string(), {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_transfer(accounts(0), token_id, Amount(10), None);
+});
+
+#[test]
+fn whole_balance_can_be_sent() {
+    let (mut tokens, token_id) = setup();
+    tokens.mt_transfer(accounts(1), token_id.clone(), Amount(100), None);
+    assert_eq!(tokens.mt_balance_of(accounts(1), vec![token_id]), vec![100.into()]);
+}
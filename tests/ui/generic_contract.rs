@@ -0,0 +1,31 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use nep_246::prelude::*;
+use nep_246::impl_multi_token_core;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Vault {
+    fee: u128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Contract<S> {
+    tokens: MultiToken,
+    store: S,
+}
+
+impl<S> Default for Contract<S> {
+    fn default() -> Self {
+        env::panic_str("The contract is not initialized")
+    }
+}
+
+impl Contract<Vault> {
+    fn on_transfer(&mut self, sender_id: &AccountId, receiver_id: &AccountId, token_ids: &[TokenId], kept: &[U128]) {}
+
+    fn on_revert(&mut self, sender_id: &AccountId, receiver_id: &AccountId, token_ids: &[TokenId], refunded: &[U128]) {}
+}
+
+impl_multi_token_core!(Contract<Vault>, tokens, on_transfer, on_revert);
+
+fn main() {}
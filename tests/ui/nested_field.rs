@@ -0,0 +1,25 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::PanicOnDefault;
+use nep_246::prelude::*;
+use nep_246::{impl_multi_token_core, impl_multi_token_enumeration};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Assets {
+    mt: MultiToken,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct State {
+    assets: Assets,
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+    state: State,
+}
+
+impl_multi_token_core!(Contract, state.assets.mt);
+impl_multi_token_enumeration!(Contract, state.assets.mt);
+
+fn main() {}
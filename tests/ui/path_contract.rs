@@ -0,0 +1,19 @@
+use nep_246::prelude::*;
+use nep_246::{impl_multi_token_approval, impl_multi_token_core};
+
+mod app {
+    use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+    use near_sdk::PanicOnDefault;
+    use nep_246::prelude::*;
+
+    #[near_bindgen]
+    #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+    pub struct Contract {
+        pub tokens: MultiToken,
+    }
+}
+
+impl_multi_token_core!(app::Contract, tokens);
+impl_multi_token_approval!(app::Contract, tokens);
+
+fn main() {}
@@ -0,0 +1,15 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::PanicOnDefault;
+use nep_246::prelude::*;
+use nep_246::{impl_multi_token_core, impl_multi_token_enumeration};
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+    tokens: MultiToken,
+}
+
+impl_multi_token_core!(Contract, tokens);
+impl_multi_token_enumeration!(Contract, tokens);
+
+fn main() {}